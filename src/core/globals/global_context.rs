@@ -7,13 +7,30 @@
 // Contains state data that scenes read/write:
 // - input_state: Low-level input state (keys, mouse, modifiers)
 // - message_bus: Universal message queue (actions, events, transitions)
+// - scene buses: Per-scene message buses, keyed by scene (see scene_bus)
+// - rng: Seeded random numbers, deterministic per seed
+// - windows: Open windows, active window, and window requests
+// - system timings: Last tick's per-step update times
 //
 //=========================================================================
 
+//=== External Dependencies ===============================================
+
+use std::any::{Any, TypeId};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use log::warn;
+
 //=== Internal Dependencies ===============================================
 
-use crate::core::input::{InputEvent, StateTracker};
+use crate::core::input::{
+    Action, ActionEvent, ActionPhase, InputContext, InputEvent, InputLog, StateTracker,
+};
 use crate::core::message_bus::MessageBus;
+use crate::core::platform_bridge::{PlatformCommand, WindowId};
+use crate::core::scene::SceneKey;
+use super::{Rng, SystemTimings};
 
 //=== GlobalContext =======================================================
 
@@ -27,7 +44,9 @@ use crate::core::message_bus::MessageBus;
 ///
 /// - `input_state`: Raw input state (keys pressed/down/released, mouse)
 /// - `message_bus`: Multi-consumer message queue (actions, events, scene transitions)
+/// - `rng`: Deterministic random numbers (seeded via `EngineBuilder::with_seed`)
 /// - `frame_events`: Current frame's input events (internal, processed by systems)
+/// - Windows: Open windows and the active window (see [`windows`](Self::windows))
 pub struct GlobalContext {
     /// Raw input state tracker for low-level input queries.
     ///
@@ -45,12 +64,77 @@ pub struct GlobalContext {
     /// Scene transitions are published by scenes and processed by SceneManager.
     pub message_bus: MessageBus,
 
+    /// Shared random number generator.
+    ///
+    /// Seeded from [`EngineBuilder::with_seed`](crate::EngineBuilder::with_seed).
+    /// Draws advance it through `&self`, so scenes can use it directly; the
+    /// same seed and draw order reproduce the same sequence.
+    pub rng: Rng,
+
+    /// Per-scene message buses, one `HashMap<S, MessageBus>` per key type.
+    ///
+    /// Type-erased because the context is not generic over the scene key.
+    scene_buses: HashMap<TypeId, Box<dyn SceneBuses>>,
+
     /// Input events for the current frame.
     ///
     /// Populated by the platform thread and consumed by InputSystem during
-    /// the update phase. Cleared after processing. Not directly accessible
-    /// to scenes (use `input_state` instead).
+    /// the update phase. Kept through the scene update for
+    /// [`frame_events`](Self::frame_events); cleared by `end_frame`.
     pub(crate) frame_input_events: Vec<Vec<InputEvent>>,
+
+    /// Synthetic events queued by [`inject_input`](Self::inject_input).
+    ///
+    /// Appended as the last batch of the next tick's input. A `RefCell` so
+    /// scenes can inject through `&self`.
+    pub(crate) injected_input: RefCell<Vec<InputEvent>>,
+
+    /// Input context in effect for this tick's action mapping.
+    ///
+    /// Mirrored from the input system by `GlobalSystems::update`.
+    pub(crate) input_context: Cell<InputContext>,
+
+    /// Context requested via [`set_input_context`](Self::set_input_context),
+    /// applied before the next tick's mapping. A `Cell` so scenes can set it
+    /// through `&self`.
+    pub(crate) pending_input_context: Cell<Option<InputContext>>,
+
+    /// Open windows in creation order (primary first).
+    ///
+    /// Synchronized from the platform thread each tick.
+    pub(crate) windows: Vec<WindowId>,
+
+    /// Window that most recently delivered input.
+    pub(crate) active_window: Option<WindowId>,
+
+    /// Commands queued for the platform thread.
+    ///
+    /// Drained by the orchestrator after each update and sent over the
+    /// reverse channel.
+    pub(crate) platform_commands: Vec<PlatformCommand>,
+
+    /// Set by [`request_exit`](Self::request_exit); ends the core loop
+    /// after the current tick.
+    pub(crate) exit_requested: bool,
+
+    /// Current target ticks per second, kept in sync by the orchestrator.
+    pub(crate) tps: f64,
+
+    /// Per-step times of the last completed system update.
+    pub(crate) system_timings: SystemTimings,
+
+    /// TPS requested via [`set_tps`](Self::set_tps), applied after the tick.
+    pub(crate) pending_tps: Option<f64>,
+
+    /// Set by [`consume_input`](Self::consume_input); cleared by
+    /// `begin_frame`/`end_frame`. A `Cell` so scenes can set it through `&self`.
+    pub(crate) input_consumed: Cell<bool>,
+
+    /// Whether any visible scene wants a redraw this tick.
+    ///
+    /// Recomputed by `SceneManager::update`; a `Cell` because scenes update
+    /// through `&GlobalContext`.
+    pub(crate) redraw_needed: Cell<bool>,
 }
 
 impl GlobalContext {
@@ -59,7 +143,391 @@ impl GlobalContext {
         Self {
             input_state: StateTracker::new(),
             message_bus: MessageBus::new(),
+            rng: Rng::new(0),
+            scene_buses: HashMap::new(),
             frame_input_events: Vec::new(),
+            injected_input: RefCell::new(Vec::new()),
+            input_context: Cell::new(InputContext::Primary),
+            pending_input_context: Cell::new(None),
+            windows: Vec::new(),
+            active_window: None,
+            platform_commands: Vec::new(),
+            exit_requested: false,
+            tps: 60.0,
+            system_timings: SystemTimings::default(),
+            pending_tps: None,
+            input_consumed: Cell::new(false),
+            redraw_needed: Cell::new(true),
         }
     }
+
+    //--- Frame Lifecycle --------------------------------------------------
+
+    /// Clears frame-scoped flags before scenes update.
+    ///
+    /// Called by `GlobalSystems::update` after input processing and before
+    /// the scene update, so flags never leak in from the previous tick.
+    pub(crate) fn begin_frame(&mut self) {
+        self.input_consumed.set(false);
+    }
+
+    /// Clears frame-scoped flags and one-shot messages after scene
+    /// transitions are processed.
+    ///
+    /// Called at the end of `GlobalSystems::update`; the tick callback
+    /// therefore sees a clean context.
+    pub(crate) fn end_frame(&mut self) {
+        self.input_consumed.set(false);
+        self.frame_input_events.clear();
+        self.message_bus.clear_oneshot();
+        for buses in self.scene_buses.values_mut() {
+            buses.clear_oneshot();
+        }
+    }
+
+    //--- Scene Buses ------------------------------------------------------
+
+    /// Returns the message bus scoped to `scene`, if anything was sent to it.
+    ///
+    /// Scene buses are separate namespaces: a message pushed to scene A's bus
+    /// is invisible on scene B's bus and on [`message_bus`](Self::message_bus),
+    /// so scenes can reuse message types without interfering. Use the global
+    /// bus for cross-scene communication.
+    pub fn scene_bus<S: SceneKey>(&self, scene: S) -> Option<&MessageBus> {
+        self.scene_buses
+            .get(&TypeId::of::<S>())?
+            .as_any()
+            .downcast_ref::<HashMap<S, MessageBus>>()?
+            .get(&scene)
+    }
+
+    /// Returns the message bus scoped to `scene`, creating it on first use.
+    ///
+    /// One-shot messages on scene buses are cleared at the end of each frame,
+    /// like those on the global bus.
+    pub fn scene_bus_mut<S: SceneKey>(&mut self, scene: S) -> &mut MessageBus {
+        self.scene_buses
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| Box::new(HashMap::<S, MessageBus>::new()))
+            .as_any_mut()
+            .downcast_mut::<HashMap<S, MessageBus>>()
+            .expect("scene bus map keyed by its own TypeId")
+            .entry(scene)
+            .or_default()
+    }
+
+    //--- Input Consumption ------------------------------------------------
+
+    /// Marks this tick's input as handled.
+    ///
+    /// The flag lives for the scene update of the current tick only: it is
+    /// cleared before scenes update and again after transitions are processed.
+    ///
+    /// Scenes update top-down, so an overlay that handles a click can call
+    /// this and scenes further down the stack will see
+    /// [`is_input_consumed`](Self::is_input_consumed) return `true`.
+    /// Raw state in `input_state` is unaffected; checking the flag is opt-in.
+    pub fn consume_input(&self) {
+        self.input_consumed.set(true);
+    }
+
+    /// Returns `true` if a scene higher in the stack consumed this tick's input.
+    pub fn is_input_consumed(&self) -> bool {
+        self.input_consumed.get()
+    }
+
+    //--- Raw Input --------------------------------------------------------
+
+    /// Returns this tick's raw input events, flattened across batches.
+    ///
+    /// For scenes that need the ordered stream (text editors, custom
+    /// widgets) rather than [`input_state`](Self::input_state). Platform
+    /// batches come first in arrival order (continuous input such as mouse
+    /// moves already coalesced), then events injected last tick. Cleared at
+    /// the end of the update, so the tick callback sees nothing.
+    pub fn frame_events(&self) -> impl Iterator<Item = &InputEvent> {
+        self.frame_input_events.iter().flatten()
+    }
+
+    /// Returns the last few hundred raw input events with their ticks,
+    /// oldest first.
+    ///
+    /// For attaching to crash reports. See [`StateTracker::input_log`].
+    pub fn recent_input_log(&self) -> &InputLog {
+        self.input_state.input_log()
+    }
+
+    //--- Input Injection --------------------------------------------------
+
+    /// Feeds a synthetic input event through the normal input pipeline.
+    ///
+    /// For bots, tutorials and scripted tests. The event is processed at the
+    /// start of the next tick, after that tick's real input, and updates
+    /// `input_state` and actions exactly as platform input would. Events
+    /// injected in one tick keep their order.
+    pub fn inject_input(&self, event: InputEvent) {
+        self.injected_input.borrow_mut().push(event);
+    }
+
+    //--- Input Context ----------------------------------------------------
+
+    /// Requests a switch of the active input context.
+    ///
+    /// Lets scenes change control schemes (e.g. entering a menu in
+    /// `on_enter`). This tick's actions were already mapped, so the switch
+    /// applies from the next tick: a key pressed on the following tick maps
+    /// through `context`'s bindings. If called several times in one tick,
+    /// the last request wins.
+    pub fn set_input_context(&self, context: InputContext) {
+        self.pending_input_context.set(Some(context));
+    }
+
+    /// Returns the input context this tick's actions were mapped with.
+    ///
+    /// A switch requested this tick is not reflected until the next tick.
+    pub fn current_input_context(&self) -> InputContext {
+        self.input_context.get()
+    }
+
+    //--- Actions ----------------------------------------------------------
+
+    /// Returns `true` if `action` was pressed this frame.
+    ///
+    /// Reads the [`ActionEvent`]s published for the current frame only; a
+    /// held action reports `false` on the frames after its press.
+    pub fn action_pressed<A: Action>(&self, action: A) -> bool {
+        self.has_action_phase(action, ActionPhase::Pressed)
+    }
+
+    /// Returns `true` if `action` was released this frame.
+    ///
+    /// Like [`action_pressed`](Self::action_pressed), reflects the current
+    /// frame only.
+    pub fn action_released<A: Action>(&self, action: A) -> bool {
+        self.has_action_phase(action, ActionPhase::Released)
+    }
+
+    fn has_action_phase<A: Action>(&self, action: A, phase: ActionPhase) -> bool {
+        self.message_bus
+            .read::<ActionEvent<A>>()
+            .iter()
+            .any(|event| event.action == action && event.phase == phase)
+    }
+
+    //--- Rendering --------------------------------------------------------
+
+    /// Returns `true` if any visible scene reported
+    /// [`needs_redraw`](crate::core::scene::Scene::needs_redraw) this tick.
+    ///
+    /// `true` when the stack is empty. The platform stops requesting
+    /// redraws while this is `false`.
+    pub fn needs_redraw(&self) -> bool {
+        self.redraw_needed.get()
+    }
+
+    //--- Lifecycle --------------------------------------------------------
+
+    /// Requests engine shutdown at the end of the current tick.
+    ///
+    /// The core thread stops after this tick completes; the platform then
+    /// closes its windows and the event loop exits.
+    pub fn request_exit(&mut self) {
+        self.exit_requested = true;
+    }
+
+    //--- Timing -----------------------------------------------------------
+
+    /// Returns the target ticks per second of the logic thread.
+    pub fn tps(&self) -> f64 {
+        self.tps
+    }
+
+    /// Returns how long each step of the last completed system update took.
+    ///
+    /// During a scene update this is the previous tick's breakdown; the
+    /// current tick is still being measured. For debug overlays.
+    pub fn system_timings(&self) -> SystemTimings {
+        self.system_timings
+    }
+
+    /// Changes the logic thread's tick rate, starting with the next tick.
+    ///
+    /// Useful for bullet-time or capping TPS on battery. Values that are not
+    /// positive and finite are ignored with a warning.
+    pub fn set_tps(&mut self, tps: f64) {
+        if tps.is_finite() && tps > 0.0 {
+            self.pending_tps = Some(tps);
+        } else {
+            warn!("Ignoring invalid TPS {}; must be positive and finite", tps);
+        }
+    }
+
+    //--- Windows ----------------------------------------------------------
+
+    /// Returns all open windows in creation order (primary window first).
+    pub fn windows(&self) -> &[WindowId] {
+        &self.windows
+    }
+
+    /// Returns the window that most recently delivered input.
+    ///
+    /// Input state in `input_state` is shared across windows; use this to
+    /// determine which window the current input belongs to. `None` until the
+    /// first input arrives or after the active window is closed.
+    pub fn active_window(&self) -> Option<WindowId> {
+        self.active_window
+    }
+
+    /// Requests a new secondary window from the platform.
+    ///
+    /// The window is created asynchronously and appears in
+    /// [`windows`](Self::windows) once the platform reports it.
+    pub fn open_window(&mut self, title: impl Into<String>, width: u32, height: u32) {
+        self.platform_commands.push(PlatformCommand::OpenWindow {
+            title: title.into(),
+            width,
+            height,
+        });
+    }
+
+    /// Requests that a secondary window be closed.
+    ///
+    /// Closing the primary window is not supported; the platform ignores
+    /// the request with a warning.
+    pub fn close_window(&mut self, window: WindowId) {
+        self.platform_commands.push(PlatformCommand::CloseWindow(window));
+    }
+
+    /// Keeps the primary window above other windows (e.g., for overlays).
+    ///
+    /// Requests made before the window exists are applied once it is created.
+    pub fn set_always_on_top(&mut self, enabled: bool) {
+        self.platform_commands.push(PlatformCommand::SetAlwaysOnTop(enabled));
+    }
+
+    /// Minimizes the primary window.
+    ///
+    /// Requests made before the window exists are applied once it is created.
+    pub fn minimize_window(&mut self) {
+        self.platform_commands.push(PlatformCommand::Minimize);
+    }
+
+    /// Maximizes the primary window.
+    ///
+    /// Requests made before the window exists are applied once it is created.
+    pub fn maximize_window(&mut self) {
+        self.platform_commands.push(PlatformCommand::Maximize);
+    }
+
+    /// Changes the primary window's title.
+    ///
+    /// Requests made before the window exists are applied once it is created.
+    pub fn set_window_title(&mut self, title: impl Into<String>) {
+        self.platform_commands.push(PlatformCommand::SetTitle(title.into()));
+    }
+
+    //--- Cursor -----------------------------------------------------------
+
+    /// Shows or hides the cursor over the primary window.
+    ///
+    /// Requests made before the window exists are applied once it is created.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.platform_commands.push(PlatformCommand::SetCursorVisible(visible));
+    }
+
+    /// Confines the cursor to the primary window (e.g., for mouse-look).
+    ///
+    /// Requests made before the window exists are applied once it is created.
+    pub fn set_cursor_grab(&mut self, grab: bool) {
+        self.platform_commands.push(PlatformCommand::SetCursorGrab(grab));
+    }
+
+    /// Keeps the cursor centered in the primary window (e.g., FPS camera).
+    ///
+    /// The platform warps the cursor back to the center after each frame,
+    /// so it never hits the screen edge; read motion from
+    /// `input_state.mouse_delta()`, which excludes the warps. Usually
+    /// combined with a hidden, grabbed cursor.
+    pub fn set_relative_mouse(&mut self, enabled: bool) {
+        self.platform_commands.push(PlatformCommand::SetRelativeMouse(enabled));
+    }
+}
+
+//=== SceneBuses ==========================================================
+
+/// Type-erased `HashMap<S, MessageBus>` for one scene key type.
+trait SceneBuses: Send {
+    fn clear_oneshot(&mut self);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<S: SceneKey> SceneBuses for HashMap<S, MessageBus> {
+    fn clear_oneshot(&mut self) {
+        for bus in self.values_mut() {
+            bus.clear_oneshot();
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestScene {
+        A,
+        B,
+    }
+
+    impl SceneKey for TestScene {}
+
+    #[derive(Debug, PartialEq)]
+    struct Ping(u32);
+
+    #[test]
+    fn scene_bus_messages_are_isolated() {
+        let mut context = GlobalContext::new();
+        context.scene_bus_mut(TestScene::A).push(Ping(1));
+        context.scene_bus_mut(TestScene::B);
+
+        let bus_a = context.scene_bus(TestScene::A).unwrap();
+        assert_eq!(bus_a.read::<Ping>(), &[Ping(1)]);
+        assert!(context.scene_bus(TestScene::B).unwrap().read::<Ping>().is_empty());
+        assert!(!context.message_bus.has_messages::<Ping>());
+    }
+
+    #[test]
+    fn scene_bus_is_none_until_used() {
+        let mut context = GlobalContext::new();
+        assert!(context.scene_bus(TestScene::A).is_none());
+
+        context.scene_bus_mut(TestScene::A);
+        assert!(context.scene_bus(TestScene::A).is_some());
+    }
+
+    #[test]
+    fn end_frame_clears_scene_bus_oneshots() {
+        let mut context = GlobalContext::new();
+        context.scene_bus_mut(TestScene::A).push_oneshot(Ping(1));
+        context.scene_bus_mut(TestScene::A).push(7_u8);
+
+        context.end_frame();
+
+        let bus = context.scene_bus(TestScene::A).unwrap();
+        assert!(bus.read::<Ping>().is_empty());
+        assert_eq!(bus.read::<u8>(), &[7]);
+    }
 }
@@ -9,10 +9,14 @@
 //
 //=========================================================================
 
+//=== External Dependencies ===============================================
+
+use std::time::Instant;
+
 //=== Internal Dependencies ===============================================
 
-use super::GlobalContext;
-use crate::core::input::{Action, InputSystem};
+use super::{GlobalContext, SystemTimings};
+use crate::core::input::{Action, ActionEvent, InputContext, InputSystem};
 use crate::core::scene::{SceneKey, SceneManager};
 
 //=== GlobalSystems =======================================================
@@ -38,6 +42,13 @@ pub struct GlobalSystems<S: SceneKey, A: Action> {
     /// Manages scene registration, activation, updates, and transitions.
     /// Processes scene transition queue from GlobalContext.
     pub scene_manager: SceneManager<S>,
+
+    /// Top scene when the scene-declared input context was last synced.
+    context_top: Option<S>,
+
+    /// Context replaced by the top scene's declared one, restored when it
+    /// stops being top.
+    replaced_context: Option<InputContext>,
 }
 
 impl<S: SceneKey, A: Action> GlobalSystems<S, A> {
@@ -49,6 +60,8 @@ impl<S: SceneKey, A: Action> GlobalSystems<S, A> {
         Self {
             input: InputSystem::new(),
             scene_manager: SceneManager::new(),
+            context_top: None,
+            replaced_context: None,
         }
     }
 
@@ -62,32 +75,518 @@ impl<S: SceneKey, A: Action> GlobalSystems<S, A> {
     ///
     /// # Processing Pipeline
     ///
-    /// 1. **Input Processing**: Converts platform events to input state and actions
-    /// 2. **Action Publishing**: Clears stale actions, publishes fresh actions to message bus
-    /// 3. **Scene Update**: Updates all active scenes with current context
-    /// 4. **Transition Processing**: Applies queued scene transitions
+    /// 1. **Input Processing**: Applies the top scene's declared
+    ///    [`input_context`](crate::core::scene::Scene::input_context) if the
+    ///    top changed, then any input context switch requested via
+    ///    [`GlobalContext::set_input_context`] last tick, then converts
+    ///    platform events, then injected events, to input state and actions
+    /// 2. **Action Publishing**: Clears stale actions, publishes fresh actions
+    ///    (per the input system's publish policy) and phased [`ActionEvent`]s
+    ///    to message bus
+    /// 3. **Begin Frame**: Clears frame-scoped flags (e.g. input consumed)
+    /// 4. **Scene Update**: Updates all active scenes with current context
+    /// 5. **Transition Processing**: Applies queued scene transitions
+    /// 6. **Late Update**: Runs [`Scene::late_update`](crate::core::scene::Scene::late_update)
+    ///    on the post-transition active scenes
+    /// 7. **End Frame**: Clears frame-scoped flags, one-shot messages and
+    ///    this tick's raw input events
+    ///
+    /// Frame-scoped flags are therefore only observable by scenes during
+    /// steps 4-6 of the tick that set them.
+    ///
+    /// The time spent in steps 1-3, 4, 5 and 6 is recorded in
+    /// [`GlobalContext::system_timings`] once the update completes.
     ///
     /// # Arguments
     ///
     /// * `context` - Shared context containing input state, message bus, events, and transition queue
     pub(crate) fn update(&mut self, context: &mut GlobalContext) {
-        // 1. Process input events into state and actions
+        let start = Instant::now();
+
+        // 1. Apply a context switch declared by a new top scene or requested
+        //    last tick, then process input events into state and actions
+        //    (injected input last)
+        self.sync_scene_input_context();
+        if let Some(input_context) = context.pending_input_context.take() {
+            self.input.set_context(input_context);
+        }
+        context.input_context.set(self.input.current_context());
+
+        let injected = context.injected_input.take();
+        if !injected.is_empty() {
+            context.frame_input_events.push(injected);
+        }
         self.input.process_frame(
             &mut context.input_state,
             &context.frame_input_events
         );
-        context.frame_input_events.clear();
 
         // 2. Clear previous frame's actions and publish fresh ones
         context.message_bus.clear::<A>();
-        for action in self.input.actions() {
-            context.message_bus.push(*action);
+        for action in self.input.published_actions() {
+            context.message_bus.push(action);
+        }
+        context.message_bus.clear::<ActionEvent<A>>();
+        for event in self.input.action_events() {
+            context.message_bus.push(*event);
         }
 
-        // 3. Update active scenes (can read actions from message bus)
+        // 3. Reset frame-scoped flags
+        context.begin_frame();
+
+        // 4. Update active scenes (can read actions from message bus)
+        let input_done = Instant::now();
         self.scene_manager.update(context);
 
-        // 4. Process scene transitions
+        // 5. Process scene transitions
+        let scenes_done = Instant::now();
         self.scene_manager.process_transitions(context);
+
+        // 6. Late update on the settled stack
+        let transitions_done = Instant::now();
+        self.scene_manager.late_update(context);
+
+        // 7. Clear flags and one-shot messages from this frame
+        let late_update_done = Instant::now();
+        context.end_frame();
+
+        context.system_timings = SystemTimings {
+            input: input_done - start,
+            scenes: scenes_done - input_done,
+            transitions: transitions_done - scenes_done,
+            late_update: late_update_done - transitions_done,
+            total: start.elapsed(),
+        };
+    }
+
+    //--- Internal Helpers -------------------------------------------------
+
+    /// Switches to the top scene's declared input context when the top
+    /// changes, restoring the context the previous top replaced.
+    fn sync_scene_input_context(&mut self) {
+        let top = self.scene_manager.top();
+        if top == self.context_top {
+            return;
+        }
+        self.context_top = top;
+
+        if let Some(replaced) = self.replaced_context.take() {
+            self.input.set_context(replaced);
+        }
+        if let Some(declared) = top.and_then(|key| self.scene_manager.scene_input_context(key)) {
+            self.replaced_context = Some(self.input.current_context());
+            self.input.set_context(declared);
+        }
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use crate::core::input::{ActionPublishPolicy, InputContext, KeyCode, Modifiers};
+    use crate::core::input::event::InputEvent;
+    use crate::core::scene::{Scene, SceneTransition};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestScene {
+        Main,
+        Menu,
+    }
+
+    impl SceneKey for TestScene {}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestAction {
+        Jump,
+    }
+
+    impl Action for TestAction {}
+
+    /// Records whether input was already consumed on entry, then consumes it.
+    struct ConsumingScene(Arc<Mutex<Vec<bool>>>);
+
+    impl Scene<TestScene> for ConsumingScene {
+        fn update(&mut self, context: &GlobalContext) {
+            self.0.lock().unwrap().push(context.is_input_consumed());
+            context.consume_input();
+        }
+    }
+
+    #[test]
+    fn consumed_flag_is_cleared_at_start_of_next_tick() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.scene_manager.register_default(TestScene::Main, ConsumingScene(Arc::clone(&seen)));
+        let mut context = GlobalContext::new();
+
+        systems.update(&mut context);
+        systems.update(&mut context);
+
+        assert_eq!(*seen.lock().unwrap(), vec![false, false]);
+    }
+
+    #[test]
+    fn consumed_flag_is_cleared_after_update() {
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.scene_manager.register_default(
+            TestScene::Main,
+            ConsumingScene(Arc::new(Mutex::new(Vec::new()))),
+        );
+        let mut context = GlobalContext::new();
+
+        systems.update(&mut context);
+
+        assert!(!context.is_input_consumed());
+    }
+
+    #[test]
+    fn flag_set_outside_update_does_not_leak_into_scenes() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.scene_manager.register_default(TestScene::Main, ConsumingScene(Arc::clone(&seen)));
+        let mut context = GlobalContext::new();
+        context.consume_input();
+
+        systems.update(&mut context);
+
+        assert_eq!(*seen.lock().unwrap(), vec![false]);
+    }
+
+    #[test]
+    fn oneshot_message_is_visible_for_one_frame() {
+        #[derive(Debug, PartialEq)]
+        struct Explosion;
+
+        struct ReadingScene(Arc<Mutex<Vec<usize>>>);
+
+        impl Scene<TestScene> for ReadingScene {
+            fn update(&mut self, context: &GlobalContext) {
+                self.0.lock().unwrap().push(context.message_bus.count::<Explosion>());
+            }
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.scene_manager.register_default(TestScene::Main, ReadingScene(Arc::clone(&seen)));
+        let mut context = GlobalContext::new();
+
+        context.message_bus.push_oneshot(Explosion);
+        systems.update(&mut context);
+        systems.update(&mut context);
+
+        assert_eq!(*seen.lock().unwrap(), vec![1, 0]);
+        assert!(!context.message_bus.has_messages::<Explosion>());
+    }
+
+    #[test]
+    fn action_helpers_follow_press_hold_release() {
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        let mut context = GlobalContext::new();
+        let mut frame = |context: &mut GlobalContext, events: Vec<InputEvent>| {
+            context.frame_input_events.push(events);
+            systems.update(context);
+            (context.action_pressed(TestAction::Jump), context.action_released(TestAction::Jump))
+        };
+
+        let down = InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE };
+        let up = InputEvent::KeyUp { key: KeyCode::Space, modifiers: Modifiers::NONE };
+
+        assert_eq!(frame(&mut context, vec![down]), (true, false));
+        assert_eq!(frame(&mut context, vec![]), (false, false));
+        assert_eq!(frame(&mut context, vec![up]), (false, true));
+        assert_eq!(frame(&mut context, vec![]), (false, false));
+    }
+
+    #[test]
+    fn injected_input_flows_through_pipeline_next_tick() {
+        struct BotScene;
+
+        impl Scene<TestScene> for BotScene {
+            fn update(&mut self, context: &GlobalContext) {
+                if !context.input_state.is_key_down(KeyCode::Space) {
+                    context.inject_input(InputEvent::KeyDown {
+                        key: KeyCode::Space,
+                        modifiers: Modifiers::NONE,
+                    });
+                }
+            }
+        }
+
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        systems.scene_manager.register_default(TestScene::Main, BotScene);
+        let mut context = GlobalContext::new();
+
+        systems.update(&mut context);
+        assert!(!context.input_state.is_key_down(KeyCode::Space));
+
+        systems.update(&mut context);
+        assert!(context.input_state.is_key_down(KeyCode::Space));
+        assert!(context.action_pressed(TestAction::Jump));
+        assert!(context.injected_input.borrow().is_empty());
+    }
+
+    #[test]
+    fn injected_input_follows_real_input() {
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        let mut context = GlobalContext::new();
+
+        context.frame_input_events.push(vec![InputEvent::KeyUp {
+            key: KeyCode::KeyA,
+            modifiers: Modifiers::NONE,
+        }]);
+        context.inject_input(InputEvent::KeyDown { key: KeyCode::KeyA, modifiers: Modifiers::NONE });
+        systems.update(&mut context);
+
+        assert!(context.input_state.is_key_down(KeyCode::KeyA));
+    }
+
+    #[test]
+    fn scene_sees_this_frames_raw_events_in_order() {
+        struct RecordingScene(Arc<Mutex<Vec<Vec<InputEvent>>>>);
+
+        impl Scene<TestScene> for RecordingScene {
+            fn update(&mut self, context: &GlobalContext) {
+                self.0.lock().unwrap().push(context.frame_events().cloned().collect());
+            }
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.scene_manager.register_default(TestScene::Main, RecordingScene(Arc::clone(&seen)));
+        let mut context = GlobalContext::new();
+        let events = vec![
+            InputEvent::KeyDown { key: KeyCode::KeyH, modifiers: Modifiers::NONE },
+            InputEvent::KeyUp { key: KeyCode::KeyH, modifiers: Modifiers::NONE },
+            InputEvent::KeyDown { key: KeyCode::KeyI, modifiers: Modifiers::NONE },
+        ];
+        let injected = InputEvent::MouseMoved { x: 3.0, y: 4.0 };
+
+        context.frame_input_events.push(events[..2].to_vec());
+        context.frame_input_events.push(events[2..].to_vec());
+        context.inject_input(injected.clone());
+        systems.update(&mut context);
+        systems.update(&mut context);
+
+        let mut expected = events;
+        expected.push(injected);
+        assert_eq!(*seen.lock().unwrap(), vec![expected, Vec::new()]);
+        assert_eq!(context.frame_events().count(), 0);
+    }
+
+    /// Runs one update with `events` and returns the published actions.
+    fn published(
+        systems: &mut GlobalSystems<TestScene, TestAction>,
+        context: &mut GlobalContext,
+        events: Vec<InputEvent>,
+    ) -> Vec<TestAction> {
+        context.frame_input_events.push(events);
+        systems.update(context);
+        context.message_bus.read::<TestAction>().to_vec()
+    }
+
+    #[test]
+    fn once_per_frame_publishes_held_action_every_frame() {
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        systems.input.bind_key(KeyCode::KeyW, TestAction::Jump, InputContext::Primary);
+        systems.input.set_publish_policy(ActionPublishPolicy::OncePerFrame);
+        let mut context = GlobalContext::new();
+
+        let down = |key| InputEvent::KeyDown { key, modifiers: Modifiers::NONE };
+        let held = published(&mut systems, &mut context, vec![down(KeyCode::Space)]);
+        assert_eq!(held, vec![TestAction::Jump]);
+
+        let both = published(&mut systems, &mut context, vec![down(KeyCode::KeyW)]);
+        assert_eq!(both, vec![TestAction::Jump]);
+        assert_eq!(published(&mut systems, &mut context, vec![]), vec![TestAction::Jump]);
+    }
+
+    #[test]
+    fn per_event_publishes_only_on_press() {
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        let mut context = GlobalContext::new();
+        assert_eq!(systems.input.publish_policy(), ActionPublishPolicy::PerEvent);
+
+        let down = InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE };
+        assert_eq!(published(&mut systems, &mut context, vec![down]), vec![TestAction::Jump]);
+        assert!(published(&mut systems, &mut context, vec![]).is_empty());
+    }
+
+    #[test]
+    fn late_update_runs_after_update_on_post_transition_stack() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum Stage {
+            Game,
+            Menu,
+        }
+
+        impl SceneKey for Stage {}
+
+        type Log = Arc<Mutex<Vec<(Stage, &'static str)>>>;
+
+        struct LoggingScene(Stage, Log);
+
+        impl Scene<Stage> for LoggingScene {
+            fn update(&mut self, _context: &GlobalContext) {
+                self.1.lock().unwrap().push((self.0, "update"));
+            }
+
+            fn late_update(&mut self, _context: &GlobalContext) {
+                self.1.lock().unwrap().push((self.0, "late_update"));
+            }
+        }
+
+        let log: Log = Arc::new(Mutex::new(Vec::new()));
+        let mut systems = GlobalSystems::<Stage, TestAction>::new();
+        systems.scene_manager.register_default(Stage::Game, LoggingScene(Stage::Game, Arc::clone(&log)));
+        systems.scene_manager.register_scene(Stage::Menu, LoggingScene(Stage::Menu, Arc::clone(&log)));
+        let mut context = GlobalContext::new();
+        context.message_bus.push(SceneTransition::Push(Stage::Menu));
+
+        systems.update(&mut context);
+
+        // The opaque menu pushed this frame replaces Game in the late phase
+        assert_eq!(*log.lock().unwrap(), vec![
+            (Stage::Game, "update"),
+            (Stage::Menu, "late_update"),
+        ]);
+    }
+
+    #[test]
+    fn scene_context_switch_changes_mapping_next_tick() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum MenuAction {
+            Jump,
+            Confirm,
+        }
+
+        impl Action for MenuAction {}
+
+        const MENU: InputContext = InputContext::custom(1);
+
+        struct MenuScene;
+
+        impl Scene<TestScene> for MenuScene {
+            fn update(&mut self, context: &GlobalContext) {
+                context.set_input_context(MENU);
+            }
+        }
+
+        let mut systems = GlobalSystems::<TestScene, MenuAction>::new();
+        systems.input.bind_key(KeyCode::Space, MenuAction::Jump, InputContext::Primary);
+        systems.input.bind_key(KeyCode::Space, MenuAction::Confirm, MENU);
+        systems.scene_manager.register_default(TestScene::Main, MenuScene);
+        let mut context = GlobalContext::new();
+        let down = InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE };
+        let up = InputEvent::KeyUp { key: KeyCode::Space, modifiers: Modifiers::NONE };
+
+        // Requested this tick, but this tick was already mapped
+        context.frame_input_events.push(vec![down.clone()]);
+        systems.update(&mut context);
+        assert!(context.action_pressed(MenuAction::Jump));
+        assert_eq!(context.current_input_context(), InputContext::Primary);
+
+        context.frame_input_events.push(vec![up]);
+        systems.update(&mut context);
+        assert_eq!(context.current_input_context(), MENU);
+
+        context.frame_input_events.push(vec![down]);
+        systems.update(&mut context);
+        assert!(context.action_pressed(MenuAction::Confirm));
+        assert!(!context.action_pressed(MenuAction::Jump));
+        assert_eq!(systems.input.current_context(), MENU);
+    }
+
+    #[test]
+    fn top_scene_declared_context_switches_mapping() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum MenuAction {
+            Jump,
+            Confirm,
+        }
+
+        impl Action for MenuAction {}
+
+        const MENU: InputContext = InputContext::custom(1);
+
+        struct GameScene;
+
+        impl Scene<TestScene> for GameScene {
+            fn update(&mut self, _context: &GlobalContext) {}
+        }
+
+        struct MenuScene;
+
+        impl Scene<TestScene> for MenuScene {
+            fn update(&mut self, _context: &GlobalContext) {}
+
+            fn input_context(&self) -> Option<InputContext> {
+                Some(MENU)
+            }
+        }
+
+        let mut systems = GlobalSystems::<TestScene, MenuAction>::new();
+        systems.input.bind_key(KeyCode::Space, MenuAction::Jump, InputContext::Primary);
+        systems.input.bind_key(KeyCode::Space, MenuAction::Confirm, MENU);
+        systems.scene_manager.register_default(TestScene::Main, GameScene);
+        systems.scene_manager.register_scene(TestScene::Menu, MenuScene);
+        let mut context = GlobalContext::new();
+
+        fn tap_space(
+            systems: &mut GlobalSystems<TestScene, MenuAction>,
+            context: &mut GlobalContext,
+        ) -> Vec<MenuAction> {
+            let down = InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE };
+            context.frame_input_events.push(vec![down]);
+            systems.update(context);
+            let pressed = context.message_bus.read::<MenuAction>().to_vec();
+
+            let up = InputEvent::KeyUp { key: KeyCode::Space, modifiers: Modifiers::NONE };
+            context.frame_input_events.push(vec![up]);
+            systems.update(context);
+            pressed
+        }
+
+        context.message_bus.push(SceneTransition::Push(TestScene::Menu));
+        systems.update(&mut context);
+        assert_eq!(tap_space(&mut systems, &mut context), vec![MenuAction::Confirm]);
+        assert_eq!(context.current_input_context(), MENU);
+
+        context.message_bus.push(SceneTransition::Remove(TestScene::Menu));
+        systems.update(&mut context);
+        assert_eq!(tap_space(&mut systems, &mut context), vec![MenuAction::Jump]);
+        assert_eq!(context.current_input_context(), InputContext::Primary);
+    }
+
+    #[test]
+    fn update_records_per_step_timings() {
+        struct SlowScene;
+
+        impl Scene<TestScene> for SlowScene {
+            fn update(&mut self, _context: &GlobalContext) {
+                std::thread::sleep(std::time::Duration::from_millis(5));
+            }
+        }
+
+        let mut systems = GlobalSystems::<TestScene, TestAction>::new();
+        systems.scene_manager.register_default(TestScene::Main, SlowScene);
+        let mut context = GlobalContext::new();
+        assert_eq!(context.system_timings(), SystemTimings::default());
+
+        systems.update(&mut context);
+
+        let timings = context.system_timings();
+        assert!(timings.scenes >= std::time::Duration::from_millis(5));
+        assert!(timings.scenes > timings.input);
+        assert!(timings.steps() <= timings.total);
+        assert!(timings.total - timings.steps() < std::time::Duration::from_millis(5));
     }
 }
@@ -6,7 +6,8 @@
 //
 // Architecture:
 //   GlobalSystems: InputSystem + SceneManager (owned by orchestrator)
-//   GlobalContext: StateTracker + MessageBus (passed to scenes)
+//   GlobalContext: StateTracker + MessageBus + Rng (passed to scenes)
+//   SystemTimings: per-step update times, stored on GlobalContext
 //
 //=========================================================================
 
@@ -14,8 +15,12 @@
 
 mod global_context;
 mod global_systems;
+mod rng;
+mod timings;
 
 //=== Public API ==========================================================
 
 pub use global_context::GlobalContext;
 pub use global_systems::GlobalSystems;
+pub use rng::Rng;
+pub use timings::SystemTimings;
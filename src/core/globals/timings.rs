@@ -0,0 +1,61 @@
+//=========================================================================
+// System Timings
+//=========================================================================
+//
+// Per-tick breakdown of where GlobalSystems::update spent its time.
+//
+// Recorded by GlobalSystems::update with one Instant per step boundary and
+// stored on GlobalContext, so a debug overlay scene can show last tick's
+// budget split without any profiler attached.
+//
+//=========================================================================
+
+//=== External Dependencies ===============================================
+
+use std::time::Duration;
+
+//=== SystemTimings =======================================================
+
+/// Wall-clock time spent in each step of one tick's system update.
+///
+/// The steps are contiguous, so their sum is [`total`](Self::total) minus
+/// the brief end-of-frame cleanup. The tick callback, platform sync and
+/// frame pacing are not included.
+///
+/// ```
+/// use aetheric_engine::prelude::*;
+///
+/// struct DebugOverlay;
+///
+/// impl<S: SceneKey> Scene<S> for DebugOverlay {
+///     fn update(&mut self, context: &GlobalContext) {
+///         let timings = context.system_timings();
+///         let _scene_share = timings.scenes.as_secs_f32() / timings.total.as_secs_f32().max(1e-9);
+///         // draw the breakdown ...
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SystemTimings {
+    /// Input processing and action publishing.
+    pub input: Duration,
+
+    /// Scene updates.
+    pub scenes: Duration,
+
+    /// Scene transition processing (including `on_enter`/`on_exit`).
+    pub transitions: Duration,
+
+    /// Late updates on the settled stack.
+    pub late_update: Duration,
+
+    /// Whole system update, from input to end-of-frame cleanup.
+    pub total: Duration,
+}
+
+impl SystemTimings {
+    /// Returns the sum of the four step timings.
+    pub fn steps(&self) -> Duration {
+        self.input + self.scenes + self.transitions + self.late_update
+    }
+}
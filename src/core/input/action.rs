@@ -6,6 +6,8 @@
 //
 // Actions: Opaque identifiers routed by the engine, interpreted by the game.
 // Contexts: Allows different bindings for different game states (gameplay vs menu).
+// Phases: Press/hold/release lifecycle of an action across frames.
+// Publish policy: Whether held inputs republish their action every frame.
 //
 //=========================================================================
 
@@ -53,6 +55,7 @@ pub trait Action: 'static + Send + Copy + Eq + Hash + Debug {}
 ///
 /// - `Primary`: Default context for core gameplay
 /// - `Custom(u32)`: User-defined contexts (0-4,294,967,295)
+/// - `Global`: Fallback bindings consulted in every context
 ///
 /// # Example
 ///
@@ -86,13 +89,21 @@ pub trait Action: 'static + Send + Copy + Eq + Hash + Debug {}
 /// ```
 ///
 /// Context switching is instant. Raw queries (`is_key_down`) work regardless of context.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum InputContext {
     /// Default context for primary gameplay.
+    #[default]
     Primary,
 
     /// User-defined context (menus, dialogue, vehicles, etc.).
     Custom(u32),
+
+    /// Bindings that fire in every context (e.g., "open pause menu").
+    ///
+    /// Consulted only when the active context has no binding for the exact
+    /// key (or button) and modifiers, so context-specific bindings win.
+    /// Bind into it; it is not meant to be activated itself.
+    Global,
 }
 
 impl InputContext {
@@ -108,10 +119,83 @@ impl InputContext {
     }
 }
 
-impl Default for InputContext {
-    fn default() -> Self {
-        Self::Primary
-    }
+//=== ActionPhase =========================================================
+
+/// Lifecycle phase of an action, derived from its bound key or button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionPhase {
+    /// The input went down this frame.
+    Pressed,
+
+    /// The input was already down before this frame and is still down.
+    Held,
+
+    /// The input went up this frame.
+    Released,
+}
+
+//=== ActionEvent =========================================================
+
+/// An action together with its phase for the current frame.
+///
+/// Published to the message bus alongside the plain action each frame, so
+/// gameplay can start a jump on `Pressed` and charge it while `Held`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ActionEvent<A: Action> {
+    /// The bound action.
+    pub action: A,
+
+    /// Phase of the action this frame.
+    pub phase: ActionPhase,
+}
+
+//=== ActionPublishPolicy =================================================
+
+/// Which frames publish a plain action `A` to the message bus.
+///
+/// Either way an action is published at most once per frame, however many
+/// of its inputs are active. Phased [`ActionEvent`]s are unaffected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ActionPublishPolicy {
+    /// Published every frame a bound input is down (pressed or held).
+    ///
+    /// Suits continuous actions such as movement.
+    OncePerFrame,
+
+    /// Published only on the frame a bound input goes down (transition).
+    #[default]
+    PerEvent,
+}
+
+//=== ContextSwitchPolicy =================================================
+
+/// How inputs already down when the active [`InputContext`] changes map.
+///
+/// Only inputs held across the switch are affected; presses made after it
+/// always map under the new context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ContextSwitchPolicy {
+    /// Held inputs map under the new context from the first frame after the
+    /// switch (`Held`/`Released` phases, held publishing, key repeat).
+    #[default]
+    Remap,
+
+    /// Held inputs stay silent until released; the next press maps under
+    /// the new context.
+    ///
+    /// Keeps a key held in one context (e.g. Space opening a menu) from
+    /// driving the other context's action.
+    Isolate,
+}
+
+//=== BindingWarning ======================================================
+
+/// Binding problem reported by [`InputSystem::validate`](super::InputSystem::validate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingWarning {
+    /// The context has bindings but is neither declared nor ever activated,
+    /// so they never fire.
+    UnreachableContext(InputContext),
 }
 
 //=========================================================================
@@ -9,47 +9,66 @@
 //
 // Only bindings in the active context resolve to actions.
 //
+// Key alternatives (WASD + arrows) are bound key by key; the group is
+// recorded separately so bindings_for() can report them together.
+//
+// Repeating keys keep their schedule beside the plain binding; rebinding or
+// unbinding the key drops it.
+//
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 //=== Internal Dependencies ===============================================
 
 use super::{
     action::{Action, InputContext},
-    event::{InputEvent, KeyCode, MouseButton, Modifiers}
+    event::{KeyCode, MouseButton, Modifiers}
 };
+#[cfg(test)]
+use super::event::InputEvent;
 
 //=== ActionMapper ========================================================
 
 /// Maps input events to actions via (key/button, modifiers, context) lookups.
 /// Only bindings in the active context resolve to actions.
-pub(crate) struct ActionMapper<A: Action> {
+///
+/// Built standalone to define a binding profile for
+/// [`InputSystem::add_profile`](super::InputSystem::add_profile).
+pub struct ActionMapper<A: Action> {
     /// Key bindings: (key, modifiers, context) → action
     key_bindings: HashMap<(KeyCode, Modifiers, InputContext), A>,
 
     /// Mouse button bindings: (button, modifiers, context) → action
     mouse_bindings: HashMap<(MouseButton, Modifiers, InputContext), A>,
 
+    /// Keys bound together as alternatives, in binding order
+    key_groups: Vec<(A, InputContext, Vec<KeyCode>)>,
+
+    /// Hold-to-repeat schedules: (key, context) → (initial delay, interval) in ticks
+    key_repeats: HashMap<(KeyCode, InputContext), (u64, u64)>,
+
     /// Currently active input context
     current_context: InputContext,
 }
 
 impl<A: Action> ActionMapper<A> {
     /// Creates a new mapper with Primary context active and no bindings.
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             key_bindings: HashMap::new(),
             mouse_bindings: HashMap::new(),
+            key_groups: Vec::new(),
+            key_repeats: HashMap::new(),
             current_context: InputContext::Primary,
         }
     }
 
     //--- Binding API ------------------------------------------------------
     /// Binds a key to an action (no modifiers).
-    pub(crate) fn bind_key(
+    pub fn bind_key(
         &mut self,
         key: KeyCode,
         action: A,
@@ -59,18 +78,58 @@ impl<A: Action> ActionMapper<A> {
     }
 
     /// Binds a key with modifiers to an action (exact match required).
-    pub(crate) fn bind_key_with_mods(
+    pub fn bind_key_with_mods(
         &mut self,
         key: KeyCode,
         modifiers: Modifiers,
         action: A,
         context: InputContext,
     ) {
+        if modifiers == Modifiers::NONE {
+            self.key_repeats.remove(&(key, context));
+        }
         self.key_bindings.insert((key, modifiers, context), action);
     }
 
+    /// Binds a key (no modifiers) that re-triggers its action while held.
+    ///
+    /// The action triggers on press, again `initial_delay_ticks` ticks later,
+    /// then every `interval_ticks` ticks until release (both at least 1).
+    /// For menu navigation and auto-fire; independent of OS key repeat.
+    pub fn bind_key_repeat(
+        &mut self,
+        key: KeyCode,
+        action: A,
+        context: InputContext,
+        initial_delay_ticks: u64,
+        interval_ticks: u64,
+    ) {
+        self.bind_key(key, action, context);
+        self.key_repeats.insert(
+            (key, context),
+            (initial_delay_ticks.max(1), interval_ticks.max(1)),
+        );
+    }
+
+    /// Binds each of `keys` to the same action (no modifiers) and records
+    /// them as one group of alternatives, e.g. `W` and `ArrowUp` for MoveUp.
+    ///
+    /// Every key triggers the action on its own; the group only affects
+    /// how [`bindings_for`](Self::bindings_for) reports them.
+    pub fn bind_key_alternatives(
+        &mut self,
+        keys: &[KeyCode],
+        action: A,
+        context: InputContext,
+    ) {
+        for &key in keys {
+            self.bind_key(key, action, context);
+        }
+        self.key_groups.push((action, context, keys.to_vec()));
+    }
+
     /// Binds a mouse button to an action (no modifiers).
-    pub(crate) fn bind_mouse(
+    pub fn bind_mouse(
         &mut self,
         button: MouseButton,
         action: A,
@@ -80,7 +139,7 @@ impl<A: Action> ActionMapper<A> {
     }
 
     /// Binds a mouse button with modifiers to an action.
-    pub(crate) fn bind_mouse_with_mods(
+    pub fn bind_mouse_with_mods(
         &mut self,
         button: MouseButton,
         modifiers: Modifiers,
@@ -97,24 +156,30 @@ impl<A: Action> ActionMapper<A> {
         modifiers: Modifiers,
         context: InputContext,
     ) {
+        if modifiers == Modifiers::NONE {
+            self.key_repeats.remove(&(key, context));
+        }
         self.key_bindings.remove(&(key, modifiers, context));
     }
 
     /// Removes key binding without modifiers (does NOT remove modified variants).
-    pub(crate) fn unbind_key(&mut self, key: KeyCode, context: InputContext) {
+    pub fn unbind_key(&mut self, key: KeyCode, context: InputContext) {
         self.unbind_key_with_mods(key, Modifiers::NONE, context);
     }
 
     /// Removes ALL bindings for a key in context (all modifier combinations).
+    #[cfg(test)]
     pub(crate) fn unbind_key_all_variants(
         &mut self,
         key: KeyCode,
         context: InputContext,
     ) {
         self.key_bindings.retain(|&(k, _, ctx), _| !(k == key && ctx == context));
+        self.key_repeats.remove(&(key, context));
     }
 
     /// Removes ALL bindings for a mouse button in context (all modifier combinations).
+    #[cfg(test)]
     pub(crate) fn unbind_mouse_all_variants(
         &mut self,
         button: MouseButton,
@@ -123,32 +188,133 @@ impl<A: Action> ActionMapper<A> {
         self.mouse_bindings.retain(|&(btn, _, ctx), _| !(btn == button && ctx == context));
     }
 
-    /// Removes a specific mouse button binding (exact modifier match).
-    pub(crate) fn unbind_mouse_with_mods(
-        &mut self,
-        button: MouseButton,
-        modifiers: Modifiers,
-        context: InputContext,
-    ) {
-        self.mouse_bindings.remove(&(button, modifiers, context));
-    }
-
-    /// Removes mouse button binding without modifiers (does NOT remove modified variants).
-    pub(crate) fn unbind_mouse(&mut self, button: MouseButton, context: InputContext) {
-        self.unbind_mouse_with_mods(button, Modifiers::NONE, context);
-    }
-
     /// Clears all bindings for a context (keys and mouse buttons).
-    pub(crate) fn clear_context(&mut self, context: InputContext) {
+    pub fn clear_context(&mut self, context: InputContext) {
         self.key_bindings.retain(|&(_, _, ctx), _| ctx != context);
         self.mouse_bindings.retain(|&(_, _, ctx), _| ctx != context);
+        self.key_groups.retain(|&(_, ctx, _)| ctx != context);
+        self.key_repeats.retain(|&(_, ctx), _| ctx != context);
+    }
+
+    /// Removes every key and mouse binding mapped to `action` in `context`
+    /// (all modifier combinations), e.g. before rebinding it from a menu.
+    ///
+    /// Other actions and contexts are unaffected.
+    pub fn unbind_action(&mut self, action: A, context: InputContext) {
+        let key_repeats = &mut self.key_repeats;
+        self.key_bindings.retain(|&(key, _, ctx), &mut a| {
+            let unbind = a == action && ctx == context;
+            if unbind {
+                key_repeats.remove(&(key, ctx));
+            }
+            !unbind
+        });
+        self.mouse_bindings.retain(|&(_, _, ctx), &mut a| !(a == action && ctx == context));
+        self.key_groups.retain(|&(a, ctx, _)| !(a == action && ctx == context));
+    }
+
+    //--- Queries ----------------------------------------------------------
+    /// Returns the distinct actions bound (by key or mouse) in `context`.
+    ///
+    /// Useful for context-sensitive control hints, e.g. listing vehicle
+    /// actions on entering a vehicle.
+    pub fn actions_in_context(&self, context: InputContext) -> HashSet<A> {
+        let keys = self.key_bindings.iter().map(|(&(_, _, ctx), &action)| (ctx, action));
+        let buttons = self.mouse_bindings.iter().map(|(&(_, _, ctx), &action)| (ctx, action));
+
+        keys.chain(buttons)
+            .filter(|&(ctx, _)| ctx == context)
+            .map(|(_, action)| action)
+            .collect()
+    }
+
+    /// Returns every context with at least one key or mouse binding.
+    pub fn bound_contexts(&self) -> HashSet<InputContext> {
+        let keys = self.key_bindings.keys().map(|&(_, _, ctx)| ctx);
+        let buttons = self.mouse_bindings.keys().map(|&(_, _, ctx)| ctx);
+
+        keys.chain(buttons).collect()
+    }
+
+    /// Returns the keys bound to `action` in `context`, grouped.
+    ///
+    /// Keys bound with [`bind_key_alternatives`](Self::bind_key_alternatives)
+    /// come first, one group per call in binding order; any other key bound
+    /// to the action follows as its own single-key group, sorted. Keys since
+    /// unbound or rebound are left out. Modifiers and mouse buttons are not
+    /// reported.
+    pub fn bindings_for(&self, action: A, context: InputContext) -> Vec<Vec<KeyCode>> {
+        let bound = |key: KeyCode| {
+            self.key_bindings.get(&(key, Modifiers::NONE, context)) == Some(&action)
+        };
+
+        let mut grouped = HashSet::new();
+        let mut groups: Vec<Vec<KeyCode>> = self.key_groups
+            .iter()
+            .filter(|&&(a, ctx, _)| a == action && ctx == context)
+            .map(|(_, _, keys)| {
+                keys.iter()
+                    .copied()
+                    .filter(|&key| bound(key) && grouped.insert(key))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|group| !group.is_empty())
+            .collect();
+
+        let mut singles: Vec<KeyCode> = self.key_bindings
+            .iter()
+            .filter(|&(&(key, _, ctx), &a)| a == action && ctx == context && !grouped.contains(&key))
+            .map(|(&(key, _, _), _)| key)
+            .collect();
+        singles.sort();
+        singles.dedup();
+
+        groups.extend(singles.into_iter().map(|key| vec![key]));
+        groups
+    }
+
+    /// Returns the key binding to show for `action` in `context`.
+    ///
+    /// The first key of [`bindings_for`](Self::bindings_for) bound without
+    /// modifiers if there is one, otherwise the lowest modified binding.
+    pub fn key_binding_for(&self, action: A, context: InputContext) -> Option<(KeyCode, Modifiers)> {
+        let unmodified = self.bindings_for(action, context)
+            .into_iter()
+            .flatten()
+            .find(|&key| self.key_bindings.get(&(key, Modifiers::NONE, context)) == Some(&action));
+        if let Some(key) = unmodified {
+            return Some((key, Modifiers::NONE));
+        }
+
+        self.key_bindings
+            .iter()
+            .filter(|&(&(_, _, ctx), &a)| a == action && ctx == context)
+            .map(|(&(key, modifiers, _), _)| (key, modifiers))
+            .min_by_key(|&(key, m)| (key, m.ctrl, m.shift, m.alt))
+    }
+
+    /// Returns the mouse binding to show for `action` in `context`.
+    ///
+    /// Unmodified bindings come first, then the lowest button.
+    pub fn button_binding_for(
+        &self,
+        action: A,
+        context: InputContext,
+    ) -> Option<(MouseButton, Modifiers)> {
+        self.mouse_bindings
+            .iter()
+            .filter(|&(&(_, _, ctx), &a)| a == action && ctx == context)
+            .map(|(&(button, modifiers, _), _)| (button, modifiers))
+            .min_by_key(|&(button, m)| (!m.is_empty(), button, m.ctrl, m.shift, m.alt))
     }
 
     //--- Event Mapping ----------------------------------------------------
     /// Maps an input event to an action in the active context.
+    #[cfg(test)]
     pub(crate) fn map_event(&self, event: &InputEvent) -> Option<A> {
         match event {
-            InputEvent::KeyDown { key, modifiers } => {
+            InputEvent::KeyDown { key, modifiers }
+            | InputEvent::KeyTapped { key, modifiers } => {
                 self.map_key(*key, *modifiers)
             }
             InputEvent::MouseButtonDown { button, modifiers } => {
@@ -159,29 +325,46 @@ impl<A: Action> ActionMapper<A> {
     }
 
     //--- Internal Mapping Helpers -----------------------------------------
-    /// Maps a key press to an action.
+    /// Maps a key press to an action, falling back to [`InputContext::Global`].
     pub(super) fn map_key(&self, key: KeyCode, modifiers: Modifiers) -> Option<A> {
-        let binding_key = (key, modifiers, self.current_context);
-        self.key_bindings.get(&binding_key).copied()
+        let lookup = |ctx| self.key_bindings.get(&(key, modifiers, ctx)).copied();
+        lookup(self.current_context).or_else(|| lookup(InputContext::Global))
     }
 
-    /// Maps a mouse button press to an action.
+    /// Returns the (initial delay, interval) repeat schedule of `key` in the
+    /// context its unmodified binding resolves from.
+    pub(super) fn repeat_for(&self, key: KeyCode) -> Option<(u64, u64)> {
+        let context = if self.key_bindings.contains_key(&(key, Modifiers::NONE, self.current_context)) {
+            self.current_context
+        } else {
+            InputContext::Global
+        };
+        self.key_repeats.get(&(key, context)).copied()
+    }
+
+    /// Maps a mouse button press to an action, falling back to [`InputContext::Global`].
     pub(super) fn map_button(&self, btn: MouseButton, modifiers: Modifiers) -> Option<A> {
-        let binding_key = (btn, modifiers, self.current_context);
-        self.mouse_bindings.get(&binding_key).copied()
+        let lookup = |ctx| self.mouse_bindings.get(&(btn, modifiers, ctx)).copied();
+        lookup(self.current_context).or_else(|| lookup(InputContext::Global))
     }
 
     /// Sets the active input context.
-    pub(crate) fn set_context(&mut self, context: InputContext) {
+    pub fn set_context(&mut self, context: InputContext) {
         self.current_context = context;
     }
 
     /// Returns the current active context.
-    pub(crate) fn current_context(&self) -> InputContext {
+    pub fn current_context(&self) -> InputContext {
         self.current_context
     }
 }
 
+impl<A: Action> Default for ActionMapper<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 //=========================================================================
 // Unit Tests
 //=========================================================================
@@ -622,4 +805,187 @@ mod tests {
         let event = InputEvent::MouseMoved { x: 100.0, y: 200.0 };
         assert_eq!(mapper.map_event(&event), None);
     }
+
+    //=====================================================================
+    // Query Tests
+    //=====================================================================
+
+    /// Verifies each context reports only its own distinct actions.
+    #[test]
+    fn actions_in_context_filters_by_context() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        let vehicle = InputContext::custom(1);
+
+        mapper.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        mapper.bind_key(KeyCode::KeyW, TestAction::Jump, InputContext::Primary);
+        mapper.bind_mouse(MouseButton::Left, TestAction::Shoot, InputContext::Primary);
+        mapper.bind_key_with_mods(KeyCode::KeyS, Modifiers::CTRL, TestAction::Save, vehicle);
+
+        assert_eq!(
+            mapper.actions_in_context(InputContext::Primary),
+            HashSet::from([TestAction::Jump, TestAction::Shoot])
+        );
+        assert_eq!(mapper.actions_in_context(vehicle), HashSet::from([TestAction::Save]));
+        assert!(mapper.actions_in_context(InputContext::custom(2)).is_empty());
+    }
+
+    //=====================================================================
+    // Key Alternatives Tests
+    //=====================================================================
+
+    /// Verifies every key of a group triggers the action.
+    #[test]
+    fn key_alternatives_all_trigger_action() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        let keys = [KeyCode::KeyW, KeyCode::ArrowUp];
+
+        mapper.bind_key_alternatives(&keys, TestAction::Jump, InputContext::Primary);
+
+        for key in keys {
+            assert_eq!(mapper.map_event(&key_down(key)), Some(TestAction::Jump));
+        }
+    }
+
+    /// Verifies groups are reported together, ahead of individual bindings.
+    #[test]
+    fn bindings_for_reports_groups_together() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        let menu = InputContext::custom(1);
+
+        mapper.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        mapper.bind_key_alternatives(
+            &[KeyCode::KeyW, KeyCode::ArrowUp],
+            TestAction::Jump,
+            InputContext::Primary,
+        );
+        mapper.bind_key(KeyCode::KeyS, TestAction::Save, InputContext::Primary);
+        mapper.bind_key_alternatives(&[KeyCode::Enter], TestAction::Jump, menu);
+
+        assert_eq!(
+            mapper.bindings_for(TestAction::Jump, InputContext::Primary),
+            vec![vec![KeyCode::KeyW, KeyCode::ArrowUp], vec![KeyCode::Space]]
+        );
+        assert_eq!(mapper.bindings_for(TestAction::Jump, menu), vec![vec![KeyCode::Enter]]);
+    }
+
+    /// Verifies rebinding or clearing drops keys from reported groups.
+    #[test]
+    fn bindings_for_skips_keys_no_longer_bound() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        mapper.bind_key_alternatives(
+            &[KeyCode::KeyW, KeyCode::ArrowUp],
+            TestAction::Jump,
+            InputContext::Primary,
+        );
+
+        mapper.bind_key(KeyCode::ArrowUp, TestAction::Shoot, InputContext::Primary);
+        assert_eq!(
+            mapper.bindings_for(TestAction::Jump, InputContext::Primary),
+            vec![vec![KeyCode::KeyW]]
+        );
+
+        mapper.clear_context(InputContext::Primary);
+        assert!(mapper.bindings_for(TestAction::Jump, InputContext::Primary).is_empty());
+    }
+
+    #[test]
+    fn key_binding_for_prefers_unmodified_keys() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        mapper.bind_key_with_mods(KeyCode::KeyA, Modifiers::CTRL, TestAction::Save, InputContext::Primary);
+        assert_eq!(
+            mapper.key_binding_for(TestAction::Save, InputContext::Primary),
+            Some((KeyCode::KeyA, Modifiers::CTRL))
+        );
+
+        mapper.bind_key(KeyCode::KeyS, TestAction::Save, InputContext::Primary);
+        assert_eq!(
+            mapper.key_binding_for(TestAction::Save, InputContext::Primary),
+            Some((KeyCode::KeyS, Modifiers::NONE))
+        );
+    }
+
+    //=====================================================================
+    // Unbind By Action Tests
+    //=====================================================================
+
+    /// Verifies every key and button bound to the action stops firing.
+    #[test]
+    fn unbind_action_removes_all_its_bindings() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        mapper.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        mapper.bind_key_with_mods(KeyCode::KeyW, Modifiers::SHIFT, TestAction::Jump, InputContext::Primary);
+        mapper.bind_mouse(MouseButton::Right, TestAction::Jump, InputContext::Primary);
+        mapper.bind_key(KeyCode::KeyF, TestAction::Shoot, InputContext::Primary);
+
+        mapper.unbind_action(TestAction::Jump, InputContext::Primary);
+
+        assert_eq!(mapper.map_event(&key_down(KeyCode::Space)), None);
+        assert_eq!(mapper.map_event(&key_down_with_mods(KeyCode::KeyW, Modifiers::SHIFT)), None);
+        assert_eq!(mapper.map_event(&mouse_down(MouseButton::Right)), None);
+        assert_eq!(mapper.map_event(&key_down(KeyCode::KeyF)), Some(TestAction::Shoot));
+        assert!(mapper.bindings_for(TestAction::Jump, InputContext::Primary).is_empty());
+    }
+
+    /// Verifies the same action in another context keeps its bindings.
+    #[test]
+    fn unbind_action_is_context_specific() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        let menu = InputContext::custom(1);
+        mapper.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        mapper.bind_key(KeyCode::Enter, TestAction::Jump, menu);
+
+        mapper.unbind_action(TestAction::Jump, InputContext::Primary);
+        mapper.set_context(menu);
+
+        assert_eq!(mapper.map_event(&key_down(KeyCode::Enter)), Some(TestAction::Jump));
+    }
+
+    //=====================================================================
+    // Global Context Tests
+    //=====================================================================
+
+    /// Verifies Global bindings fire in Primary and custom contexts.
+    #[test]
+    fn global_binding_fires_in_every_context() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        mapper.bind_key(KeyCode::Escape, TestAction::Save, InputContext::Global);
+        mapper.bind_mouse(MouseButton::Middle, TestAction::Save, InputContext::Global);
+
+        for context in [InputContext::Primary, InputContext::custom(3)] {
+            mapper.set_context(context);
+            assert_eq!(mapper.map_event(&key_down(KeyCode::Escape)), Some(TestAction::Save));
+            assert_eq!(mapper.map_event(&mouse_down(MouseButton::Middle)), Some(TestAction::Save));
+        }
+    }
+
+    /// Verifies a context-specific binding overrides the Global one.
+    #[test]
+    fn context_binding_overrides_global() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        let menu = InputContext::custom(0);
+        mapper.bind_key(KeyCode::Escape, TestAction::Save, InputContext::Global);
+        mapper.bind_key(KeyCode::Escape, TestAction::Jump, menu);
+
+        assert_eq!(mapper.map_event(&key_down(KeyCode::Escape)), Some(TestAction::Save));
+
+        mapper.set_context(menu);
+        assert_eq!(mapper.map_event(&key_down(KeyCode::Escape)), Some(TestAction::Jump));
+
+        mapper.unbind_key(KeyCode::Escape, menu);
+        assert_eq!(mapper.map_event(&key_down(KeyCode::Escape)), Some(TestAction::Save));
+    }
+
+    /// Verifies modifier variants fall back independently.
+    #[test]
+    fn global_fallback_matches_exact_modifiers() {
+        let mut mapper = ActionMapper::<TestAction>::new();
+        mapper.bind_key_with_mods(KeyCode::KeyS, Modifiers::CTRL, TestAction::Save, InputContext::Global);
+        mapper.bind_key(KeyCode::KeyS, TestAction::Shoot, InputContext::Primary);
+
+        assert_eq!(mapper.map_event(&key_down(KeyCode::KeyS)), Some(TestAction::Shoot));
+        assert_eq!(
+            mapper.map_event(&key_down_with_mods(KeyCode::KeyS, Modifiers::CTRL)),
+            Some(TestAction::Save)
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,163 @@
+//=========================================================================
+// Axis Response
+//=========================================================================
+//
+// Dead-zone and response-curve shaping for analog axis values.
+//
+// Pipeline (per value in -1.0..=1.0):
+//   |v| <= dead_zone → 0.0
+//   otherwise rescale (dead_zone..1.0) → (0.0..1.0), apply curve, restore sign
+//
+// The engine does not read analog devices yet; the shaping is exposed so
+// games feeding their own stick values get the same behavior axis input
+// will use.
+//
+//=========================================================================
+
+//=== ResponseCurve =======================================================
+
+/// Maps a normalized axis magnitude (`0.0..=1.0`) to an output magnitude.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResponseCurve {
+    /// Output equals input.
+    #[default]
+    Linear,
+
+    /// Output is the input squared; finer control near center.
+    Quadratic,
+
+    /// Output is the input raised to a custom exponent.
+    Exponent(f32),
+}
+
+impl ResponseCurve {
+    /// Applies the curve to a magnitude in `0.0..=1.0`.
+    pub fn apply(self, magnitude: f32) -> f32 {
+        match self {
+            Self::Linear => magnitude,
+            Self::Quadratic => magnitude * magnitude,
+            Self::Exponent(exponent) => magnitude.powf(exponent),
+        }
+    }
+}
+
+//=== AxisResponse ========================================================
+
+/// Per-axis dead-zone and response curve.
+///
+/// Values inside the dead-zone become `0.0`; values outside it are rescaled
+/// so the curve starts at zero on the dead-zone edge and reaches `±1.0` at
+/// full deflection.
+///
+/// ```
+/// use aetheric_engine::core::input::{AxisResponse, ResponseCurve};
+///
+/// let response = AxisResponse::default()
+///     .with_dead_zone(0.1)
+///     .with_curve(ResponseCurve::Quadratic);
+///
+/// assert_eq!(response.apply(0.05), 0.0);
+/// assert_eq!(response.apply(-1.0), -1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AxisResponse {
+    dead_zone: f32,
+    curve: ResponseCurve,
+}
+
+impl AxisResponse {
+    /// Sets the dead-zone radius (clamped to `0.0..=1.0`).
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the response curve applied outside the dead-zone.
+    pub fn with_curve(mut self, curve: ResponseCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Returns the dead-zone radius.
+    pub fn dead_zone(&self) -> f32 {
+        self.dead_zone
+    }
+
+    /// Returns the response curve.
+    pub fn curve(&self) -> ResponseCurve {
+        self.curve
+    }
+
+    /// Shapes a raw axis value; input is clamped to `-1.0..=1.0`.
+    pub fn apply(&self, value: f32) -> f32 {
+        let magnitude = value.abs().min(1.0);
+        if magnitude <= self.dead_zone {
+            return 0.0;
+        }
+
+        let rescaled = (magnitude - self.dead_zone) / (1.0 - self.dead_zone);
+        self.curve.apply(rescaled).copysign(value)
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn default_is_identity() {
+        let response = AxisResponse::default();
+        assert_eq!(response.apply(0.3), 0.3);
+        assert_eq!(response.apply(-0.7), -0.7);
+    }
+
+    #[test]
+    fn values_inside_dead_zone_are_zero() {
+        let response = AxisResponse::default().with_dead_zone(0.1);
+        assert_eq!(response.apply(0.05), 0.0);
+        assert_eq!(response.apply(-0.1), 0.0);
+    }
+
+    #[test]
+    fn output_is_rescaled_from_dead_zone_edge() {
+        let response = AxisResponse::default().with_dead_zone(0.2);
+        assert!(approx(response.apply(0.6), 0.5));
+        assert!(approx(response.apply(-0.6), -0.5));
+        assert_eq!(response.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn quadratic_curve_squares_magnitude() {
+        let response = AxisResponse::default().with_curve(ResponseCurve::Quadratic);
+        assert!(approx(response.apply(0.5), 0.25));
+        assert!(approx(response.apply(-0.5), -0.25));
+    }
+
+    #[test]
+    fn custom_exponent_curve() {
+        let response = AxisResponse::default().with_curve(ResponseCurve::Exponent(3.0));
+        assert!(approx(response.apply(0.5), 0.125));
+    }
+
+    #[test]
+    fn input_is_clamped_to_unit_range() {
+        let response = AxisResponse::default();
+        assert_eq!(response.apply(1.5), 1.0);
+        assert_eq!(response.apply(-3.0), -1.0);
+    }
+
+    #[test]
+    fn full_dead_zone_silences_axis() {
+        let response = AxisResponse::default().with_dead_zone(2.0);
+        assert_eq!(response.dead_zone(), 1.0);
+        assert_eq!(response.apply(1.0), 0.0);
+    }
+}
@@ -12,12 +12,15 @@
 
 //=== External Dependencies ===============================================
 
+use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::{BitAnd, BitOr};
 
 //=== MouseButton =========================================================
 
 /// Physical mouse button identifier.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseButton {
     /// Primary button (typically left).
     Left,
@@ -35,6 +38,32 @@ pub enum MouseButton {
     Other
 }
 
+//--- Implementation ------------------------------------------------------
+
+impl MouseButton {
+    /// Every button, in declaration order (for remapping UIs and tests).
+    pub const ALL: &'static [MouseButton] = &[
+        MouseButton::Left,
+        MouseButton::Right,
+        MouseButton::Middle,
+        MouseButton::Other,
+    ];
+}
+
+//--- Trait Implementations -----------------------------------------------
+
+/// Formats as a prompt label (`"Left Mouse"`).
+impl fmt::Display for MouseButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MouseButton::Left => "Left Mouse",
+            MouseButton::Right => "Right Mouse",
+            MouseButton::Middle => "Middle Mouse",
+            MouseButton::Other => "Mouse Button",
+        })
+    }
+}
+
 //=== KeyCode =============================================================
 
 /// Physical keyboard key identifier based on key position, not character output.
@@ -51,7 +80,8 @@ pub enum MouseButton {
 /// - **Cross-platform**: Platform layer normalizes key codes
 ///
 /// For text input (chat, names, etc.), you'll need character events (future API).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeyCode {
     //--- Numeric Keys -----------------------------------------------------
 
@@ -98,6 +128,64 @@ pub enum KeyCode {
     Unidentified
 }
 
+//--- Implementation ------------------------------------------------------
+
+impl KeyCode {
+    /// Every key except [`Unidentified`](Self::Unidentified), in declaration
+    /// order (for remapping UIs and tests).
+    ///
+    /// Must list new variants too; a unit test checks it against the enum.
+    pub const ALL: &'static [KeyCode] = &[
+        KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+        KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+        KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE,
+        KeyCode::KeyF, KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ,
+        KeyCode::KeyK, KeyCode::KeyL, KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO,
+        KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR, KeyCode::KeyS, KeyCode::KeyT,
+        KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX, KeyCode::KeyY,
+        KeyCode::KeyZ,
+        KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight, KeyCode::ArrowUp,
+        KeyCode::Space,
+        KeyCode::Enter,
+        KeyCode::Escape,
+        KeyCode::Tab,
+        KeyCode::Backspace,
+        KeyCode::Delete,
+    ];
+
+    /// Returns `true` for modifier keys (Shift, Ctrl, Alt).
+    ///
+    /// Modifiers have no `KeyCode` yet: the platform reports them only
+    /// through [`Modifiers`], so this is currently `false` for every key.
+    pub fn is_modifier(self) -> bool {
+        false
+    }
+}
+
+//--- Trait Implementations -----------------------------------------------
+
+/// Formats as a short key name (`"S"`, `"7"`, `"Up"`, `"Esc"`) that
+/// [`parse_binding`](super::parse_binding) accepts back.
+impl fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            KeyCode::ArrowDown => "Down",
+            KeyCode::ArrowLeft => "Left",
+            KeyCode::ArrowRight => "Right",
+            KeyCode::ArrowUp => "Up",
+            KeyCode::Escape => "Esc",
+            KeyCode::Unidentified => "Unknown",
+            _ => {
+                // Letters and digits drop their variant prefix
+                let name = format!("{:?}", self);
+                let short = name.strip_prefix("Key").or_else(|| name.strip_prefix("Digit"));
+                return f.write_str(short.unwrap_or(&name));
+            }
+        };
+        f.write_str(name)
+    }
+}
+
 //=== InputEvent ==========================================================
 
 /// Low-level input event from the platform layer.
@@ -116,6 +204,24 @@ pub enum InputEvent {
         modifiers: Modifiers,
     },
 
+    /// Character the key produced under the current keyboard layout.
+    ///
+    /// Sent just before the matching `KeyDown` when the platform reports a
+    /// single-character logical key (e.g., `KeyQ` → `'a'` on AZERTY).
+    KeyChar {
+        key: KeyCode,
+        ch: char,
+    },
+
+    /// Key pressed and released within one frame (coalesced `KeyDown` + `KeyUp`).
+    ///
+    /// Only produced when tap coalescing is enabled. Carries the modifiers
+    /// at release time.
+    KeyTapped {
+        key: KeyCode,
+        modifiers: Modifiers,
+    },
+
     /// Mouse button pressed.
     MouseButtonDown {
         button: MouseButton,
@@ -131,6 +237,34 @@ pub enum InputEvent {
     /// Mouse cursor moved (screen space, pixels, top-left origin).
     MouseMoved { x: f32, y: f32 },
 
+    /// Cursor repositioned by the platform (relative mouse mode).
+    ///
+    /// Moves the tracked position without producing a mouse delta.
+    CursorWarped { x: f32, y: f32 },
+
+    /// Mouse wheel scrolled, in lines (positive `dy` scrolls up/away).
+    ///
+    /// Continuous: scrolls within one frame accumulate.
+    MouseScrolled { dx: f32, dy: f32 },
+
+    /// Raw, unaccelerated motion reported by the mouse device.
+    ///
+    /// Continuous: motion within one frame accumulates. Keeps reporting
+    /// while the cursor is pinned at a screen edge.
+    MouseMotion { dx: f32, dy: f32 },
+
+    /// Window client area resized (physical pixels).
+    ///
+    /// The first cursor position after a resize re-anchors the mouse delta
+    /// instead of registering as motion.
+    WindowResized { width: f32, height: f32 },
+
+    /// Mouse cursor entered a window's client area.
+    CursorEntered,
+
+    /// Mouse cursor left a window's client area.
+    CursorLeft,
+
     /// Unrecognized event (silently ignored).
     Unidentified
 }
@@ -138,11 +272,22 @@ pub enum InputEvent {
 //--- Implementation ------------------------------------------------------
 
 impl InputEvent {
+    /// Returns `true` for events coalesced per frame rather than queued
+    /// (cursor position, scroll, raw motion).
+    pub(crate) fn is_continuous(&self) -> bool {
+        matches!(
+            self,
+            Self::MouseMoved { .. } | Self::MouseScrolled { .. } | Self::MouseMotion { .. }
+        )
+    }
+
     /// Returns a new event with updated modifiers (internal utility).
+    #[cfg(test)]
     pub(crate) fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
         match &mut self {
             Self::KeyDown { modifiers: m, .. }
             | Self::KeyUp { modifiers: m, .. }
+            | Self::KeyTapped { modifiers: m, .. }
             | Self::MouseButtonDown { modifiers: m, .. }
             | Self::MouseButtonUp { modifiers: m, .. } => {
                 *m = modifiers;
@@ -166,6 +311,10 @@ impl PartialEq for InputEvent {
             (KeyUp { key: a, modifiers: ma }, KeyUp { key: b, modifiers: mb }) => {
                 a == b && ma == mb
             }
+            (KeyTapped { key: a, modifiers: ma }, KeyTapped { key: b, modifiers: mb }) => {
+                a == b && ma == mb
+            }
+            (KeyChar { key: a, ch: ca }, KeyChar { key: b, ch: cb }) => a == b && ca == cb,
             (
                 MouseButtonDown { button: a, modifiers: ma },
                 MouseButtonDown { button: b, modifiers: mb }
@@ -180,6 +329,19 @@ impl PartialEq for InputEvent {
             }
             // MouseMoved: coordinates ignored, always equal
             (MouseMoved { .. }, MouseMoved { .. }) => true,
+            (CursorWarped { x: xa, y: ya }, CursorWarped { x: xb, y: yb }) => {
+                xa.to_bits() == xb.to_bits() && ya.to_bits() == yb.to_bits()
+            }
+            (MouseScrolled { dx: xa, dy: ya }, MouseScrolled { dx: xb, dy: yb })
+            | (MouseMotion { dx: xa, dy: ya }, MouseMotion { dx: xb, dy: yb })
+            | (
+                WindowResized { width: xa, height: ya },
+                WindowResized { width: xb, height: yb }
+            ) => {
+                xa.to_bits() == xb.to_bits() && ya.to_bits() == yb.to_bits()
+            }
+            (CursorEntered, CursorEntered) => true,
+            (CursorLeft, CursorLeft) => true,
             (Unidentified, Unidentified) => true,
             _ => false,
         }
@@ -196,7 +358,9 @@ impl Hash for InputEvent {
 
         // Hash payload based on variant
         match self {
-            Self::KeyDown { key, modifiers } | Self::KeyUp { key, modifiers } => {
+            Self::KeyDown { key, modifiers }
+            | Self::KeyUp { key, modifiers }
+            | Self::KeyTapped { key, modifiers } => {
                 key.hash(state);
                 modifiers.hash(state);
             }
@@ -205,7 +369,18 @@ impl Hash for InputEvent {
                 button.hash(state);
                 modifiers.hash(state);
             }
-            // MouseMoved and Unidentified: only discriminant matters
+            Self::KeyChar { key, ch } => {
+                key.hash(state);
+                ch.hash(state);
+            }
+            Self::CursorWarped { x, y }
+            | Self::MouseScrolled { dx: x, dy: y }
+            | Self::MouseMotion { dx: x, dy: y }
+            | Self::WindowResized { width: x, height: y } => {
+                x.to_bits().hash(state);
+                y.to_bits().hash(state);
+            }
+            // MouseMoved, cursor crossings and Unidentified: only discriminant matters
             _ => {}
         }
     }
@@ -253,6 +428,7 @@ impl Hash for InputEvent {
 /// // Pressing Ctrl+Shift+S triggers only SaveAs (not Save)
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Modifiers {
     pub shift: bool,
     pub ctrl: bool,
@@ -319,6 +495,46 @@ impl Modifiers {
     };
 }
 
+//--- Set Operations ------------------------------------------------------
+
+impl Modifiers {
+    /// Returns `true` if every modifier in `other` is also held in `self`.
+    ///
+    /// `Modifiers::SHIFT_CTRL.contains(Modifiers::CTRL)` is `true`.
+    #[inline]
+    pub const fn contains(self, other: Modifiers) -> bool {
+        (self.shift || !other.shift)
+            && (self.ctrl || !other.ctrl)
+            && (self.alt || !other.alt)
+    }
+
+    /// Returns modifiers held in either `self` or `other`.
+    #[inline]
+    pub const fn union(self, other: Modifiers) -> Self {
+        Self {
+            shift: self.shift || other.shift,
+            ctrl: self.ctrl || other.ctrl,
+            alt: self.alt || other.alt,
+        }
+    }
+
+    /// Returns modifiers held in both `self` and `other`.
+    #[inline]
+    pub const fn intersection(self, other: Modifiers) -> Self {
+        Self {
+            shift: self.shift && other.shift,
+            ctrl: self.ctrl && other.ctrl,
+            alt: self.alt && other.alt,
+        }
+    }
+
+    /// Returns `true` if no modifiers are held.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        !self.shift && !self.ctrl && !self.alt
+    }
+}
+
 //--- Trait Implementations -----------------------------------------------
 
 impl Default for Modifiers {
@@ -328,6 +544,33 @@ impl Default for Modifiers {
     }
 }
 
+/// Formats held modifiers as `"Ctrl+Shift+Alt"` (empty for none).
+impl fmt::Display for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let held = [(self.ctrl, "Ctrl"), (self.shift, "Shift"), (self.alt, "Alt")];
+        let names: Vec<&str> = held.iter().filter(|(on, _)| *on).map(|&(_, name)| name).collect();
+        f.write_str(&names.join("+"))
+    }
+}
+
+/// `a | b` is [`Modifiers::union`].
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// `a & b` is [`Modifiers::intersection`].
+impl BitAnd for Modifiers {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
 //=========================================================================
 // Unit Tests
 //=========================================================================
@@ -366,6 +609,7 @@ mod tests {
         }
     }
 
+    #[allow(dead_code)]
     fn mouse_up(btn: MouseButton) -> InputEvent {
         InputEvent::MouseButtonUp {
             button: btn,
@@ -727,4 +971,88 @@ mod tests {
         assert_ne!(Modifiers::CTRL, Modifiers::SHIFT_CTRL);
         assert_ne!(Modifiers::ALL, Modifiers::SHIFT_ALT);
     }
+
+    //=====================================================================
+    // Modifiers Set Operation Tests
+    //=====================================================================
+
+    /// Superset contains its subsets; subsets do not contain supersets.
+    #[test]
+    fn modifiers_contains() {
+        assert!(Modifiers::SHIFT_CTRL.contains(Modifiers::CTRL));
+        assert!(Modifiers::SHIFT_CTRL.contains(Modifiers::SHIFT_CTRL));
+        assert!(Modifiers::CTRL.contains(Modifiers::NONE));
+        assert!(!Modifiers::CTRL.contains(Modifiers::SHIFT_CTRL));
+        assert!(!Modifiers::SHIFT_CTRL.contains(Modifiers::ALT));
+    }
+
+    /// Union combines flags; `|` matches `union`.
+    #[test]
+    fn modifiers_union() {
+        assert_eq!(Modifiers::SHIFT.union(Modifiers::ALT), Modifiers::SHIFT_ALT);
+        assert_eq!(Modifiers::SHIFT | Modifiers::ALT, Modifiers::SHIFT_ALT);
+        assert_eq!(Modifiers::SHIFT_CTRL | Modifiers::ALT, Modifiers::ALL);
+        assert_eq!(Modifiers::CTRL | Modifiers::NONE, Modifiers::CTRL);
+    }
+
+    /// Intersection keeps shared flags; `&` matches `intersection`.
+    #[test]
+    fn modifiers_intersection() {
+        assert_eq!(Modifiers::SHIFT_CTRL.intersection(Modifiers::CTRL_ALT), Modifiers::CTRL);
+        assert_eq!(Modifiers::SHIFT_CTRL & Modifiers::CTRL_ALT, Modifiers::CTRL);
+        assert_eq!(Modifiers::SHIFT & Modifiers::ALT, Modifiers::NONE);
+    }
+
+    /// Only NONE is empty.
+    #[test]
+    fn modifiers_is_empty() {
+        assert!(Modifiers::NONE.is_empty());
+        assert!(Modifiers::default().is_empty());
+        assert!(!Modifiers::SHIFT.is_empty());
+        assert!((Modifiers::SHIFT & Modifiers::ALT).is_empty());
+    }
+
+    //=====================================================================
+    // Display Tests
+    //=====================================================================
+
+    #[test]
+    fn keycode_display_uses_short_names() {
+        assert_eq!(KeyCode::KeyS.to_string(), "S");
+        assert_eq!(KeyCode::Digit7.to_string(), "7");
+        assert_eq!(KeyCode::ArrowUp.to_string(), "Up");
+        assert_eq!(KeyCode::Escape.to_string(), "Esc");
+        assert_eq!(KeyCode::Space.to_string(), "Space");
+    }
+
+    #[test]
+    fn modifiers_display_joins_held_keys() {
+        assert_eq!(Modifiers::NONE.to_string(), "");
+        assert_eq!(Modifiers::CTRL.to_string(), "Ctrl");
+        assert_eq!(Modifiers::ALL.to_string(), "Ctrl+Shift+Alt");
+        assert_eq!(MouseButton::Left.to_string(), "Left Mouse");
+    }
+
+    //=====================================================================
+    // Variant List Tests
+    //=====================================================================
+
+    /// KeyCode::ALL lists every key but Unidentified once, in order.
+    #[test]
+    fn keycode_all_covers_every_variant() {
+        // Unidentified is declared last, so its discriminant is the count
+        assert_eq!(KeyCode::ALL.len(), KeyCode::Unidentified as usize);
+        for (index, &key) in KeyCode::ALL.iter().enumerate() {
+            assert_eq!(key as usize, index, "{:?} out of place", key);
+        }
+    }
+
+    /// MouseButton::ALL lists every button once, in order.
+    #[test]
+    fn mousebutton_all_covers_every_variant() {
+        assert_eq!(MouseButton::ALL.len(), MouseButton::Other as usize + 1);
+        for (index, &button) in MouseButton::ALL.iter().enumerate() {
+            assert_eq!(button as usize, index, "{:?} out of place", button);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,173 @@
+//=========================================================================
+// Input Log
+//=========================================================================
+//
+// Fixed-size ring of the most recent raw input events, for diagnostics.
+//
+// Architecture:
+//   StateTracker::process_event() ──record(tick, event)──> InputLog
+//   GlobalContext::recent_input_log() ──> crash report / bug triage
+//
+// Once full, each new event evicts the oldest, so the log always holds a
+// short replay of what the player did last.
+//
+//=========================================================================
+
+//=== External Dependencies ===============================================
+
+use std::collections::VecDeque;
+
+//=== Internal Dependencies ===============================================
+
+use super::event::InputEvent;
+
+//=== Constants ===========================================================
+
+/// Number of events an [`InputLog`] keeps unless configured otherwise.
+pub const DEFAULT_INPUT_LOG_CAPACITY: usize = 256;
+
+//=== InputLogEntry =======================================================
+
+/// One logged event and the tick it was processed on.
+#[derive(Debug, Clone)]
+pub struct InputLogEntry {
+    /// Input tick the event was processed on.
+    pub tick: u64,
+
+    /// The raw event, before debouncing or other filtering.
+    pub event: InputEvent,
+}
+
+//=== InputLog ============================================================
+
+/// Circular log of the last `capacity` input events, oldest first.
+///
+/// ```
+/// use aetheric_engine::core::input::event::InputEvent;
+/// use aetheric_engine::core::input::InputLog;
+///
+/// let mut log = InputLog::new(2);
+/// for x in [1.0, 2.0, 3.0] {
+///     log.record(0, InputEvent::MouseMoved { x, y: 0.0 });
+/// }
+///
+/// assert_eq!(log.len(), 2);
+/// assert!(matches!(log.iter().next().unwrap().event, InputEvent::MouseMoved { x, .. } if x == 2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InputLog {
+    entries: VecDeque<InputLogEntry>,
+    capacity: usize,
+}
+
+impl InputLog {
+    /// Creates an empty log keeping at most `capacity` events (`0` disables it).
+    pub fn new(capacity: usize) -> Self {
+        Self { entries: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    /// Appends `event`, evicting the oldest entry when full.
+    pub fn record(&mut self, tick: u64, event: InputEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(InputLogEntry { tick, event });
+    }
+
+    /// Changes the capacity, dropping the oldest entries if it shrinks.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Returns the maximum number of events kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the logged events, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &InputLogEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of logged events.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been logged.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drops all logged events.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl Default for InputLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_INPUT_LOG_CAPACITY)
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moved(x: f32) -> InputEvent {
+        InputEvent::MouseMoved { x, y: 0.0 }
+    }
+
+    fn logged_xs(log: &InputLog) -> Vec<(u64, f32)> {
+        log.iter()
+            .map(|entry| match entry.event {
+                InputEvent::MouseMoved { x, .. } => (entry.tick, x),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn overflow_keeps_most_recent_events_in_order() {
+        let mut log = InputLog::new(3);
+
+        for tick in 0..5 {
+            log.record(tick, moved(tick as f32));
+        }
+
+        assert_eq!(log.len(), 3);
+        assert_eq!(logged_xs(&log), vec![(2, 2.0), (3, 3.0), (4, 4.0)]);
+    }
+
+    #[test]
+    fn shrinking_drops_oldest_entries() {
+        let mut log = InputLog::new(4);
+        for tick in 0..4 {
+            log.record(tick, moved(tick as f32));
+        }
+
+        log.set_capacity(2);
+        log.record(4, moved(4.0));
+
+        assert_eq!(logged_xs(&log), vec![(3, 3.0), (4, 4.0)]);
+    }
+
+    #[test]
+    fn zero_capacity_records_nothing() {
+        let mut log = InputLog::new(0);
+        log.record(0, moved(1.0));
+
+        assert!(log.is_empty());
+    }
+}
@@ -9,33 +9,63 @@
 //
 // Each frame: clear deltas → process events → finalize → generate actions
 //
+// Bindings live in named profiles (one ActionMapper each); only the active
+// profile's mapper is consulted.
+//
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::mem;
 
-//=== Internal Dependencies ===============================================
+use log::warn;
 
-use action_mapper::ActionMapper;
+//=== Internal Dependencies ===============================================
 
 //=== Module Declarations =================================================
 
 pub mod action;
+pub mod axis;
 pub mod event;
+pub mod input_log;
+pub mod snapshot;
 pub mod state_tracker;
+pub mod toggle;
 
 mod action_mapper;
+mod parse;
 
 //=== Public API ==========================================================
 
-pub use action::{Action, InputContext};
+pub use action::{
+    Action, ActionEvent, ActionPhase, ActionPublishPolicy, BindingWarning, ContextSwitchPolicy,
+    InputContext,
+};
+pub use action_mapper::ActionMapper;
+pub use axis::{AxisResponse, ResponseCurve};
 pub use event::{KeyCode, Modifiers, MouseButton};
+pub use input_log::{InputLog, InputLogEntry, DEFAULT_INPUT_LOG_CAPACITY};
+pub use parse::{parse_binding, ParseBindingError};
+pub use snapshot::{InputDelta, InputSnapshot};
 pub use state_tracker::StateTracker;
+pub use toggle::ToggleState;
 
 //=== Internal API ========================================================
 
 pub(crate) use event::InputEvent;
+pub(crate) use state_tracker::InputObserver;
+
+//=== Constants ===========================================================
+
+/// Name of the binding profile an [`InputSystem`] starts with.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Default double-tap window in ticks (250 ms at 60 TPS).
+///
+/// See [`StateTracker::is_key_double_tapped`].
+pub const DEFAULT_DOUBLE_TAP_TICKS: u64 = 15;
 
 //=== InputSystem =========================================================
 
@@ -105,11 +135,74 @@ pub(crate) use event::InputEvent;
 /// // }
 /// ```
 pub struct InputSystem<A: Action> {
-    /// Action mapping system (bindings, contexts)
+    /// Action mapping system (bindings, contexts) of the active profile
     mapper: ActionMapper<A>,
 
+    /// Name of the profile currently held in `mapper`
+    active_profile: String,
+
+    /// Inactive binding profiles by name
+    profiles: HashMap<String, ActionMapper<A>>,
+
     /// Actions triggered this frame (generated by process_frame)
     current_actions: Vec<A>,
+
+    /// Phased action events this frame (generated by process_frame)
+    current_events: Vec<ActionEvent<A>>,
+
+    /// Modifier state at the end of the last processed frame
+    modifiers: Modifiers,
+
+    /// Report zero mouse delta while the cursor is outside the window
+    freeze_delta_outside: bool,
+
+    /// Ignore presses this many ticks after the same input's release (0 = off)
+    debounce_ticks: u64,
+
+    /// Longest gap between the two presses of a key double tap
+    double_tap_ticks: u64,
+
+    /// Number of recent raw events kept in the state tracker's input log
+    input_log_capacity: usize,
+
+    /// Per-axis multipliers for reported mouse deltas
+    mouse_sensitivity: (f32, f32),
+
+    /// Negate the reported vertical mouse delta
+    invert_mouse_y: bool,
+
+    /// Which frames publish plain actions to the message bus
+    publish_policy: ActionPublishPolicy,
+
+    /// Ticks each action stays unpublished after it publishes
+    action_cooldowns: HashMap<A, u64>,
+
+    /// Ticks left before each cooling action may publish again
+    cooldown_remaining: HashMap<A, u64>,
+
+    /// Actions withheld from publication this frame by their cooldown
+    suppressed: HashSet<A>,
+
+    /// Ticks each repeating key has been held since its press
+    repeat_held: HashMap<KeyCode, u64>,
+
+    /// How inputs held across a context switch map
+    context_switch_policy: ContextSwitchPolicy,
+
+    /// Context the previous frame was mapped with
+    mapped_context: InputContext,
+
+    /// Keys held across a context switch, silenced under `Isolate`
+    isolated_keys: HashSet<KeyCode>,
+
+    /// Mouse buttons held across a context switch, silenced under `Isolate`
+    isolated_buttons: HashSet<MouseButton>,
+
+    /// Contexts the game expects to activate (see `with_contexts`)
+    declared_contexts: HashSet<InputContext>,
+
+    /// Contexts that have been active at some point
+    used_contexts: HashSet<InputContext>,
 }
 
 impl<A: Action> InputSystem<A> {
@@ -119,7 +212,28 @@ impl<A: Action> InputSystem<A> {
     pub(crate) fn new() -> Self {
         Self {
             mapper: ActionMapper::new(),
+            active_profile: DEFAULT_PROFILE.to_string(),
+            profiles: HashMap::new(),
             current_actions: Vec::new(),
+            current_events: Vec::new(),
+            modifiers: Modifiers::NONE,
+            freeze_delta_outside: false,
+            debounce_ticks: 0,
+            double_tap_ticks: DEFAULT_DOUBLE_TAP_TICKS,
+            input_log_capacity: DEFAULT_INPUT_LOG_CAPACITY,
+            mouse_sensitivity: (1.0, 1.0),
+            invert_mouse_y: false,
+            publish_policy: ActionPublishPolicy::default(),
+            action_cooldowns: HashMap::new(),
+            cooldown_remaining: HashMap::new(),
+            suppressed: HashSet::new(),
+            repeat_held: HashMap::new(),
+            context_switch_policy: ContextSwitchPolicy::default(),
+            mapped_context: InputContext::Primary,
+            isolated_keys: HashSet::new(),
+            isolated_buttons: HashSet::new(),
+            declared_contexts: HashSet::new(),
+            used_contexts: HashSet::from([InputContext::Primary]),
         }
     }
 
@@ -133,28 +247,32 @@ impl<A: Action> InputSystem<A> {
     /// # Processing Pipeline
     /// 1. Clear previous frame's deltas (pressed/released flags)
     /// 2. Update state from all event batches
-    /// 3. Finalize continuous inputs (mouse delta)
-    /// 4. Generate actions via current bindings
+    /// 3. Finalize continuous inputs (mouse delta, frozen outside the window
+    ///    if enabled)
+    /// 4. Generate actions and phased action events via current bindings
+    /// 5. Re-trigger held repeating keys that are due
+    /// 6. Withhold actions still on cooldown from publication
+    ///
+    /// Every frame maps under a single context: a switch made between frames
+    /// applies to the whole next frame, including events that arrived
+    /// before it. Inputs held across the switch follow the
+    /// [`ContextSwitchPolicy`].
     ///
     /// # Arguments
     ///
     /// * `state` - The state tracker to update and query
     /// * `event_batches` - Slices of events (typically discrete + continuous)
     pub fn process_frame(&mut self, state: &mut StateTracker, event_batches: &[Vec<InputEvent>]) {
-        // 1. Clear previous frame's deltas
-        state.clear();
-
-        // 2. Process all event batches
-        for batch in event_batches {
-            state.process_events(batch);
-        }
+        // 0. Inputs still down from before a context switch may be isolated
+        self.track_context_switch(state);
 
-        // 3. Calculate mouse delta AFTER all batches processed
-        state.finalize_frame();
+        // 1-3. Update the state tracker
+        self.track_state(state, event_batches);
 
         // 4. Generate actions with deduplication
         self.current_actions.clear();
         let modifiers = state.modifiers();
+        self.modifiers = modifiers;
         let mut seen = HashSet::new();
 
         for key in state.keys_pressed() {
@@ -172,6 +290,171 @@ impl<A: Action> InputSystem<A> {
                 }
             }
         }
+
+        // 5. Re-trigger held repeating keys
+        self.apply_key_repeats(state, &mut seen);
+
+        // 6. Generate phased action events
+        self.generate_action_events(state);
+
+        // 7. Withhold actions still on cooldown
+        self.apply_cooldowns();
+
+        // Isolation ends once an input is released (or pressed anew)
+        self.isolated_keys.retain(|&k| state.is_key_down(k) && !state.is_key_pressed(k));
+        self.isolated_buttons
+            .retain(|&b| state.is_button_down(b) && !state.is_button_pressed(b));
+    }
+
+    /// Runs only the state-tracking steps of [`process_frame`](Self::process_frame).
+    ///
+    /// Used while the engine is paused: held inputs stay accurate, but no
+    /// actions are generated and cooldown and repeat counters don't advance.
+    pub(crate) fn track_state(&self, state: &mut StateTracker, event_batches: &[Vec<InputEvent>]) {
+        // 1. Clear previous frame's deltas
+        state.clear();
+        state.set_debounce_ticks(self.debounce_ticks);
+        state.set_double_tap_ticks(self.double_tap_ticks);
+        state.set_input_log_capacity(self.input_log_capacity);
+        state.set_mouse_scale(self.mouse_scale());
+
+        // 2. Process all event batches
+        for batch in event_batches {
+            state.process_events(batch);
+        }
+
+        // 3. Calculate mouse delta AFTER all batches processed
+        state.finalize_frame();
+        if self.freeze_delta_outside && !state.mouse_in_window() {
+            state.reset_mouse_delta();
+        }
+    }
+
+    /// Records inputs held across a context switch under
+    /// [`ContextSwitchPolicy::Isolate`].
+    ///
+    /// Runs before the frame's events, so everything down is held over.
+    fn track_context_switch(&mut self, state: &StateTracker) {
+        let context = self.mapper.current_context();
+        if context == self.mapped_context {
+            return;
+        }
+        self.mapped_context = context;
+
+        if self.context_switch_policy == ContextSwitchPolicy::Isolate {
+            self.isolated_keys.extend(state.keys_down().copied());
+            self.isolated_buttons.extend(state.buttons_down().copied());
+        }
+    }
+
+    /// Adds the actions of held repeating keys whose next repeat is due.
+    ///
+    /// A key's hold count restarts on each press and is dropped on release.
+    fn apply_key_repeats(&mut self, state: &StateTracker, seen: &mut HashSet<A>) {
+        self.repeat_held.retain(|&key, _| state.is_key_down(key));
+        let modifiers = state.modifiers();
+
+        for &key in state.keys_down() {
+            if self.isolated_keys.contains(&key) && !state.is_key_pressed(key) {
+                continue;
+            }
+            let Some((delay, interval)) = self.mapper.repeat_for(key) else {
+                continue;
+            };
+            if state.is_key_pressed(key) {
+                self.repeat_held.insert(key, 0);
+                continue;
+            }
+
+            let held = self.repeat_held.entry(key).or_insert(0);
+            *held += 1;
+            if *held < delay || !(*held - delay).is_multiple_of(interval) {
+                continue;
+            }
+            if let Some(action) = self.mapper.map_key(key, modifiers) {
+                if seen.insert(action) {
+                    self.current_actions.push(action);
+                }
+            }
+        }
+    }
+
+    /// Starts cooldowns for actions publishing this frame and suppresses
+    /// those still cooling down.
+    ///
+    /// Counters are checked before they tick down, so an action with a
+    /// cooldown of N ticks stays suppressed for exactly N frames.
+    fn apply_cooldowns(&mut self) {
+        self.suppressed.clear();
+        if self.action_cooldowns.is_empty() {
+            return;
+        }
+
+        let mut started = HashSet::new();
+        for action in self.candidate_actions() {
+            let Some(&ticks) = self.action_cooldowns.get(&action) else {
+                continue;
+            };
+            match self.cooldown_remaining.entry(action) {
+                Entry::Occupied(_) => {
+                    self.suppressed.insert(action);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(ticks);
+                    started.insert(action);
+                }
+            }
+        }
+
+        for (action, remaining) in self.cooldown_remaining.iter_mut() {
+            if !started.contains(action) {
+                *remaining -= 1;
+            }
+        }
+        self.cooldown_remaining.retain(|_, remaining| *remaining > 0);
+    }
+
+    /// Derives press/hold/release events from key and button transitions.
+    ///
+    /// Bindings are resolved with the current modifiers, so releasing a key
+    /// after the modifier it was bound with yields no `Released` event.
+    /// Inputs isolated by a context switch yield no `Held`/`Released` events.
+    /// Events are deduplicated per (action, phase).
+    fn generate_action_events(&mut self, state: &StateTracker) {
+        self.current_events.clear();
+        let modifiers = state.modifiers();
+        let mapper = &self.mapper;
+        let (isolated_keys, isolated_buttons) = (&self.isolated_keys, &self.isolated_buttons);
+
+        let pressed = state.keys_pressed().map(|&k| mapper.map_key(k, modifiers))
+            .chain(state.buttons_pressed().map(|&b| mapper.map_button(b, modifiers)))
+            .map(|action| (action, ActionPhase::Pressed));
+
+        let held = state.keys_down()
+            .filter(|&&k| !state.is_key_pressed(k) && !isolated_keys.contains(&k))
+            .map(|&k| mapper.map_key(k, modifiers))
+            .chain(state.buttons_down()
+                .filter(|&&b| !state.is_button_pressed(b) && !isolated_buttons.contains(&b))
+                .map(|&b| mapper.map_button(b, modifiers)))
+            .map(|action| (action, ActionPhase::Held));
+
+        let released = state.keys_released()
+            .filter(|&k| !isolated_keys.contains(k))
+            .map(|&k| mapper.map_key(k, modifiers))
+            .chain(state.buttons_released()
+                .filter(|&b| !isolated_buttons.contains(b))
+                .map(|&b| mapper.map_button(b, modifiers)))
+            .map(|action| (action, ActionPhase::Released));
+
+        let mut seen = HashSet::new();
+        for (action, phase) in pressed.chain(held).chain(released) {
+            if let Some(action) = action {
+                let event = ActionEvent { action, phase };
+                if seen.insert(event) {
+                    self.current_events.push(event);
+                }
+            }
+        }
     }
 
     //=====================================================================
@@ -195,6 +478,90 @@ impl<A: Action> InputSystem<A> {
         self.current_actions.contains(action)
     }
 
+    /// Returns actions with their press/hold/release phase this frame.
+    ///
+    /// [`actions`](Self::actions) equals the actions of the `Pressed` events.
+    #[must_use]
+    #[inline]
+    pub fn action_events(&self) -> &[ActionEvent<A>] {
+        &self.current_events
+    }
+
+    /// Checks if an action is in the given phase this frame.
+    #[inline]
+    pub fn has_action_phase(&self, action: A, phase: ActionPhase) -> bool {
+        self.current_events.contains(&ActionEvent { action, phase })
+    }
+
+    /// Returns the actions to publish this frame under the publish policy.
+    ///
+    /// Equals [`actions`](Self::actions) for [`ActionPublishPolicy::PerEvent`];
+    /// also includes held actions for [`ActionPublishPolicy::OncePerFrame`].
+    /// Actions on cooldown (see [`set_action_cooldown`](Self::set_action_cooldown))
+    /// are left out.
+    pub fn published_actions(&self) -> Vec<A> {
+        let mut actions = self.candidate_actions();
+        actions.retain(|action| !self.suppressed.contains(action));
+        actions
+    }
+
+    /// Actions the publish policy selects this frame, before cooldowns.
+    fn candidate_actions(&self) -> Vec<A> {
+        match self.publish_policy {
+            ActionPublishPolicy::PerEvent => self.current_actions.clone(),
+            ActionPublishPolicy::OncePerFrame => {
+                let mut seen = HashSet::new();
+                self.current_events
+                    .iter()
+                    .filter(|event| event.phase != ActionPhase::Released)
+                    .map(|event| event.action)
+                    .filter(|&action| seen.insert(action))
+                    .collect()
+            }
+        }
+    }
+
+    /// Sets which frames publish plain actions to the message bus.
+    ///
+    /// Default: [`ActionPublishPolicy::PerEvent`] (on press only).
+    pub fn set_publish_policy(&mut self, policy: ActionPublishPolicy) {
+        self.publish_policy = policy;
+    }
+
+    /// Returns the current action publish policy.
+    pub fn publish_policy(&self) -> ActionPublishPolicy {
+        self.publish_policy
+    }
+
+    //=====================================================================
+    // Modifier Queries
+    //=====================================================================
+
+    /// Returns the modifier keys held at the end of the last processed frame.
+    #[must_use]
+    #[inline]
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Returns `true` if Shift was held at the end of the last processed frame.
+    #[inline]
+    pub fn shift_held(&self) -> bool {
+        self.modifiers.shift
+    }
+
+    /// Returns `true` if Ctrl was held at the end of the last processed frame.
+    #[inline]
+    pub fn ctrl_held(&self) -> bool {
+        self.modifiers.ctrl
+    }
+
+    /// Returns `true` if Alt was held at the end of the last processed frame.
+    #[inline]
+    pub fn alt_held(&self) -> bool {
+        self.modifiers.alt
+    }
+
     //=====================================================================
     // Fluent Configuration API (Immutable Chain)
     //=====================================================================
@@ -253,7 +620,46 @@ impl<A: Action> InputSystem<A> {
     ///     .with_context(InputContext::custom(0));
     /// ```
     pub fn with_context(mut self, context: InputContext) -> Self {
-        self.mapper.set_context(context);
+        self.set_context(context);
+        self
+    }
+
+    /// Declares the contexts the game will activate using fluent API.
+    ///
+    /// See [`declare_contexts`](Self::declare_contexts).
+    ///
+    /// # Examples
+    /// ```ignore
+    /// # use aetheric_engine::prelude::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameAction { Jump }
+    /// # impl Action for GameAction {}
+    /// const MENU: InputContext = InputContext::custom(0);
+    ///
+    /// let input = InputSystem::<GameAction>::default()
+    ///     .with_contexts(&[InputContext::Primary, MENU]);
+    /// ```
+    pub fn with_contexts(mut self, contexts: &[InputContext]) -> Self {
+        self.declare_contexts(contexts);
+        self
+    }
+
+    /// Gives an action a publication cooldown using fluent API.
+    ///
+    /// See [`set_action_cooldown`](Self::set_action_cooldown).
+    ///
+    /// # Examples
+    /// ```ignore
+    /// # use aetheric_engine::prelude::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameAction { Shoot }
+    /// # impl Action for GameAction {}
+    /// let input = InputSystem::<GameAction>::default()
+    ///     .with_binding(KeyCode::Space, GameAction::Shoot)
+    ///     .with_action_cooldown(GameAction::Shoot, 10);
+    /// ```
+    pub fn with_action_cooldown(mut self, action: A, ticks: u64) -> Self {
+        self.set_action_cooldown(action, ticks);
         self
     }
 
@@ -301,6 +707,23 @@ impl<A: Action> InputSystem<A> {
         self.mapper.bind_key_with_mods(key, modifiers, action, context);
     }
 
+    /// Binds a key that re-triggers its action at a fixed rate while held.
+    ///
+    /// Repeats appear in [`actions`](Self::actions) like presses, so they are
+    /// published under [`ActionPublishPolicy::PerEvent`]. See
+    /// [`ActionMapper::bind_key_repeat`]. Context parameter: see
+    /// [`bind_key`](Self::bind_key).
+    pub fn bind_key_repeat(
+        &mut self,
+        key: KeyCode,
+        action: A,
+        context: InputContext,
+        initial_delay_ticks: u64,
+        interval_ticks: u64,
+    ) {
+        self.mapper.bind_key_repeat(key, action, context, initial_delay_ticks, interval_ticks);
+    }
+
     /// Binds a mouse button to an action at runtime.
     ///
     /// Context parameter: see [`bind_key`](Self::bind_key) for context usage.
@@ -321,6 +744,14 @@ impl<A: Action> InputSystem<A> {
         self.mapper.bind_mouse_with_mods(button, modifiers, action, context);
     }
 
+    /// Binds several keys to one action as a group of alternatives.
+    ///
+    /// See [`ActionMapper::bind_key_alternatives`]. Context parameter: see
+    /// [`bind_key`](Self::bind_key).
+    pub fn bind_key_alternatives(&mut self, keys: &[KeyCode], action: A, context: InputContext) {
+        self.mapper.bind_key_alternatives(keys, action, context);
+    }
+
     /// Removes all bindings for a key in the specified context.
     ///
     /// Other contexts are unaffected. Context parameter: see [`bind_key`](Self::bind_key).
@@ -336,6 +767,13 @@ impl<A: Action> InputSystem<A> {
         self.mapper.clear_context(context);
     }
 
+    /// Removes every key and mouse binding mapped to `action` in a context.
+    ///
+    /// Use before rebinding an action from a controls menu.
+    pub fn unbind_action(&mut self, action: A, context: InputContext) {
+        self.mapper.unbind_action(action, context);
+    }
+
     //=====================================================================
     // Context Management
     //=====================================================================
@@ -418,10 +856,13 @@ impl<A: Action> InputSystem<A> {
     // # }
     // ```
     //
-    // Then in your scene logic, read actions from MessageBus and request context
-    // changes via custom messages. Note: Scenes only have access to GlobalContext,
-    // not GlobalSystems. Context switching would typically be done during scene
-    // transitions or via a custom message system.
+    // Then in your scene logic, read actions from MessageBus and switch contexts
+    // with `GlobalContext::set_input_context` (e.g. in `on_enter`/`on_exit`).
+    // Scenes only have access to GlobalContext, so the switch is applied at
+    // the start of the next tick, before that tick's actions are mapped.
+    // A frame never mixes contexts. Keys held across the switch remap under
+    // the new context by default; `ContextSwitchPolicy::Isolate` keeps them
+    // silent until released (see `set_context_switch_policy`).
     //
     // # Performance Note
     //
@@ -455,6 +896,19 @@ impl<A: Action> InputSystem<A> {
     /// ```
     pub fn set_context(&mut self, context: InputContext) {
         self.mapper.set_context(context);
+        self.used_contexts.insert(context);
+    }
+
+    /// Sets how inputs held across a context switch map.
+    ///
+    /// Default: [`ContextSwitchPolicy::Remap`].
+    pub fn set_context_switch_policy(&mut self, policy: ContextSwitchPolicy) {
+        self.context_switch_policy = policy;
+    }
+
+    /// Returns the current context switch policy.
+    pub fn context_switch_policy(&self) -> ContextSwitchPolicy {
+        self.context_switch_policy
     }
 
     /// Returns the currently active context.
@@ -465,6 +919,220 @@ impl<A: Action> InputSystem<A> {
         self.mapper.current_context()
     }
 
+    /// Returns the distinct actions bound in `context` (active profile).
+    ///
+    /// See [`ActionMapper::actions_in_context`].
+    pub fn actions_in_context(&self, context: InputContext) -> HashSet<A> {
+        self.mapper.actions_in_context(context)
+    }
+
+    /// Returns the keys bound to `action` in `context`, grouped as
+    /// alternatives (active profile).
+    ///
+    /// See [`ActionMapper::bindings_for`].
+    pub fn bindings_for(&self, action: A, context: InputContext) -> Vec<Vec<KeyCode>> {
+        self.mapper.bindings_for(action, context)
+    }
+
+    /// Returns a label for `action`'s binding in `context`, for on-screen
+    /// prompts (`"Ctrl+S"`, `"Left Mouse"`).
+    ///
+    /// Keyboard bindings are preferred over mouse ones. `None` if the action
+    /// is unbound in `context`.
+    pub fn prompt_for(&self, action: A, context: InputContext) -> Option<String> {
+        let (name, modifiers) = match self.mapper.key_binding_for(action, context) {
+            Some((key, modifiers)) => (key.to_string(), modifiers),
+            None => {
+                let (button, modifiers) = self.mapper.button_binding_for(action, context)?;
+                (button.to_string(), modifiers)
+            }
+        };
+
+        if modifiers.is_empty() {
+            Some(name)
+        } else {
+            Some(format!("{}+{}", modifiers, name))
+        }
+    }
+
+    //--- Validation -------------------------------------------------------
+
+    /// Declares contexts the game will activate, for [`validate`](Self::validate).
+    ///
+    /// Call at startup with every context scenes switch to later. Adds to
+    /// any contexts declared before.
+    pub fn declare_contexts(&mut self, contexts: &[InputContext]) {
+        self.declared_contexts.extend(contexts.iter().copied());
+    }
+
+    /// Checks every profile for bindings that can never fire.
+    ///
+    /// A context is reachable if it was declared with
+    /// [`declare_contexts`](Self::declare_contexts) or has been active
+    /// (Primary always has). Each unreachable context with bindings is
+    /// logged and returned once, Primary first, then custom ids ascending.
+    pub fn validate(&self) -> Vec<BindingWarning> {
+        let mut unreachable: Vec<InputContext> = self
+            .profiles
+            .values()
+            .chain([&self.mapper])
+            .flat_map(ActionMapper::bound_contexts)
+            .filter(|ctx| {
+                *ctx != InputContext::Global
+                    && !self.declared_contexts.contains(ctx)
+                    && !self.used_contexts.contains(ctx)
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        unreachable.sort_by_key(|ctx| match ctx {
+            InputContext::Primary | InputContext::Global => None,
+            InputContext::Custom(id) => Some(*id),
+        });
+
+        unreachable
+            .into_iter()
+            .map(|ctx| {
+                warn!("Bindings in {:?} never fire: context is not declared or activated", ctx);
+                BindingWarning::UnreachableContext(ctx)
+            })
+            .collect()
+    }
+
+    //--- Profiles ---------------------------------------------------------
+
+    /// Registers a named binding profile (e.g. "Southpaw").
+    ///
+    /// Bindings made through `bind_*` go to the active profile, which starts
+    /// as [`DEFAULT_PROFILE`]. Adding a profile under the active name
+    /// replaces the live bindings.
+    pub fn add_profile(&mut self, name: impl Into<String>, mapper: ActionMapper<A>) {
+        let name = name.into();
+        if name == self.active_profile {
+            let context = self.mapper.current_context();
+            self.mapper = mapper;
+            self.mapper.set_context(context);
+        } else {
+            self.profiles.insert(name, mapper);
+        }
+    }
+
+    /// Makes the named profile's bindings live; the previous profile is kept.
+    ///
+    /// The active [`InputContext`] carries over, so switching control scheme
+    /// does not leave a menu or vehicle context. Returns `false` (with a
+    /// warning) if no profile is registered under `name`.
+    pub fn activate_profile(&mut self, name: &str) -> bool {
+        if name == self.active_profile {
+            return true;
+        }
+        let Some(mut mapper) = self.profiles.remove(name) else {
+            warn!("Input profile {:?} not registered", name);
+            return false;
+        };
+
+        mapper.set_context(self.mapper.current_context());
+        let previous = mem::replace(&mut self.mapper, mapper);
+        let previous_name = mem::replace(&mut self.active_profile, name.to_string());
+        self.profiles.insert(previous_name, previous);
+        true
+    }
+
+    /// Returns the name of the active binding profile.
+    pub fn active_profile(&self) -> &str {
+        &self.active_profile
+    }
+
+    //--- Mouse ------------------------------------------------------------
+
+    /// Reports a zero mouse delta while the cursor is outside the window.
+    ///
+    /// Keeps camera or drag logic from reacting to movement the window no
+    /// longer owns. Position is still updated. Default: disabled.
+    pub fn set_freeze_delta_outside_window(&mut self, enabled: bool) {
+        self.freeze_delta_outside = enabled;
+    }
+
+    /// Scales the reported `mouse_delta` and `raw_mouse_delta` per axis.
+    ///
+    /// For camera sensitivity settings. `mouse_position` stays unscaled.
+    /// Default: `(1.0, 1.0)`.
+    pub fn set_mouse_sensitivity(&mut self, x: f32, y: f32) {
+        self.mouse_sensitivity = (x, y);
+    }
+
+    /// Returns the per-axis mouse sensitivity.
+    pub fn mouse_sensitivity(&self) -> (f32, f32) {
+        self.mouse_sensitivity
+    }
+
+    /// Negates the reported vertical mouse delta (inverted look).
+    ///
+    /// Applied on top of the sensitivity. Default: disabled.
+    pub fn set_invert_mouse_y(&mut self, enabled: bool) {
+        self.invert_mouse_y = enabled;
+    }
+
+    /// Returns `true` if the vertical mouse delta is inverted.
+    pub fn invert_mouse_y(&self) -> bool {
+        self.invert_mouse_y
+    }
+
+    /// Returns the sensitivity with inversion folded in.
+    fn mouse_scale(&self) -> (f32, f32) {
+        let (x, y) = self.mouse_sensitivity;
+        (x, if self.invert_mouse_y { -y } else { y })
+    }
+
+    //--- Debouncing --------------------------------------------------------
+
+    /// Ignores a key or button press within `ticks` ticks of its release.
+    ///
+    /// Smooths bouncing hardware that reports spurious release/press pairs.
+    /// A suppressed press is dropped entirely, so keep the window short
+    /// (1-2 ticks) to leave deliberate fast presses alone. `0` disables
+    /// debouncing (the default).
+    pub fn set_debounce_ticks(&mut self, ticks: u64) {
+        self.debounce_ticks = ticks;
+    }
+
+    //--- Double Tap -------------------------------------------------------
+
+    /// Sets the longest gap, in ticks, between two presses of a key for
+    /// [`StateTracker::is_key_double_tapped`] to report a double tap.
+    ///
+    /// Default: [`DEFAULT_DOUBLE_TAP_TICKS`].
+    pub fn set_double_tap_ticks(&mut self, ticks: u64) {
+        self.double_tap_ticks = ticks;
+    }
+
+    //--- Diagnostics ------------------------------------------------------
+
+    /// Sets how many recent raw events [`StateTracker::input_log`] keeps.
+    ///
+    /// `0` disables the log. Default: [`DEFAULT_INPUT_LOG_CAPACITY`].
+    pub fn set_input_log_capacity(&mut self, capacity: usize) {
+        self.input_log_capacity = capacity;
+    }
+
+    //--- Cooldowns --------------------------------------------------------
+
+    /// Withholds `action` from publication for `ticks` ticks after it publishes.
+    ///
+    /// For rate-limited actions like "shoot": combined with
+    /// [`ActionPublishPolicy::OncePerFrame`], holding the key fires once
+    /// every `ticks + 1` frames. Only [`published_actions`](Self::published_actions)
+    /// (and so the message bus) is filtered; [`actions`](Self::actions) and
+    /// [`action_events`](Self::action_events) still report raw input.
+    /// `0` removes the cooldown.
+    pub fn set_action_cooldown(&mut self, action: A, ticks: u64) {
+        if ticks == 0 {
+            self.action_cooldowns.remove(&action);
+            self.cooldown_remaining.remove(&action);
+        } else {
+            self.action_cooldowns.insert(action, ticks);
+        }
+    }
 }
 
 //=========================================================================
@@ -561,6 +1229,23 @@ mod tests {
         assert!(input.has_action(&TestAction::Jump));
     }
 
+    #[test]
+    fn key_tapped_generates_action() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+
+        let events = [vec![InputEvent::KeyTapped {
+            key: KeyCode::Space,
+            modifiers: Modifiers::NONE,
+        }]];
+        input.process_frame(&mut state, &events);
+
+        assert_eq!(input.actions(), &[TestAction::Jump]);
+        assert!(!state.is_key_down(KeyCode::Space));
+    }
+
     #[test]
     fn process_frame_deduplicates_actions() {
         let mut input = InputSystem::<TestAction>::new();
@@ -621,6 +1306,63 @@ mod tests {
         assert_eq!(input.actions(), &[TestAction::Shoot]);
     }
 
+    //=====================================================================
+    // Action Phases
+    //=====================================================================
+
+    fn phases(input: &InputSystem<TestAction>) -> Vec<ActionPhase> {
+        input.action_events().iter().map(|event| event.phase).collect()
+    }
+
+    #[test]
+    fn holding_key_yields_pressed_held_released() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        assert_eq!(phases(&input), vec![ActionPhase::Pressed]);
+        assert_eq!(input.actions(), &[TestAction::Jump]);
+
+        input.process_frame(&mut state, &[]);
+        assert_eq!(phases(&input), vec![ActionPhase::Held]);
+        assert!(input.actions().is_empty());
+
+        input.process_frame(&mut state, &[]);
+        assert!(input.has_action_phase(TestAction::Jump, ActionPhase::Held));
+
+        input.process_frame(&mut state, &[vec![key_up(KeyCode::Space)]]);
+        assert_eq!(phases(&input), vec![ActionPhase::Released]);
+
+        input.process_frame(&mut state, &[]);
+        assert!(input.action_events().is_empty());
+    }
+
+    #[test]
+    fn tapped_key_yields_pressed_and_released_same_frame() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space), key_up(KeyCode::Space)]]);
+
+        assert_eq!(phases(&input), vec![ActionPhase::Pressed, ActionPhase::Released]);
+    }
+
+    #[test]
+    fn mouse_button_phases_follow_button_state() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_mouse(MouseButton::Left, TestAction::Shoot, InputContext::Primary);
+
+        input.process_frame(&mut state, &[vec![mouse_down(MouseButton::Left)]]);
+        input.process_frame(&mut state, &[]);
+        assert!(input.has_action_phase(TestAction::Shoot, ActionPhase::Held));
+
+        input.process_frame(&mut state, &[vec![mouse_up(MouseButton::Left)]]);
+        assert!(input.has_action_phase(TestAction::Shoot, ActionPhase::Released));
+    }
+
     //=====================================================================
     // Modifier Tests
     //=====================================================================
@@ -670,6 +1412,63 @@ mod tests {
         assert!(input.actions().is_empty());
     }
 
+    #[test]
+    fn prompt_for_modified_key_binding() {
+        let mut input = InputSystem::<TestAction>::new();
+        input.bind_key_with_mods(
+            KeyCode::KeyS,
+            Modifiers::CTRL,
+            TestAction::Save,
+            InputContext::Primary
+        );
+
+        assert_eq!(input.prompt_for(TestAction::Save, InputContext::Primary).as_deref(), Some("Ctrl+S"));
+        assert_eq!(input.prompt_for(TestAction::Jump, InputContext::Primary), None);
+    }
+
+    #[test]
+    fn prompt_for_mouse_binding_yields_to_keyboard() {
+        let mut input = InputSystem::<TestAction>::new();
+        input.bind_mouse(MouseButton::Left, TestAction::Shoot, InputContext::Primary);
+        assert_eq!(
+            input.prompt_for(TestAction::Shoot, InputContext::Primary).as_deref(),
+            Some("Left Mouse")
+        );
+
+        input.bind_key(KeyCode::KeyF, TestAction::Shoot, InputContext::Primary);
+        assert_eq!(input.prompt_for(TestAction::Shoot, InputContext::Primary).as_deref(), Some("F"));
+    }
+
+    #[test]
+    fn modifier_queries_follow_processed_events() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        assert_eq!(input.modifiers(), Modifiers::NONE);
+
+        let events = [vec![key_down_with_mods(KeyCode::KeyS, Modifiers::SHIFT_CTRL)]];
+        input.process_frame(&mut state, &events);
+
+        assert_eq!(input.modifiers(), Modifiers::SHIFT_CTRL);
+        assert!(input.shift_held());
+        assert!(input.ctrl_held());
+        assert!(!input.alt_held());
+    }
+
+    #[test]
+    fn modifier_queries_clear_on_release() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+
+        let events = [vec![key_down_with_mods(KeyCode::KeyA, Modifiers::ALT)]];
+        input.process_frame(&mut state, &events);
+        assert!(input.alt_held());
+
+        let events = [vec![key_up(KeyCode::KeyA)]];
+        input.process_frame(&mut state, &events);
+        assert!(!input.alt_held());
+        assert_eq!(input.modifiers(), Modifiers::NONE);
+    }
+
     #[test]
     fn same_key_different_modifiers() {
         let mut input = InputSystem::<TestAction>::new();
@@ -955,4 +1754,386 @@ mod tests {
 
         assert!(input.actions().is_empty());
     }
+
+    #[test]
+    fn mouse_delta_frozen_outside_window_when_enabled() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        let moved = |x, y| vec![mouse_move(x, y)];
+
+        input.process_frame(&mut state, &[vec![InputEvent::CursorLeft], moved(-10.0, 5.0)]);
+        assert_eq!(state.mouse_delta(), (-10.0, 5.0));
+
+        input.set_freeze_delta_outside_window(true);
+        input.process_frame(&mut state, &[moved(-30.0, 5.0)]);
+        assert_eq!(state.mouse_delta(), (0.0, 0.0));
+        assert_eq!(state.mouse_position(), (-30.0, 5.0));
+
+        input.process_frame(&mut state, &[vec![InputEvent::CursorEntered], moved(-20.0, 5.0)]);
+        assert_eq!(state.mouse_delta(), (10.0, 0.0));
+    }
+
+    #[test]
+    fn mouse_sensitivity_scales_deltas_but_not_position() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.set_mouse_sensitivity(2.0, 2.0);
+
+        input.process_frame(&mut state, &[vec![
+            mouse_move(10.0, 4.0),
+            InputEvent::MouseMotion { dx: 3.0, dy: -1.5 },
+        ]]);
+
+        assert_eq!(state.mouse_delta(), (20.0, 8.0));
+        assert_eq!(state.raw_mouse_delta(), (6.0, -3.0));
+        assert_eq!(state.mouse_position(), (10.0, 4.0));
+    }
+
+    #[test]
+    fn invert_mouse_y_negates_vertical_delta() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.set_mouse_sensitivity(0.5, 1.0);
+        input.set_invert_mouse_y(true);
+
+        input.process_frame(&mut state, &[vec![
+            mouse_move(8.0, 6.0),
+            InputEvent::MouseMotion { dx: 2.0, dy: 2.0 },
+        ]]);
+
+        assert_eq!(state.mouse_delta(), (4.0, -6.0));
+        assert_eq!(state.raw_mouse_delta(), (1.0, -2.0));
+        assert_eq!(state.mouse_position(), (8.0, 6.0));
+    }
+
+    //=====================================================================
+    // Profiles
+    //=====================================================================
+
+    fn mapper_with(key: KeyCode, action: TestAction) -> ActionMapper<TestAction> {
+        let mut mapper = ActionMapper::new();
+        mapper.bind_key(key, action, InputContext::Primary);
+        mapper
+    }
+
+    fn press_space(input: &mut InputSystem<TestAction>) -> Vec<TestAction> {
+        let mut state = StateTracker::new();
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        input.actions().to_vec()
+    }
+
+    #[test]
+    fn activating_profile_switches_bindings() {
+        let mut input = InputSystem::<TestAction>::new();
+        input.add_profile("classic", mapper_with(KeyCode::Space, TestAction::Jump));
+        input.add_profile("southpaw", mapper_with(KeyCode::Space, TestAction::Shoot));
+
+        assert!(input.activate_profile("classic"));
+        assert_eq!(press_space(&mut input), vec![TestAction::Jump]);
+
+        assert!(input.activate_profile("southpaw"));
+        assert_eq!(input.active_profile(), "southpaw");
+        assert_eq!(press_space(&mut input), vec![TestAction::Shoot]);
+
+        // The previous profile was kept, not consumed
+        assert!(input.activate_profile("classic"));
+        assert_eq!(press_space(&mut input), vec![TestAction::Jump]);
+    }
+
+    #[test]
+    fn default_profile_keeps_direct_bindings() {
+        let mut input = InputSystem::<TestAction>::new();
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        input.add_profile("alt", mapper_with(KeyCode::Space, TestAction::AltFire));
+
+        input.activate_profile("alt");
+        input.activate_profile(DEFAULT_PROFILE);
+
+        assert_eq!(press_space(&mut input), vec![TestAction::Jump]);
+    }
+
+    #[test]
+    fn contexts_work_within_profiles() {
+        let menu = InputContext::custom(0);
+        let mut vehicle = mapper_with(KeyCode::Space, TestAction::Jump);
+        vehicle.bind_key(KeyCode::Space, TestAction::Save, menu);
+
+        let mut input = InputSystem::<TestAction>::new();
+        input.add_profile("vehicle", vehicle);
+        input.set_context(menu);
+
+        input.activate_profile("vehicle");
+
+        assert_eq!(input.current_context(), menu);
+        assert_eq!(press_space(&mut input), vec![TestAction::Save]);
+    }
+
+    #[test]
+    fn activating_unknown_profile_is_rejected() {
+        let mut input = InputSystem::<TestAction>::new();
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+
+        assert!(!input.activate_profile("missing"));
+        assert_eq!(input.active_profile(), DEFAULT_PROFILE);
+        assert_eq!(press_space(&mut input), vec![TestAction::Jump]);
+    }
+
+    #[test]
+    fn debounce_is_configured_on_input_system() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        input.set_debounce_ticks(1);
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        input.process_frame(&mut state, &[vec![key_up(KeyCode::Space), key_down(KeyCode::Space)]]);
+
+        assert!(input.actions().is_empty());
+        assert!(!state.is_key_down(KeyCode::Space));
+    }
+
+    #[test]
+    fn cooldown_suppresses_publication_then_rearms() {
+        let mut input = InputSystem::<TestAction>::new()
+            .with_binding(KeyCode::Space, TestAction::Shoot)
+            .with_binding(KeyCode::KeyJ, TestAction::Jump)
+            .with_action_cooldown(TestAction::Shoot, 2);
+        input.set_publish_policy(ActionPublishPolicy::OncePerFrame);
+        let mut state = StateTracker::new();
+
+        let mut shots = Vec::new();
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space), key_down(KeyCode::KeyJ)]]);
+        for frame in 0..4 {
+            if frame > 0 {
+                input.process_frame(&mut state, &[]);
+            }
+            let published = input.published_actions();
+            assert!(published.contains(&TestAction::Jump));
+            shots.push(published.contains(&TestAction::Shoot));
+        }
+
+        // Fires, is withheld for two ticks while held, then fires again
+        assert_eq!(shots, vec![true, false, false, true]);
+        assert!(input.has_action_phase(TestAction::Shoot, ActionPhase::Held));
+    }
+
+    #[test]
+    fn cooldown_counts_down_while_action_is_idle() {
+        let mut input = InputSystem::<TestAction>::new()
+            .with_binding(KeyCode::Space, TestAction::Shoot)
+            .with_action_cooldown(TestAction::Shoot, 1);
+        let mut state = StateTracker::new();
+        let mut tap = |input: &mut InputSystem<TestAction>| {
+            input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+            let published = input.published_actions();
+            input.process_frame(&mut state, &[vec![key_up(KeyCode::Space)]]);
+            published
+        };
+
+        assert_eq!(tap(&mut input), vec![TestAction::Shoot]);
+        assert_eq!(tap(&mut input), vec![TestAction::Shoot]);
+
+        input.set_action_cooldown(TestAction::Shoot, 0);
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        input.process_frame(&mut state, &[vec![key_up(KeyCode::Space), key_down(KeyCode::Space)]]);
+        assert_eq!(input.published_actions(), vec![TestAction::Shoot]);
+    }
+
+    #[test]
+    fn double_tap_window_is_configured_on_input_system() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.set_double_tap_ticks(1);
+        let tap = [vec![InputEvent::KeyTapped { key: KeyCode::KeyD, modifiers: Modifiers::NONE }]];
+
+        input.process_frame(&mut state, &tap);
+        input.process_frame(&mut state, &[]);
+        input.process_frame(&mut state, &tap);
+
+        assert!(!state.is_key_double_tapped(KeyCode::KeyD));
+    }
+
+    #[test]
+    fn key_alternatives_trigger_action_through_input_system() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key_alternatives(
+            &[KeyCode::KeyW, KeyCode::ArrowUp],
+            TestAction::MoveUp,
+            InputContext::Primary,
+        );
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::ArrowUp)]]);
+
+        assert_eq!(input.actions(), &[TestAction::MoveUp]);
+        assert_eq!(
+            input.bindings_for(TestAction::MoveUp, InputContext::Primary),
+            vec![vec![KeyCode::KeyW, KeyCode::ArrowUp]]
+        );
+    }
+
+    #[test]
+    fn binding_in_undeclared_context_is_reported() {
+        const MENU: InputContext = InputContext::custom(0);
+        const VEHICLE: InputContext = InputContext::custom(1);
+
+        let mut input = InputSystem::<TestAction>::new().with_contexts(&[MENU]);
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        input.bind_key(KeyCode::Enter, TestAction::Save, MENU);
+        input.bind_mouse(MouseButton::Left, TestAction::Shoot, VEHICLE);
+        input.bind_key(KeyCode::Escape, TestAction::Save, InputContext::Global);
+
+        assert_eq!(input.validate(), vec![BindingWarning::UnreachableContext(VEHICLE)]);
+    }
+
+    #[test]
+    fn activated_context_passes_validation() {
+        const VEHICLE: InputContext = InputContext::custom(1);
+
+        let mut input = InputSystem::<TestAction>::new();
+        input.bind_key(KeyCode::KeyE, TestAction::AltFire, VEHICLE);
+        assert_eq!(input.validate().len(), 1);
+
+        input.set_context(VEHICLE);
+        assert!(input.validate().is_empty());
+    }
+
+    #[test]
+    fn validation_covers_inactive_profiles() {
+        const MENU: InputContext = InputContext::custom(0);
+
+        let mut southpaw = ActionMapper::new();
+        southpaw.bind_key(KeyCode::Enter, TestAction::Save, MENU);
+        let mut input = InputSystem::<TestAction>::new();
+        input.add_profile("southpaw", southpaw);
+
+        assert_eq!(input.validate(), vec![BindingWarning::UnreachableContext(MENU)]);
+
+        input.declare_contexts(&[MENU]);
+        assert!(input.validate().is_empty());
+    }
+
+    #[test]
+    fn repeating_key_fires_on_press_then_after_delay_at_interval() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key_repeat(KeyCode::ArrowDown, TestAction::MoveUp, InputContext::Primary, 3, 2);
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::ArrowDown)]]);
+        let mut fired = vec![input.has_action(&TestAction::MoveUp)];
+        for _ in 0..7 {
+            input.process_frame(&mut state, &[]);
+            fired.push(input.has_action(&TestAction::MoveUp));
+        }
+
+        assert_eq!(fired, vec![true, false, false, true, false, true, false, true]);
+        assert_eq!(input.published_actions(), vec![TestAction::MoveUp]);
+    }
+
+    #[test]
+    fn repeating_stops_on_release_and_restarts_on_press() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key_repeat(KeyCode::Space, TestAction::Shoot, InputContext::Primary, 2, 1);
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        input.process_frame(&mut state, &[]);
+        input.process_frame(&mut state, &[vec![key_up(KeyCode::Space)]]);
+        assert!(!input.has_action(&TestAction::Shoot));
+        input.process_frame(&mut state, &[]);
+        assert!(!input.has_action(&TestAction::Shoot));
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        input.process_frame(&mut state, &[]);
+        assert!(!input.has_action(&TestAction::Shoot));
+        input.process_frame(&mut state, &[]);
+        assert!(input.has_action(&TestAction::Shoot));
+    }
+
+    #[test]
+    fn rebinding_key_drops_repeat() {
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key_repeat(KeyCode::Space, TestAction::Shoot, InputContext::Primary, 1, 1);
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        input.process_frame(&mut state, &[]);
+
+        assert!(input.actions().is_empty());
+    }
+
+    //=====================================================================
+    // Context Switch Policy
+    //=====================================================================
+
+    /// Space jumps in gameplay and saves in the menu; Space is held across
+    /// a switch to the menu, released, then pressed again.
+    fn hold_space_across_menu_switch(
+        policy: ContextSwitchPolicy,
+    ) -> Vec<Vec<ActionEvent<TestAction>>> {
+        const MENU: InputContext = InputContext::custom(0);
+
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        input.bind_key(KeyCode::Space, TestAction::Save, MENU);
+        input.set_context_switch_policy(policy);
+        input.set_publish_policy(ActionPublishPolicy::OncePerFrame);
+
+        input.process_frame(&mut state, &[vec![key_down(KeyCode::Space)]]);
+        input.set_context(MENU);
+
+        let mut frames = Vec::new();
+        for batch in [vec![], vec![key_up(KeyCode::Space)], vec![key_down(KeyCode::Space)]] {
+            input.process_frame(&mut state, &[batch]);
+            assert!(!input.published_actions().contains(&TestAction::Jump));
+            frames.push(input.action_events().to_vec());
+        }
+        frames
+    }
+
+    #[test]
+    fn remap_policy_maps_held_input_under_new_context() {
+        let frames = hold_space_across_menu_switch(ContextSwitchPolicy::Remap);
+
+        let phase = |phase| vec![ActionEvent { action: TestAction::Save, phase }];
+        assert_eq!(
+            frames,
+            vec![phase(ActionPhase::Held), phase(ActionPhase::Released), phase(ActionPhase::Pressed)]
+        );
+    }
+
+    #[test]
+    fn isolate_policy_silences_held_input_until_pressed_again() {
+        let frames = hold_space_across_menu_switch(ContextSwitchPolicy::Isolate);
+
+        assert_eq!(
+            frames,
+            vec![
+                vec![],
+                vec![],
+                vec![ActionEvent { action: TestAction::Save, phase: ActionPhase::Pressed }],
+            ]
+        );
+    }
+
+    #[test]
+    fn switch_applies_to_whole_next_frame() {
+        const MENU: InputContext = InputContext::custom(0);
+
+        let mut input = InputSystem::<TestAction>::new();
+        let mut state = StateTracker::new();
+        input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        input.bind_key(KeyCode::Enter, TestAction::Jump, InputContext::Primary);
+        input.bind_key(KeyCode::Enter, TestAction::Save, MENU);
+
+        input.set_context(MENU);
+        input.process_frame(
+            &mut state,
+            &[vec![key_down(KeyCode::Space)], vec![key_down(KeyCode::Enter)]],
+        );
+
+        assert_eq!(input.actions(), &[TestAction::Save]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,281 @@
+//=========================================================================
+// Binding Parser
+//=========================================================================
+//
+// Parses human-readable binding strings ("Ctrl+Shift+S") for config files.
+//
+// Grammar:
+//   binding   = [modifier "+"]* key
+//   modifier  = ctrl | control | shift | alt
+//
+// Tokens are case-insensitive and surrounding whitespace is ignored.
+// Meta-style tokens (meta, super, cmd, win) are rejected: the platform
+// layer does not track the Super key, so such bindings could never fire.
+//
+//=========================================================================
+
+//=== External Dependencies ===============================================
+
+use std::fmt;
+use std::str::FromStr;
+
+//=== Internal Dependencies ===============================================
+
+use super::event::{KeyCode, Modifiers};
+
+//=== ParseBindingError ===================================================
+
+/// Error returned when a binding string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBindingError {
+    /// A modifier token was not recognized (e.g. "Hyper").
+    UnknownModifier(String),
+
+    /// A modifier token names a key the engine does not track (e.g. "Win").
+    UnsupportedModifier(String),
+
+    /// The key token was not recognized (e.g. "Nonsense").
+    UnknownKey(String),
+
+    /// The binding had no key after the modifiers (e.g. "Ctrl+").
+    MissingKey,
+}
+
+impl fmt::Display for ParseBindingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownModifier(token) => write!(f, "Unknown modifier: {:?}", token),
+            Self::UnsupportedModifier(token) => {
+                write!(f, "Unsupported modifier: {:?} (only Ctrl, Shift and Alt)", token)
+            }
+            Self::UnknownKey(token) => write!(f, "Unknown key: {:?}", token),
+            Self::MissingKey => write!(f, "Binding has no key"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBindingError {}
+
+//=== Binding Parsing =====================================================
+
+/// Parses a binding such as `"Ctrl+Shift+S"` into modifiers and a key.
+///
+/// ```
+/// use aetheric_engine::core::input::{parse_binding, KeyCode, Modifiers};
+///
+/// assert_eq!(parse_binding("Ctrl+S"), Ok((Modifiers::CTRL, KeyCode::KeyS)));
+/// assert_eq!(parse_binding("space"), Ok((Modifiers::NONE, KeyCode::Space)));
+/// assert!(parse_binding("Ctrl+Nonsense").is_err());
+/// ```
+pub fn parse_binding(binding: &str) -> Result<(Modifiers, KeyCode), ParseBindingError> {
+    let (modifiers, key) = match binding.rsplit_once('+') {
+        Some((modifiers, key)) => (modifiers.parse()?, key),
+        None => (Modifiers::NONE, binding),
+    };
+
+    if key.trim().is_empty() {
+        return Err(ParseBindingError::MissingKey);
+    }
+
+    Ok((modifiers, key.parse()?))
+}
+
+//--- Trait Implementations -----------------------------------------------
+
+/// Parses `"+"`-separated modifier tokens (`"Ctrl+Shift"`). Empty is `NONE`.
+impl FromStr for Modifiers {
+    type Err = ParseBindingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Modifiers::NONE);
+        }
+
+        s.split('+').try_fold(Modifiers::NONE, |acc, token| {
+            let modifier = match token.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CTRL,
+                "shift" => Modifiers::SHIFT,
+                "alt" => Modifiers::ALT,
+                "meta" | "super" | "cmd" | "win" => {
+                    return Err(ParseBindingError::UnsupportedModifier(token.trim().to_string()))
+                }
+                _ => return Err(ParseBindingError::UnknownModifier(token.trim().to_string())),
+            };
+            Ok(acc | modifier)
+        })
+    }
+}
+
+/// Parses a key name (`"S"`, `"KeyS"`, `"7"`, `"Space"`, `"Up"`, ...).
+impl FromStr for KeyCode {
+    type Err = ParseBindingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = s.trim();
+        let lower = token.to_ascii_lowercase();
+
+        // Strip the "key"/"digit" prefixes used by the enum variant names
+        let name = lower
+            .strip_prefix("key")
+            .or_else(|| lower.strip_prefix("digit"))
+            .filter(|rest| rest.len() == 1)
+            .unwrap_or(&lower);
+
+        let key = match name {
+            "a" => KeyCode::KeyA, "b" => KeyCode::KeyB, "c" => KeyCode::KeyC,
+            "d" => KeyCode::KeyD, "e" => KeyCode::KeyE, "f" => KeyCode::KeyF,
+            "g" => KeyCode::KeyG, "h" => KeyCode::KeyH, "i" => KeyCode::KeyI,
+            "j" => KeyCode::KeyJ, "k" => KeyCode::KeyK, "l" => KeyCode::KeyL,
+            "m" => KeyCode::KeyM, "n" => KeyCode::KeyN, "o" => KeyCode::KeyO,
+            "p" => KeyCode::KeyP, "q" => KeyCode::KeyQ, "r" => KeyCode::KeyR,
+            "s" => KeyCode::KeyS, "t" => KeyCode::KeyT, "u" => KeyCode::KeyU,
+            "v" => KeyCode::KeyV, "w" => KeyCode::KeyW, "x" => KeyCode::KeyX,
+            "y" => KeyCode::KeyY, "z" => KeyCode::KeyZ,
+
+            "0" => KeyCode::Digit0, "1" => KeyCode::Digit1, "2" => KeyCode::Digit2,
+            "3" => KeyCode::Digit3, "4" => KeyCode::Digit4, "5" => KeyCode::Digit5,
+            "6" => KeyCode::Digit6, "7" => KeyCode::Digit7, "8" => KeyCode::Digit8,
+            "9" => KeyCode::Digit9,
+
+            "up" | "arrowup" => KeyCode::ArrowUp,
+            "down" | "arrowdown" => KeyCode::ArrowDown,
+            "left" | "arrowleft" => KeyCode::ArrowLeft,
+            "right" | "arrowright" => KeyCode::ArrowRight,
+
+            "space" => KeyCode::Space,
+            "enter" | "return" => KeyCode::Enter,
+            "escape" | "esc" => KeyCode::Escape,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+
+            _ => return Err(ParseBindingError::UnknownKey(token.to_string())),
+        };
+
+        Ok(key)
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //=====================================================================
+    // Modifiers
+    //=====================================================================
+
+    #[test]
+    fn modifiers_parse_single_tokens() {
+        assert_eq!("Ctrl".parse(), Ok(Modifiers::CTRL));
+        assert_eq!("control".parse(), Ok(Modifiers::CTRL));
+        assert_eq!("SHIFT".parse(), Ok(Modifiers::SHIFT));
+        assert_eq!("alt".parse(), Ok(Modifiers::ALT));
+    }
+
+    #[test]
+    fn modifiers_parse_combinations() {
+        assert_eq!("Ctrl+Shift".parse(), Ok(Modifiers::SHIFT_CTRL));
+        assert_eq!("shift + alt".parse(), Ok(Modifiers::SHIFT_ALT));
+        assert_eq!("Alt+Ctrl+Shift".parse(), Ok(Modifiers::ALL));
+    }
+
+    #[test]
+    fn modifiers_meta_tokens_are_unsupported() {
+        for token in ["meta", "Super", "CMD", "win"] {
+            assert_eq!(
+                token.parse::<Modifiers>(),
+                Err(ParseBindingError::UnsupportedModifier(token.to_string())),
+                "token {}",
+                token
+            );
+        }
+        assert_eq!(
+            parse_binding("Win+S"),
+            Err(ParseBindingError::UnsupportedModifier("Win".to_string()))
+        );
+    }
+
+    #[test]
+    fn modifiers_empty_is_none() {
+        assert_eq!("".parse(), Ok(Modifiers::NONE));
+    }
+
+    #[test]
+    fn modifiers_reject_unknown_token() {
+        assert_eq!(
+            "Ctrl+Hyper".parse::<Modifiers>(),
+            Err(ParseBindingError::UnknownModifier("Hyper".to_string()))
+        );
+    }
+
+    //=====================================================================
+    // KeyCode
+    //=====================================================================
+
+    #[test]
+    fn keycode_parses_letters_and_digits() {
+        assert_eq!("S".parse(), Ok(KeyCode::KeyS));
+        assert_eq!("keyw".parse(), Ok(KeyCode::KeyW));
+        assert_eq!("7".parse(), Ok(KeyCode::Digit7));
+        assert_eq!("Digit0".parse(), Ok(KeyCode::Digit0));
+    }
+
+    #[test]
+    fn keycode_parses_named_keys() {
+        assert_eq!("Space".parse(), Ok(KeyCode::Space));
+        assert_eq!("esc".parse(), Ok(KeyCode::Escape));
+        assert_eq!("Return".parse(), Ok(KeyCode::Enter));
+        assert_eq!("Up".parse(), Ok(KeyCode::ArrowUp));
+        assert_eq!("ArrowLeft".parse(), Ok(KeyCode::ArrowLeft));
+    }
+
+    //=====================================================================
+    // Bindings
+    //=====================================================================
+
+    #[test]
+    fn binding_parses_key_only() {
+        assert_eq!(parse_binding("Space"), Ok((Modifiers::NONE, KeyCode::Space)));
+    }
+
+    #[test]
+    fn binding_parses_modified_keys() {
+        assert_eq!(parse_binding("Ctrl+S"), Ok((Modifiers::CTRL, KeyCode::KeyS)));
+        assert_eq!(parse_binding("Ctrl+Shift+S"), Ok((Modifiers::SHIFT_CTRL, KeyCode::KeyS)));
+        assert_eq!(parse_binding("Shift+Alt+1"), Ok((Modifiers::SHIFT_ALT, KeyCode::Digit1)));
+    }
+
+    #[test]
+    fn binding_rejects_unknown_key() {
+        assert_eq!(
+            parse_binding("Ctrl+Nonsense"),
+            Err(ParseBindingError::UnknownKey("Nonsense".to_string()))
+        );
+        assert_eq!(parse_binding("alt+f4"), Err(ParseBindingError::UnknownKey("f4".to_string())));
+    }
+
+    #[test]
+    fn binding_rejects_missing_key() {
+        assert_eq!(parse_binding("Ctrl+"), Err(ParseBindingError::MissingKey));
+        assert_eq!(parse_binding(""), Err(ParseBindingError::MissingKey));
+    }
+
+    #[test]
+    fn displayed_bindings_parse_back() {
+        for &key in KeyCode::ALL {
+            assert_eq!(key.to_string().parse(), Ok(key));
+            let binding = format!("{}+{}", Modifiers::SHIFT_CTRL, key);
+            assert_eq!(parse_binding(&binding), Ok((Modifiers::SHIFT_CTRL, key)));
+        }
+    }
+
+    #[test]
+    fn error_messages_name_the_token() {
+        let err = parse_binding("Ctrl+Nonsense").unwrap_err();
+        assert!(err.to_string().contains("Nonsense"));
+    }
+}
@@ -0,0 +1,255 @@
+//=========================================================================
+// Input Snapshot
+//=========================================================================
+//
+// Plain, serializable copy of StateTracker's persistent state.
+//
+// Used for netcode and replays: StateTracker::snapshot() → transmit/store
+// → StateTracker::restore(). Sets are stored as sorted vectors so equal
+// states always produce identical snapshots (and serialized bytes).
+//
+// Deltas: snapshot.diff(&next) → InputDelta (what changed) → transmit
+// → snapshot.apply_delta(&delta) reconstructs `next` on the other side.
+//
+// Serde support is behind the `serde` feature.
+//
+//=========================================================================
+
+//=== Internal Dependencies ===============================================
+
+use super::event::{KeyCode, Modifiers, MouseButton};
+
+//=== InputSnapshot =======================================================
+
+/// Persistent input state at one point in time.
+///
+/// Captures what is held, not what changed: per-frame pressed/released
+/// flags are not included.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputSnapshot {
+    /// Keys held, sorted.
+    pub keys_down: Vec<KeyCode>,
+
+    /// Mouse buttons held, sorted.
+    pub mouse_buttons_down: Vec<MouseButton>,
+
+    /// Cursor position (pixels, top-left origin).
+    pub mouse_position: (f32, f32),
+
+    /// Modifier keys held.
+    pub modifiers: Modifiers,
+}
+
+impl InputSnapshot {
+    /// Returns the changes that turn `self` into `other`.
+    pub fn diff(&self, other: &InputSnapshot) -> InputDelta {
+        InputDelta {
+            keys_pressed: added(&self.keys_down, &other.keys_down),
+            keys_released: added(&other.keys_down, &self.keys_down),
+            buttons_pressed: added(&self.mouse_buttons_down, &other.mouse_buttons_down),
+            buttons_released: added(&other.mouse_buttons_down, &self.mouse_buttons_down),
+            mouse_position: (other.mouse_position != self.mouse_position)
+                .then_some(other.mouse_position),
+            modifiers: (other.modifiers != self.modifiers).then_some(other.modifiers),
+        }
+    }
+
+    /// Applies a delta produced by [`diff`](Self::diff), keeping sets sorted.
+    pub fn apply_delta(&mut self, delta: &InputDelta) {
+        apply(&mut self.keys_down, &delta.keys_pressed, &delta.keys_released);
+        apply(&mut self.mouse_buttons_down, &delta.buttons_pressed, &delta.buttons_released);
+
+        if let Some(position) = delta.mouse_position {
+            self.mouse_position = position;
+        }
+        if let Some(modifiers) = delta.modifiers {
+            self.modifiers = modifiers;
+        }
+    }
+}
+
+//=== InputDelta ==========================================================
+
+/// Changes between two [`InputSnapshot`]s, for per-frame netcode messages.
+///
+/// Unchanged fields are empty or `None`, so an idle frame is an empty delta.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputDelta {
+    /// Keys held in the later snapshot but not the earlier one, sorted.
+    pub keys_pressed: Vec<KeyCode>,
+
+    /// Keys held in the earlier snapshot but not the later one, sorted.
+    pub keys_released: Vec<KeyCode>,
+
+    /// Mouse buttons newly held, sorted.
+    pub buttons_pressed: Vec<MouseButton>,
+
+    /// Mouse buttons no longer held, sorted.
+    pub buttons_released: Vec<MouseButton>,
+
+    /// New cursor position, if it moved.
+    ///
+    /// Absolute rather than an offset so reconstruction is exact.
+    pub mouse_position: Option<(f32, f32)>,
+
+    /// New modifier state, if it changed.
+    pub modifiers: Option<Modifiers>,
+}
+
+impl InputDelta {
+    /// Returns `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+//=== Helpers =============================================================
+
+/// Items in sorted `to` that are missing from sorted `from`.
+fn added<T: Ord + Copy>(from: &[T], to: &[T]) -> Vec<T> {
+    to.iter().copied().filter(|item| from.binary_search(item).is_err()).collect()
+}
+
+/// Inserts `pressed` and removes `released`, keeping `set` sorted.
+fn apply<T: Ord + Copy>(set: &mut Vec<T>, pressed: &[T], released: &[T]) {
+    set.retain(|item| !released.contains(item));
+    for &item in pressed {
+        if let Err(index) = set.binary_search(&item) {
+            set.insert(index, item);
+        }
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::input::event::InputEvent;
+    use crate::core::input::StateTracker;
+
+    fn held_state() -> StateTracker {
+        let mut state = StateTracker::new();
+        state.clear();
+        state.process_events(&[
+            InputEvent::KeyDown { key: KeyCode::KeyW, modifiers: Modifiers::SHIFT },
+            InputEvent::KeyDown { key: KeyCode::KeyA, modifiers: Modifiers::SHIFT },
+            InputEvent::MouseButtonDown { button: MouseButton::Right, modifiers: Modifiers::SHIFT },
+            InputEvent::MouseMoved { x: 120.0, y: 48.0 },
+        ]);
+        state.finalize_frame();
+        state
+    }
+
+    #[test]
+    fn snapshot_sorts_held_inputs() {
+        let snapshot = held_state().snapshot();
+
+        assert_eq!(snapshot.keys_down, vec![KeyCode::KeyA, KeyCode::KeyW]);
+        assert_eq!(snapshot.mouse_buttons_down, vec![MouseButton::Right]);
+        assert_eq!(snapshot.mouse_position, (120.0, 48.0));
+        assert_eq!(snapshot.modifiers, Modifiers::SHIFT);
+    }
+
+    #[test]
+    fn restore_round_trips_queries() {
+        let original = held_state();
+        let mut restored = StateTracker::new();
+
+        restored.restore(&original.snapshot());
+
+        assert_eq!(restored.snapshot(), original.snapshot());
+        for key in [KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS] {
+            assert_eq!(restored.is_key_down(key), original.is_key_down(key));
+        }
+        assert!(restored.is_button_down(MouseButton::Right));
+        assert_eq!(restored.mouse_position(), original.mouse_position());
+        assert!(restored.shift_held());
+
+        // Restoring doesn't advance the input tick: the next frame is tick 1
+        restored.clear();
+        restored.process_events(&[InputEvent::KeyUp { key: KeyCode::KeyW, modifiers: Modifiers::NONE }]);
+        assert_eq!(restored.input_log().iter().last().map(|entry| entry.tick), Some(1));
+    }
+
+    #[test]
+    fn restore_clears_frame_deltas() {
+        let mut state = held_state();
+        assert!(state.is_key_pressed(KeyCode::KeyW));
+
+        state.restore(&InputSnapshot {
+            keys_down: vec![KeyCode::KeyW],
+            mouse_buttons_down: vec![],
+            mouse_position: (0.0, 0.0),
+            modifiers: Modifiers::NONE,
+        });
+
+        assert!(state.is_key_down(KeyCode::KeyW));
+        assert!(!state.is_key_down(KeyCode::KeyA));
+        assert!(!state.is_key_pressed(KeyCode::KeyW));
+        assert_eq!(state.mouse_delta(), (0.0, 0.0));
+    }
+
+    //--- Deltas -----------------------------------------------------------
+
+    fn snapshot(
+        keys: &[KeyCode],
+        buttons: &[MouseButton],
+        position: (f32, f32),
+    ) -> InputSnapshot {
+        InputSnapshot {
+            keys_down: keys.to_vec(),
+            mouse_buttons_down: buttons.to_vec(),
+            mouse_position: position,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn diff_lists_pressed_and_released() {
+        let before = snapshot(&[KeyCode::KeyA, KeyCode::KeyW], &[MouseButton::Left], (1.0, 2.0));
+        let after = snapshot(&[KeyCode::KeyD, KeyCode::KeyW], &[MouseButton::Right], (4.5, 2.0));
+
+        let delta = before.diff(&after);
+
+        assert_eq!(delta.keys_pressed, vec![KeyCode::KeyD]);
+        assert_eq!(delta.keys_released, vec![KeyCode::KeyA]);
+        assert_eq!(delta.buttons_pressed, vec![MouseButton::Right]);
+        assert_eq!(delta.buttons_released, vec![MouseButton::Left]);
+        assert_eq!(delta.mouse_position, Some((4.5, 2.0)));
+        assert_eq!(delta.modifiers, None);
+    }
+
+    #[test]
+    fn apply_delta_reconstructs_later_snapshot() {
+        let before = held_state().snapshot();
+        let mut after = before.clone();
+        after.keys_down = vec![KeyCode::KeyD, KeyCode::KeyS, KeyCode::KeyW];
+        after.mouse_buttons_down.clear();
+        after.mouse_position = (-3.25, 900.0);
+        after.modifiers = Modifiers::CTRL;
+
+        let mut rebuilt = before.clone();
+        rebuilt.apply_delta(&before.diff(&after));
+
+        assert_eq!(rebuilt, after);
+    }
+
+    #[test]
+    fn identical_snapshots_give_empty_delta() {
+        let state = held_state().snapshot();
+        assert!(state.diff(&state).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_is_serializable() {
+        fn assert_serde<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+        assert_serde::<InputSnapshot>();
+        assert_serde::<InputDelta>();
+    }
+}
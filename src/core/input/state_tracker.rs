@@ -13,11 +13,19 @@
 
 //=== External Dependencies ===============================================
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 //=== Internal Dependencies ===============================================
 
 use super::event::{Modifiers, InputEvent, KeyCode, MouseButton};
+use super::input_log::InputLog;
+use super::snapshot::InputSnapshot;
+use super::DEFAULT_DOUBLE_TAP_TICKS;
+
+//=== Types ===============================================================
+
+/// Callback invoked with every raw event the tracker processes.
+pub(crate) type InputObserver = Box<dyn FnMut(&InputEvent) + Send>;
 
 //=== StateTracker ========================================================
 
@@ -27,11 +35,24 @@ pub struct StateTracker {
     //--- Persistent State (survives frame boundary) ----------------------
     keys_down: HashSet<KeyCode>,
     mouse_buttons_down: HashSet<MouseButton>,
+    button_press_positions: HashMap<MouseButton, (f32, f32)>,
     mouse_position: (f32, f32),
+    cursor_in_window: bool,
     modifiers: Modifiers,
+    frame_start_modifiers: Modifiers,
+    logical_chars: HashMap<KeyCode, char>,
+    key_press_ticks: HashMap<KeyCode, u64>,
+    key_release_ticks: HashMap<KeyCode, u64>,
+    button_release_ticks: HashMap<MouseButton, u64>,
+    debounce_ticks: u64,
+    key_tap_ticks: HashMap<KeyCode, u64>,
+    double_tap_ticks: u64,
+    mouse_scale: (f32, f32),
+    tick: u64,
 
     //--- Frame Deltas (reset each frame via clear()) --------------------
     keys_pressed_this_frame: HashSet<KeyCode>,
+    keys_double_tapped_this_frame: HashSet<KeyCode>,
     keys_released_this_frame: HashSet<KeyCode>,
     mouse_buttons_pressed_this_frame: HashSet<MouseButton>,
     mouse_buttons_released_this_frame: HashSet<MouseButton>,
@@ -39,6 +60,13 @@ pub struct StateTracker {
     //--- Continuous Input (accumulated/calculated) -----------------------
     mouse_delta: (f32, f32),
     last_mouse_position: (f32, f32),
+    scroll_delta: (f32, f32),
+    raw_mouse_delta: (f32, f32),
+    mouse_resync_pending: bool,
+
+    //--- Diagnostics -----------------------------------------------------
+    observer: Option<InputObserver>,
+    input_log: InputLog,
 }
 
 impl StateTracker {
@@ -47,14 +75,32 @@ impl StateTracker {
         Self {
             keys_down: HashSet::new(),
             mouse_buttons_down: HashSet::new(),
+            button_press_positions: HashMap::new(),
             mouse_position: (0.0, 0.0),
+            cursor_in_window: false,
             modifiers: Modifiers::NONE,
+            frame_start_modifiers: Modifiers::NONE,
+            logical_chars: HashMap::new(),
+            key_press_ticks: HashMap::new(),
+            key_release_ticks: HashMap::new(),
+            button_release_ticks: HashMap::new(),
+            debounce_ticks: 0,
+            key_tap_ticks: HashMap::new(),
+            double_tap_ticks: DEFAULT_DOUBLE_TAP_TICKS,
+            mouse_scale: (1.0, 1.0),
+            tick: 0,
             keys_pressed_this_frame: HashSet::new(),
+            keys_double_tapped_this_frame: HashSet::new(),
             keys_released_this_frame: HashSet::new(),
             mouse_buttons_pressed_this_frame: HashSet::new(),
             mouse_buttons_released_this_frame: HashSet::new(),
             mouse_delta: (0.0, 0.0),
             last_mouse_position: (0.0, 0.0),
+            scroll_delta: (0.0, 0.0),
+            raw_mouse_delta: (0.0, 0.0),
+            mouse_resync_pending: false,
+            observer: None,
+            input_log: InputLog::default(),
         }
     }
 
@@ -62,11 +108,21 @@ impl StateTracker {
 
     /// Clears frame-specific deltas (pressed/released flags).
     pub(super) fn clear(&mut self) {
+        self.tick += 1;
+        self.reset_frame();
+    }
+
+    /// Resets frame deltas without advancing the input tick.
+    fn reset_frame(&mut self) {
         self.keys_pressed_this_frame.clear();
+        self.keys_double_tapped_this_frame.clear();
         self.keys_released_this_frame.clear();
         self.mouse_buttons_pressed_this_frame.clear();
         self.mouse_buttons_released_this_frame.clear();
+        self.frame_start_modifiers = self.modifiers;
         self.last_mouse_position = self.mouse_position;
+        self.scroll_delta = (0.0, 0.0);
+        self.raw_mouse_delta = (0.0, 0.0);
     }
 
     /// Processes input events, updating internal state.
@@ -76,22 +132,152 @@ impl StateTracker {
         }
     }
 
-    /// Finalizes frame calculations (calculates mouse delta).
+    /// Finalizes frame calculations (mouse delta, button press positions).
     pub(super) fn finalize_frame(&mut self) {
         self.mouse_delta = (
             self.mouse_position.0 - self.last_mouse_position.0,
             self.mouse_position.1 - self.last_mouse_position.1,
         );
+
+        // Discrete and continuous input arrive in separate batches, so the
+        // frame's final position is the best estimate of where a press landed
+        for &button in &self.mouse_buttons_pressed_this_frame {
+            if self.mouse_buttons_down.contains(&button) {
+                self.button_press_positions.insert(button, self.mouse_position);
+            }
+        }
+    }
+
+    /// Installs a callback that sees every event before it is applied.
+    pub(crate) fn set_observer(&mut self, observer: InputObserver) {
+        self.observer = Some(observer);
+    }
+
+    /// Sets the debounce window; `0` disables debouncing.
+    pub(super) fn set_debounce_ticks(&mut self, ticks: u64) {
+        self.debounce_ticks = ticks;
+    }
+
+    /// Sets the longest gap, in ticks, between the presses of a double tap.
+    pub(super) fn set_double_tap_ticks(&mut self, ticks: u64) {
+        self.double_tap_ticks = ticks;
+    }
+
+    /// Resizes the recent-event log, keeping the newest entries.
+    pub(super) fn set_input_log_capacity(&mut self, capacity: usize) {
+        if self.input_log.capacity() != capacity {
+            self.input_log.set_capacity(capacity);
+        }
+    }
+
+    /// Sets the per-axis factors applied to reported mouse deltas.
+    pub(super) fn set_mouse_scale(&mut self, scale: (f32, f32)) {
+        self.mouse_scale = scale;
+    }
+
+    /// Zeroes this frame's mouse delta (cursor outside the window).
+    pub(super) fn reset_mouse_delta(&mut self) {
+        self.mouse_delta = (0.0, 0.0);
+    }
+
+    //--- Snapshots --------------------------------------------------------
+
+    /// Captures held keys/buttons, mouse position, and modifiers.
+    ///
+    /// Held sets are sorted so equal states give equal snapshots.
+    pub fn snapshot(&self) -> InputSnapshot {
+        InputSnapshot {
+            keys_down: self.keys_down_sorted(),
+            mouse_buttons_down: self.buttons_down_sorted(),
+            mouse_position: self.mouse_position,
+            modifiers: self.modifiers,
+        }
+    }
+
+    /// Replaces persistent state with `snapshot`.
+    ///
+    /// Frame deltas (pressed/released, mouse delta) are cleared, so the
+    /// restored state reads as if the inputs had been held since last frame.
+    /// The input tick is left unchanged.
+    pub fn restore(&mut self, snapshot: &InputSnapshot) {
+        self.keys_down = snapshot.keys_down.iter().copied().collect();
+        self.mouse_buttons_down = snapshot.mouse_buttons_down.iter().copied().collect();
+        self.mouse_position = snapshot.mouse_position;
+        self.button_press_positions = snapshot
+            .mouse_buttons_down
+            .iter()
+            .map(|&button| (button, snapshot.mouse_position))
+            .collect();
+        self.modifiers = snapshot.modifiers;
+        self.key_press_ticks.clear();
+        self.key_tap_ticks.clear();
+        self.mouse_resync_pending = false;
+
+        self.reset_frame();
+        self.mouse_delta = (0.0, 0.0);
+    }
+
+    //--- Simulation (testing) ---------------------------------------------
+
+    /// Runs `frames` frames with `key` held, pressing it on the first.
+    ///
+    /// The key stays down afterwards; follow with
+    /// [`simulate_release`](Self::simulate_release) to let go. If it was
+    /// already held, no new press is recorded.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn simulate_hold(&mut self, key: KeyCode, frames: usize) -> &mut Self {
+        let down = [InputEvent::KeyDown { key, modifiers: self.modifiers }];
+        for _ in 0..frames {
+            self.simulate_frame(&down);
+        }
+        self
+    }
+
+    /// Runs one frame in which `key` is released.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn simulate_release(&mut self, key: KeyCode) -> &mut Self {
+        self.simulate_frame(&[InputEvent::KeyUp { key, modifiers: self.modifiers }])
+    }
+
+    /// Runs one frame in which `key` is pressed and released.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn simulate_tap(&mut self, key: KeyCode) -> &mut Self {
+        let modifiers = self.modifiers;
+        self.simulate_frame(&[InputEvent::KeyTapped { key, modifiers }])
+    }
+
+    /// Runs one full frame lifecycle over `events`.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn simulate_frame(&mut self, events: &[InputEvent]) -> &mut Self {
+        self.clear();
+        self.process_events(events);
+        self.finalize_frame();
+        self
     }
 
     //--- Internal Helpers -------------------------------------------------
+    /// Shows one raw event to the observer and the log, then applies it.
     fn process_event(&mut self, event: &InputEvent) {
+        if let Some(observer) = &mut self.observer {
+            observer(event);
+        }
+        self.input_log.record(self.tick, event.clone());
+        self.apply_event(event);
+    }
+
+    /// Updates state from one event (a tap applies as down then up).
+    fn apply_event(&mut self, event: &InputEvent) {
         match event {
             InputEvent::KeyDown { key, modifiers } => {
                 self.modifiers = *modifiers;
+                if self.is_bounce(self.key_release_ticks.get(key)) {
+                    return;
+                }
                 // Only mark as pressed if it wasn't already down
                 if self.keys_down.insert(*key) {
                     self.keys_pressed_this_frame.insert(*key);
+                    self.key_press_ticks.insert(*key, self.tick);
+                    self.register_tap(*key);
                 }
             }
 
@@ -100,11 +286,25 @@ impl StateTracker {
                 // Only mark as released if it was actually down
                 if self.keys_down.remove(key) {
                     self.keys_released_this_frame.insert(*key);
+                    self.key_release_ticks.insert(*key, self.tick);
                 }
             }
 
+            InputEvent::KeyChar { key, ch } => {
+                self.logical_chars.insert(*key, *ch);
+            }
+
+            InputEvent::KeyTapped { key, modifiers } => {
+                // Equivalent to KeyDown followed by KeyUp
+                self.apply_event(&InputEvent::KeyDown { key: *key, modifiers: *modifiers });
+                self.apply_event(&InputEvent::KeyUp { key: *key, modifiers: *modifiers });
+            }
+
             InputEvent::MouseButtonDown { button, modifiers } => {
                 self.modifiers = *modifiers;
+                if self.is_bounce(self.button_release_ticks.get(button)) {
+                    return;
+                }
                 if self.mouse_buttons_down.insert(*button) {
                     self.mouse_buttons_pressed_this_frame.insert(*button);
                 }
@@ -113,20 +313,83 @@ impl StateTracker {
             InputEvent::MouseButtonUp { button, modifiers } => {
                 self.modifiers = *modifiers;
                 if self.mouse_buttons_down.remove(button) {
+                    self.button_press_positions.remove(button);
                     self.mouse_buttons_released_this_frame.insert(*button);
+                    self.button_release_ticks.insert(*button, self.tick);
                 }
             }
 
             InputEvent::MouseMoved { x, y } => {
+                if self.mouse_resync_pending {
+                    // First position after a resize: re-anchor, don't move
+                    self.mouse_resync_pending = false;
+                    self.last_mouse_position.0 += x - self.mouse_position.0;
+                    self.last_mouse_position.1 += y - self.mouse_position.1;
+                }
+                self.mouse_position = (*x, *y);
+            }
+
+            InputEvent::CursorWarped { x, y } => {
+                // Shift the delta baseline by the jump so only real motion counts
+                self.last_mouse_position.0 += x - self.mouse_position.0;
+                self.last_mouse_position.1 += y - self.mouse_position.1;
                 self.mouse_position = (*x, *y);
             }
 
+            InputEvent::MouseScrolled { dx, dy } => {
+                self.scroll_delta.0 += dx;
+                self.scroll_delta.1 += dy;
+            }
+
+            InputEvent::MouseMotion { dx, dy } => {
+                self.raw_mouse_delta.0 += dx;
+                self.raw_mouse_delta.1 += dy;
+            }
+
+            InputEvent::WindowResized { .. } => {
+                // The cached position may no longer match the new layout
+                self.mouse_resync_pending = true;
+            }
+
+            InputEvent::CursorEntered => {
+                self.cursor_in_window = true;
+            }
+
+            InputEvent::CursorLeft => {
+                self.cursor_in_window = false;
+            }
+
             InputEvent::Unidentified => {
-                // Ignore unrecognized events
+                // Ignore unrecognized events (unmapped keys are reported by
+                // the platform's InputProcessor, which knows the winit code)
             }
         }
     }
 
+    /// Completes a double tap if `key`'s armed first tap is recent enough,
+    /// otherwise arms this press as a first tap.
+    ///
+    /// A completed double tap disarms, so a third quick press starts over.
+    fn register_tap(&mut self, key: KeyCode) {
+        let within_window = self.key_tap_ticks
+            .get(&key)
+            .is_some_and(|&first| self.tick - first <= self.double_tap_ticks);
+
+        if within_window {
+            self.key_tap_ticks.remove(&key);
+            self.keys_double_tapped_this_frame.insert(key);
+        } else {
+            self.key_tap_ticks.insert(key, self.tick);
+        }
+    }
+
+    /// Returns `true` if a press now would follow a release within the
+    /// debounce window.
+    fn is_bounce(&self, released_at: Option<&u64>) -> bool {
+        self.debounce_ticks > 0
+            && released_at.is_some_and(|&released| self.tick - released <= self.debounce_ticks)
+    }
+
     //=====================================================================
     // Query API - Keyboard
     //=====================================================================
@@ -138,6 +401,49 @@ impl StateTracker {
         self.keys_pressed_this_frame.contains(&key)
     }
 
+    /// Returns `true` if any key was pressed this frame, for "press any key"
+    /// prompts.
+    ///
+    /// With `include_modifiers` false, modifier presses are ignored, so
+    /// tapping Shift alone does not skip the prompt. Modifiers only show up
+    /// in [`modifiers`](Self::modifiers) today, so with `include_modifiers`
+    /// true a modifier newly held this frame also counts.
+    pub fn any_key_pressed(&self, include_modifiers: bool) -> bool {
+        if include_modifiers {
+            let (now, before) = (self.modifiers, self.frame_start_modifiers);
+            let modifier_pressed = (now.shift && !before.shift)
+                || (now.ctrl && !before.ctrl)
+                || (now.alt && !before.alt);
+            return modifier_pressed || !self.keys_pressed_this_frame.is_empty();
+        }
+
+        self.keys_pressed_this_frame.iter().any(|key| !key.is_modifier())
+    }
+
+    /// Returns `true` if key was pressed this tick or within the last `ticks`.
+    ///
+    /// Input buffering for action games: a jump pressed a couple of ticks
+    /// before landing still counts. `ticks == 0` matches
+    /// [`is_key_pressed`](Self::is_key_pressed). The press stays buffered
+    /// after release; consumers should act on it once.
+    pub fn is_key_pressed_buffered(&self, key: KeyCode, ticks: u64) -> bool {
+        self.key_press_ticks
+            .get(&key)
+            .is_some_and(|&pressed| self.tick - pressed <= ticks)
+    }
+
+    /// Returns `true` if key was pressed this frame as the second of two
+    /// presses within the double-tap window (one frame only).
+    ///
+    /// Use for "double-tap a direction to dash". The window defaults to
+    /// [`DEFAULT_DOUBLE_TAP_TICKS`](super::DEFAULT_DOUBLE_TAP_TICKS) and is
+    /// set with [`InputSystem::set_double_tap_ticks`](super::InputSystem::set_double_tap_ticks).
+    /// Each double tap needs two fresh presses: a third quick press does not
+    /// fire again.
+    pub fn is_key_double_tapped(&self, key: KeyCode) -> bool {
+        self.keys_double_tapped_this_frame.contains(&key)
+    }
+
     /// Returns `true` while key is held.
     ///
     /// Use for continuous actions like movement or charging.
@@ -152,6 +458,22 @@ impl StateTracker {
         self.keys_released_this_frame.contains(&key)
     }
 
+    /// Returns the character `key` most recently produced, if known.
+    ///
+    /// Reflects the active keyboard layout (e.g., `KeyQ` → `'a'` on AZERTY),
+    /// for prompt text like "Press A". Gameplay should stay on physical
+    /// [`KeyCode`]s. `None` until the key has been pressed once.
+    pub fn logical_char(&self, key: KeyCode) -> Option<char> {
+        self.logical_chars.get(&key).copied()
+    }
+
+    /// Returns `true` while key is held and the current modifiers match exactly.
+    ///
+    /// `Modifiers::NONE` requires that no modifier is held.
+    pub fn is_key_down_with_mods(&self, key: KeyCode, modifiers: Modifiers) -> bool {
+        self.is_key_down(key) && self.modifiers == modifiers
+    }
+
     //=====================================================================
     // Query API - Mouse Buttons
     //=====================================================================
@@ -171,6 +493,22 @@ impl StateTracker {
         self.mouse_buttons_released_this_frame.contains(&button)
     }
 
+    /// Returns where the cursor was when `button` went down, while it is held.
+    ///
+    /// For sliders and drag handles: the drag offset is `mouse_position()`
+    /// minus this. Recorded at the end of the press frame; `None` once the
+    /// button is released. A button held when a snapshot is
+    /// [restored](Self::restore) reports the restored position.
+    pub fn button_press_position(&self, button: MouseButton) -> Option<(f32, f32)> {
+        self.button_press_positions.get(&button).copied()
+    }
+
+    /// Like [`is_key_down_with_mods`](Self::is_key_down_with_mods) but for
+    /// mouse buttons (e.g., Shift+Click held vs plain Click held).
+    pub fn is_button_down_with_mods(&self, button: MouseButton, modifiers: Modifiers) -> bool {
+        self.is_button_down(button) && self.modifiers == modifiers
+    }
+
     //=====================================================================
     // Query API - Mouse Position & Movement
     //=====================================================================
@@ -182,11 +520,41 @@ impl StateTracker {
 
     /// Returns mouse movement delta (0,0 if no movement).
     ///
-    /// Useful for camera control, drag operations, etc.
+    /// Useful for camera control, drag operations, etc. Scaled by the
+    /// input system's [mouse sensitivity](super::InputSystem::set_mouse_sensitivity).
     pub fn mouse_delta(&self) -> (f32, f32) {
-        self.mouse_delta
+        self.scaled(self.mouse_delta)
     }
 
+    /// Returns wheel scroll this frame in lines (0,0 if none).
+    ///
+    /// Positive `y` scrolls up (away from the user).
+    pub fn scroll_delta(&self) -> (f32, f32) {
+        self.scroll_delta
+    }
+
+    /// Returns raw device motion this frame (0,0 if none).
+    ///
+    /// Unaccelerated and unaffected by the cursor reaching a screen edge,
+    /// unlike [`mouse_delta`](Self::mouse_delta). Suited to camera control.
+    /// Scaled by mouse sensitivity like `mouse_delta`.
+    pub fn raw_mouse_delta(&self) -> (f32, f32) {
+        self.scaled(self.raw_mouse_delta)
+    }
+
+    /// Applies mouse sensitivity to a delta.
+    fn scaled(&self, (dx, dy): (f32, f32)) -> (f32, f32) {
+        (dx * self.mouse_scale.0, dy * self.mouse_scale.1)
+    }
+
+    /// Returns `true` while the cursor is over a window's client area.
+    ///
+    /// Driven by the platform's cursor enter/leave events; `false` until the
+    /// cursor first enters a window. `mouse_position` keeps reporting the last
+    /// (possibly out-of-bounds) position while this is `false`.
+    pub fn mouse_in_window(&self) -> bool {
+        self.cursor_in_window
+    }
 
     //=====================================================================
     // Query API - Modifiers
@@ -212,6 +580,41 @@ impl StateTracker {
         self.modifiers.alt
     }
 
+    //=====================================================================
+    // Query API - Activity
+    //=====================================================================
+
+    /// Returns the number of keys currently held.
+    pub fn keys_down_count(&self) -> usize {
+        self.keys_down.len()
+    }
+
+    /// Returns the number of mouse buttons currently held.
+    pub fn buttons_down_count(&self) -> usize {
+        self.mouse_buttons_down.len()
+    }
+
+    /// Returns `true` if the user did nothing this frame.
+    ///
+    /// Idle means no keys or buttons are held or were pressed this frame,
+    /// and the mouse did not move. Useful for idle timers (dimming, autosave).
+    pub fn is_idle(&self) -> bool {
+        self.keys_down.is_empty()
+            && self.mouse_buttons_down.is_empty()
+            && self.keys_pressed_this_frame.is_empty()
+            && self.mouse_buttons_pressed_this_frame.is_empty()
+            && self.mouse_delta == (0.0, 0.0)
+    }
+
+    /// Returns the most recent raw events with the tick each was processed
+    /// on, oldest first.
+    ///
+    /// A short replay of what the player did, for crash reports and bug
+    /// triage. Sized by [`InputSystem::set_input_log_capacity`](super::InputSystem::set_input_log_capacity).
+    pub fn input_log(&self) -> &InputLog {
+        &self.input_log
+    }
+
     //=====================================================================
     // Query API - Iteration
     //=====================================================================
@@ -245,6 +648,52 @@ impl StateTracker {
     pub fn buttons_released(&self) -> impl Iterator<Item = &MouseButton> {
         self.mouse_buttons_released_this_frame.iter()
     }
+
+    //=====================================================================
+    // Query API - Sorted Iteration
+    //=====================================================================
+    //
+    // The iterators above follow HashSet order, which differs between runs.
+    // These return the same inputs in `Ord` order for deterministic
+    // "first key" logic, UI listings and snapshots.
+
+    /// Returns all keys currently held, sorted.
+    pub fn keys_down_sorted(&self) -> Vec<KeyCode> {
+        sorted(&self.keys_down)
+    }
+
+    /// Returns all keys pressed this frame, sorted.
+    pub fn keys_pressed_sorted(&self) -> Vec<KeyCode> {
+        sorted(&self.keys_pressed_this_frame)
+    }
+
+    /// Returns all keys released this frame, sorted.
+    pub fn keys_released_sorted(&self) -> Vec<KeyCode> {
+        sorted(&self.keys_released_this_frame)
+    }
+
+    /// Returns all mouse buttons currently held, sorted.
+    pub fn buttons_down_sorted(&self) -> Vec<MouseButton> {
+        sorted(&self.mouse_buttons_down)
+    }
+
+    /// Returns all mouse buttons pressed this frame, sorted.
+    pub fn buttons_pressed_sorted(&self) -> Vec<MouseButton> {
+        sorted(&self.mouse_buttons_pressed_this_frame)
+    }
+
+    /// Returns all mouse buttons released this frame, sorted.
+    pub fn buttons_released_sorted(&self) -> Vec<MouseButton> {
+        sorted(&self.mouse_buttons_released_this_frame)
+    }
+}
+
+//--- Helpers -------------------------------------------------------------
+
+fn sorted<T: Ord + Copy>(set: &HashSet<T>) -> Vec<T> {
+    let mut items: Vec<T> = set.iter().copied().collect();
+    items.sort_unstable();
+    items
 }
 
 //--- Trait Implementations -----------------------------------------------
@@ -262,6 +711,7 @@ impl Default for StateTracker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
 
     //--- Test Helpers -----------------------------------------------------
 
@@ -289,6 +739,63 @@ mod tests {
     // Keyboard Tests
     //=====================================================================
 
+    /// Tests that processed events are logged with their tick.
+    #[test]
+    fn input_log_records_events_with_ticks() {
+        let mut system = StateTracker::new();
+        system.simulate_frame(&[key_down(KeyCode::KeyA)]);
+        system.simulate_frame(&[]);
+        system.simulate_frame(&[key_up(KeyCode::KeyA), mouse_move(3.0, 4.0)]);
+
+        let logged: Vec<(u64, &InputEvent)> =
+            system.input_log().iter().map(|entry| (entry.tick, &entry.event)).collect();
+
+        assert_eq!(logged, vec![
+            (1, &key_down(KeyCode::KeyA)),
+            (3, &key_up(KeyCode::KeyA)),
+            (3, &mouse_move(3.0, 4.0)),
+        ]);
+    }
+
+    /// Tests a coalesced tap is logged as the single raw event.
+    #[test]
+    fn input_log_records_tap_once() {
+        let mut system = StateTracker::new();
+        let tap = InputEvent::KeyTapped { key: KeyCode::KeyA, modifiers: Modifiers::NONE };
+        system.simulate_frame(std::slice::from_ref(&tap));
+
+        let logged: Vec<&InputEvent> = system.input_log().iter().map(|entry| &entry.event).collect();
+        assert_eq!(logged, vec![&tap]);
+    }
+
+    /// Tests that a Shift press alone (a held key re-reported with Shift)
+    /// is not "any key" unless modifiers are included.
+    #[test]
+    fn shift_alone_does_not_satisfy_any_key() {
+        let mut system = StateTracker::new();
+        system.simulate_frame(&[key_down(KeyCode::KeyW)]);
+
+        system.simulate_frame(&[InputEvent::KeyDown { key: KeyCode::KeyW, modifiers: Modifiers::SHIFT }]);
+
+        assert!(!system.any_key_pressed(false));
+        assert!(system.any_key_pressed(true));
+
+        system.simulate_frame(&[]);
+        assert!(!system.any_key_pressed(true));
+    }
+
+    /// Tests that pressing a letter satisfies "any key", modifiers or not.
+    #[test]
+    fn letter_press_satisfies_any_key() {
+        let mut system = StateTracker::new();
+
+        system.simulate_frame(&[InputEvent::KeyDown { key: KeyCode::KeyA, modifiers: Modifiers::SHIFT }]);
+
+        assert!(system.any_key_pressed(false));
+        assert!(system.any_key_pressed(true));
+        assert!(!KeyCode::KeyA.is_modifier());
+    }
+
     /// Tests that key_pressed only returns true on transition frame.
     #[test]
     fn key_pressed_only_on_transition_frame() {
@@ -317,6 +824,28 @@ mod tests {
         assert!(system.is_key_released(KeyCode::KeyA));
     }
 
+    /// Tests that a coalesced tap matches an uncoalesced press/release pair.
+    #[test]
+    fn key_tapped_matches_down_up_pair() {
+        let tap = InputEvent::KeyTapped { key: KeyCode::Space, modifiers: Modifiers::NONE };
+
+        let mut coalesced = StateTracker::new();
+        coalesced.clear();
+        coalesced.process_events(&[tap]);
+        coalesced.finalize_frame();
+
+        let mut raw = StateTracker::new();
+        raw.clear();
+        raw.process_events(&[key_down(KeyCode::Space), key_up(KeyCode::Space)]);
+        raw.finalize_frame();
+
+        for tracker in [&coalesced, &raw] {
+            assert!(tracker.is_key_pressed(KeyCode::Space));
+            assert!(tracker.is_key_released(KeyCode::Space));
+            assert!(!tracker.is_key_down(KeyCode::Space));
+        }
+    }
+
     /// Tests that key_down persists across frames.
     #[test]
     fn key_down_persists_across_frames() {
@@ -439,6 +968,35 @@ mod tests {
         assert!(!system.is_button_down(MouseButton::Right));
     }
 
+    /// Tests that the press position is kept while dragging and cleared on release.
+    #[test]
+    fn button_press_position_tracks_drag_start() {
+        let mut system = StateTracker::new();
+        system.simulate_frame(&[mouse_move(40.0, 25.0)]);
+        assert_eq!(system.button_press_position(MouseButton::Left), None);
+
+        system.simulate_frame(&[mouse_down(MouseButton::Left)]);
+        assert_eq!(system.button_press_position(MouseButton::Left), Some((40.0, 25.0)));
+
+        system.simulate_frame(&[mouse_move(90.0, 30.0)]);
+        assert_eq!(system.button_press_position(MouseButton::Left), Some((40.0, 25.0)));
+        assert_eq!(system.mouse_position(), (90.0, 30.0));
+
+        system.simulate_frame(&[mouse_up(MouseButton::Left)]);
+        assert_eq!(system.button_press_position(MouseButton::Left), None);
+    }
+
+    /// Tests that a press uses the position the cursor reached that frame.
+    #[test]
+    fn button_press_position_uses_press_frame_position() {
+        let mut system = StateTracker::new();
+
+        system.simulate_frame(&[mouse_down(MouseButton::Right), mouse_move(12.0, 7.0)]);
+
+        assert_eq!(system.button_press_position(MouseButton::Right), Some((12.0, 7.0)));
+        assert_eq!(system.button_press_position(MouseButton::Left), None);
+    }
+
     //=====================================================================
     // Mouse Movement Tests
     //=====================================================================
@@ -481,6 +1039,44 @@ mod tests {
     // Modifier Tests
     //=====================================================================
 
+    /// Tests that modifier-aware queries require both input and exact modifiers.
+    #[test]
+    fn down_with_mods_requires_matching_modifiers() {
+        let mut system = StateTracker::new();
+
+        system.clear();
+        system.process_events(&[InputEvent::MouseButtonDown {
+            button: MouseButton::Left,
+            modifiers: Modifiers::SHIFT,
+        }]);
+        system.finalize_frame();
+
+        assert!(system.is_button_down_with_mods(MouseButton::Left, Modifiers::SHIFT));
+        assert!(!system.is_button_down_with_mods(MouseButton::Left, Modifiers::NONE));
+        assert!(!system.is_button_down_with_mods(MouseButton::Right, Modifiers::SHIFT));
+
+        // Releasing Shift (reported with the next key event) breaks the match
+        system.clear();
+        system.process_events(&[key_down(KeyCode::KeyA)]);
+        system.finalize_frame();
+
+        assert!(system.is_button_down_with_mods(MouseButton::Left, Modifiers::NONE));
+        assert!(system.is_key_down_with_mods(KeyCode::KeyA, Modifiers::NONE));
+        assert!(!system.is_key_down_with_mods(KeyCode::KeyA, Modifiers::SHIFT));
+    }
+
+    #[test]
+    fn key_down_with_mods_false_when_key_not_held() {
+        let mut system = StateTracker::new();
+
+        system.clear();
+        system.process_events(&[InputEvent::KeyDown { key: KeyCode::KeyS, modifiers: Modifiers::CTRL }]);
+        system.finalize_frame();
+
+        assert!(system.is_key_down_with_mods(KeyCode::KeyS, Modifiers::CTRL));
+        assert!(!system.is_key_down_with_mods(KeyCode::KeyD, Modifiers::CTRL));
+    }
+
     /// Tests that modifiers are updated from events.
     #[test]
     fn modifiers_updated_on_key_events() {
@@ -556,7 +1152,7 @@ mod tests {
         system.process_events(&[mouse_move(100.0, 200.0)]);
         system.finalize_frame();
 
-        let old_pos = system.mouse_position();
+        let _old_pos = system.mouse_position();
         system.clear();
 
         // last_mouse_position should now equal old position
@@ -583,6 +1179,531 @@ mod tests {
         assert_eq!(system.mouse_delta(), (20.0, 10.0));
     }
 
+    //=====================================================================
+    // Activity Tests
+    //=====================================================================
+
+    /// Runs one full frame over `events`.
+    fn frame(system: &mut StateTracker, events: &[InputEvent]) {
+        system.clear();
+        system.process_events(events);
+        system.finalize_frame();
+    }
+
+    /// Tests held counts follow presses and releases.
+    #[test]
+    fn down_counts_track_held_inputs() {
+        let mut system = StateTracker::new();
+        assert_eq!(system.keys_down_count(), 0);
+        assert_eq!(system.buttons_down_count(), 0);
+
+        frame(&mut system, &[
+            key_down(KeyCode::KeyW),
+            key_down(KeyCode::KeyA),
+            mouse_down(MouseButton::Left),
+        ]);
+        assert_eq!(system.keys_down_count(), 2);
+        assert_eq!(system.buttons_down_count(), 1);
+
+        frame(&mut system, &[key_up(KeyCode::KeyW), mouse_up(MouseButton::Left)]);
+        assert_eq!(system.keys_down_count(), 1);
+        assert_eq!(system.buttons_down_count(), 0);
+    }
+
+    /// Tests idle flag flips with held keys.
+    #[test]
+    fn idle_flips_with_key_presses() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[]);
+        assert!(system.is_idle());
+
+        frame(&mut system, &[key_down(KeyCode::Space)]);
+        assert!(!system.is_idle());
+
+        // Still held
+        frame(&mut system, &[]);
+        assert!(!system.is_idle());
+
+        frame(&mut system, &[key_up(KeyCode::Space)]);
+        assert!(system.is_idle());
+    }
+
+    /// Tests held mouse buttons break idle.
+    #[test]
+    fn idle_broken_by_held_button() {
+        let mut system = StateTracker::new();
+
+        frame(&mut system, &[mouse_down(MouseButton::Right)]);
+        assert!(!system.is_idle());
+
+        frame(&mut system, &[mouse_up(MouseButton::Right)]);
+        assert!(system.is_idle());
+    }
+
+    /// Tests mouse movement alone breaks idle for that frame only.
+    #[test]
+    fn idle_broken_by_mouse_movement_alone() {
+        let mut system = StateTracker::new();
+
+        frame(&mut system, &[mouse_move(10.0, 5.0)]);
+        assert!(!system.is_idle());
+
+        frame(&mut system, &[]);
+        assert!(system.is_idle());
+    }
+
+    /// Tests a same-frame tap still counts as activity.
+    #[test]
+    fn idle_broken_by_same_frame_tap() {
+        let mut system = StateTracker::new();
+
+        frame(&mut system, &[key_down(KeyCode::KeyE), key_up(KeyCode::KeyE)]);
+        assert_eq!(system.keys_down_count(), 0);
+        assert!(!system.is_idle());
+    }
+
+    //=====================================================================
+    // Logical Characters
+    //=====================================================================
+
+    /// Tests the latest logical char per physical key is remembered.
+    #[test]
+    fn logical_char_tracks_latest_layout_char() {
+        let mut system = StateTracker::new();
+        assert_eq!(system.logical_char(KeyCode::KeyQ), None);
+
+        frame(&mut system, &[
+            InputEvent::KeyChar { key: KeyCode::KeyQ, ch: 'a' },
+            key_down(KeyCode::KeyQ),
+        ]);
+        assert_eq!(system.logical_char(KeyCode::KeyQ), Some('a'));
+        assert!(system.is_key_pressed(KeyCode::KeyQ));
+
+        // Persists after release; updated when the layout changes
+        frame(&mut system, &[key_up(KeyCode::KeyQ)]);
+        assert_eq!(system.logical_char(KeyCode::KeyQ), Some('a'));
+
+        frame(&mut system, &[InputEvent::KeyChar { key: KeyCode::KeyQ, ch: 'q' }]);
+        assert_eq!(system.logical_char(KeyCode::KeyQ), Some('q'));
+    }
+
+    //=====================================================================
+    // Cursor Window Presence
+    //=====================================================================
+
+    /// Tests enter/leave events drive mouse_in_window.
+    #[test]
+    fn mouse_in_window_follows_enter_and_leave() {
+        let mut system = StateTracker::new();
+        assert!(!system.mouse_in_window());
+
+        frame(&mut system, &[InputEvent::CursorEntered]);
+        assert!(system.mouse_in_window());
+
+        // Persists across frames without further events
+        frame(&mut system, &[]);
+        assert!(system.mouse_in_window());
+
+        frame(&mut system, &[InputEvent::CursorLeft]);
+        assert!(!system.mouse_in_window());
+    }
+
+    /// Tests positions outside the window are kept raw by the tracker.
+    #[test]
+    fn position_kept_after_cursor_leaves() {
+        let mut system = StateTracker::new();
+
+        frame(&mut system, &[
+            InputEvent::CursorEntered,
+            mouse_move(-20.0, 15.0),
+            InputEvent::CursorLeft,
+        ]);
+
+        assert!(!system.mouse_in_window());
+        assert_eq!(system.mouse_position(), (-20.0, 15.0));
+    }
+
+    //=====================================================================
+    // Observer
+    //=====================================================================
+
+    /// Tests the observer sees raw events, including suppressed ones.
+    #[test]
+    fn observer_sees_every_event_in_order() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let mut system = StateTracker::new();
+        system.set_observer(Box::new(move |event| sink.lock().unwrap().push(event.clone())));
+        system.set_debounce_ticks(5);
+
+        let events = [key_down(KeyCode::KeyA), key_up(KeyCode::KeyA), key_down(KeyCode::KeyA)];
+        frame(&mut system, &events);
+
+        assert_eq!(*seen.lock().unwrap(), events.to_vec());
+        assert!(!system.is_key_down(KeyCode::KeyA));
+    }
+
+    /// Tests a coalesced tap reaches the observer once, as a tap.
+    #[test]
+    fn observer_sees_tap_once() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let mut system = StateTracker::new();
+        system.set_observer(Box::new(move |event| sink.lock().unwrap().push(event.clone())));
+
+        let taps = [InputEvent::KeyTapped { key: KeyCode::KeyA, modifiers: Modifiers::NONE }];
+        frame(&mut system, &taps);
+
+        assert_eq!(*seen.lock().unwrap(), taps.to_vec());
+        assert!(system.is_key_pressed(KeyCode::KeyA));
+    }
+
+    //=====================================================================
+    // Relative Mouse
+    //=====================================================================
+
+    /// Tests recentering between frames adds no delta of its own.
+    #[test]
+    fn cursor_warp_produces_no_delta() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[InputEvent::CursorWarped { x: 400.0, y: 300.0 }]);
+        assert_eq!(system.mouse_delta(), (0.0, 0.0));
+
+        frame(&mut system, &[mouse_move(430.0, 290.0)]);
+        assert_eq!(system.mouse_delta(), (30.0, -10.0));
+
+        // Platform recenters; the jump back is not reported as motion
+        frame(&mut system, &[InputEvent::CursorWarped { x: 400.0, y: 300.0 }]);
+        assert_eq!(system.mouse_delta(), (0.0, 0.0));
+
+        frame(&mut system, &[
+            InputEvent::CursorWarped { x: 400.0, y: 300.0 },
+            mouse_move(395.0, 300.0),
+        ]);
+        assert_eq!(system.mouse_delta(), (-5.0, 0.0));
+        assert_eq!(system.mouse_position(), (395.0, 300.0));
+    }
+
+    /// Tests motion on both sides of a mid-frame warp is summed.
+    #[test]
+    fn cursor_warp_mid_frame_keeps_motion() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[InputEvent::CursorWarped { x: 100.0, y: 100.0 }]);
+
+        frame(&mut system, &[
+            mouse_move(120.0, 100.0),
+            InputEvent::CursorWarped { x: 100.0, y: 100.0 },
+            mouse_move(110.0, 95.0),
+        ]);
+
+        assert_eq!(system.mouse_delta(), (30.0, -5.0));
+    }
+
+    /// Tests a resize between frames does not turn the next cursor position
+    /// into a spurious delta.
+    #[test]
+    fn resize_resyncs_mouse_position_without_delta() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[mouse_move(100.0, 100.0)]);
+
+        frame(&mut system, &[
+            InputEvent::WindowResized { width: 1920.0, height: 1080.0 },
+            mouse_move(700.0, 420.0),
+        ]);
+        assert_eq!(system.mouse_delta(), (0.0, 0.0));
+        assert_eq!(system.mouse_position(), (700.0, 420.0));
+
+        frame(&mut system, &[mouse_move(710.0, 420.0)]);
+        assert_eq!(system.mouse_delta(), (10.0, 0.0));
+    }
+
+    /// Tests motion batched ahead of a resize in the same tick is kept.
+    #[test]
+    fn resize_keeps_earlier_motion_in_the_same_tick() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[mouse_move(100.0, 100.0)]);
+
+        system.clear();
+        system.process_events(&[mouse_move(200.0, 100.0)]);
+        system.process_events(&[
+            InputEvent::WindowResized { width: 1920.0, height: 1080.0 },
+            mouse_move(300.0, 100.0),
+        ]);
+        system.finalize_frame();
+
+        assert_eq!(system.mouse_delta(), (100.0, 0.0));
+        assert_eq!(system.mouse_position(), (300.0, 100.0));
+    }
+
+    //=====================================================================
+    // Scroll & Raw Motion
+    //=====================================================================
+
+    /// Tests scroll and raw motion sum across batches and reset each frame.
+    #[test]
+    fn scroll_and_raw_motion_accumulate_per_frame() {
+        let mut system = StateTracker::new();
+        system.clear();
+        system.process_events(&[
+            InputEvent::MouseScrolled { dx: 0.0, dy: 1.0 },
+            InputEvent::MouseMotion { dx: 4.0, dy: -2.0 },
+        ]);
+        system.process_events(&[
+            InputEvent::MouseScrolled { dx: 0.5, dy: 2.0 },
+            InputEvent::MouseMotion { dx: 1.0, dy: 1.0 },
+        ]);
+        system.finalize_frame();
+
+        assert_eq!(system.scroll_delta(), (0.5, 3.0));
+        assert_eq!(system.raw_mouse_delta(), (5.0, -1.0));
+        assert_eq!(system.mouse_delta(), (0.0, 0.0));
+
+        frame(&mut system, &[]);
+        assert_eq!(system.scroll_delta(), (0.0, 0.0));
+        assert_eq!(system.raw_mouse_delta(), (0.0, 0.0));
+    }
+
+    /// Tests moves interleaved with scroll keep the last position and all scroll.
+    #[test]
+    fn interleaved_moves_and_scroll_keep_position_and_total() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[
+            mouse_move(10.0, 10.0),
+            InputEvent::MouseScrolled { dx: 0.0, dy: 1.0 },
+            mouse_move(15.0, 12.0),
+            InputEvent::MouseScrolled { dx: 0.0, dy: 2.0 },
+        ]);
+
+        assert_eq!(system.mouse_position(), (15.0, 12.0));
+        assert_eq!(system.mouse_delta(), (15.0, 12.0));
+        assert_eq!(system.scroll_delta(), (0.0, 3.0));
+    }
+
+    //=====================================================================
+    // Debouncing
+    //=====================================================================
+
+    /// Tests a re-press within the window is dropped, one outside registers.
+    #[test]
+    fn debounce_suppresses_quick_repress() {
+        let mut system = StateTracker::new();
+        system.set_debounce_ticks(2);
+
+        frame(&mut system, &[key_down(KeyCode::KeyA)]);
+        frame(&mut system, &[key_up(KeyCode::KeyA)]);
+        frame(&mut system, &[key_down(KeyCode::KeyA)]);
+        assert!(!system.is_key_pressed(KeyCode::KeyA));
+        assert!(!system.is_key_down(KeyCode::KeyA));
+
+        frame(&mut system, &[]);
+        frame(&mut system, &[key_down(KeyCode::KeyA)]);
+        assert!(system.is_key_pressed(KeyCode::KeyA));
+    }
+
+    /// Tests bounces within a single frame and on mouse buttons.
+    #[test]
+    fn debounce_applies_within_frame_and_to_buttons() {
+        let mut system = StateTracker::new();
+        system.set_debounce_ticks(1);
+        frame(&mut system, &[mouse_down(MouseButton::Left)]);
+
+        frame(&mut system, &[mouse_up(MouseButton::Left), mouse_down(MouseButton::Left)]);
+        assert!(system.is_button_released(MouseButton::Left));
+        assert!(!system.is_button_down(MouseButton::Left));
+
+        frame(&mut system, &[]);
+        frame(&mut system, &[mouse_down(MouseButton::Left)]);
+        assert!(system.is_button_pressed(MouseButton::Left));
+    }
+
+    /// Tests debouncing is off by default.
+    #[test]
+    fn debounce_disabled_by_default() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[key_down(KeyCode::KeyA), key_up(KeyCode::KeyA)]);
+        frame(&mut system, &[key_down(KeyCode::KeyA)]);
+
+        assert!(system.is_key_pressed(KeyCode::KeyA));
+    }
+
+    //=====================================================================
+    // Double Tap
+    //=====================================================================
+
+    fn tap_frames(system: &mut StateTracker, key: KeyCode, gaps: &[usize]) -> Vec<bool> {
+        let mut fired = Vec::new();
+        for &gap in gaps {
+            for _ in 0..gap {
+                frame(system, &[]);
+            }
+            system.simulate_tap(key);
+            fired.push(system.is_key_double_tapped(key));
+        }
+        fired
+    }
+
+    /// Tests two presses inside the window fire on the second press only.
+    #[test]
+    fn fast_double_tap_fires() {
+        let mut system = StateTracker::new();
+        system.set_double_tap_ticks(5);
+
+        assert_eq!(tap_frames(&mut system, KeyCode::KeyD, &[0, 4]), vec![false, true]);
+
+        frame(&mut system, &[]);
+        assert!(!system.is_key_double_tapped(KeyCode::KeyD));
+    }
+
+    /// Tests presses spaced beyond the window never fire.
+    #[test]
+    fn slow_double_tap_does_not_fire() {
+        let mut system = StateTracker::new();
+        system.set_double_tap_ticks(5);
+
+        assert_eq!(
+            tap_frames(&mut system, KeyCode::KeyD, &[0, 5, 5]),
+            vec![false, false, false]
+        );
+    }
+
+    /// Tests a triple tap fires once, then two fresh presses are needed.
+    #[test]
+    fn triple_tap_fires_once_then_rearms() {
+        let mut system = StateTracker::new();
+        system.set_double_tap_ticks(5);
+
+        assert_eq!(
+            tap_frames(&mut system, KeyCode::KeyA, &[0, 0, 0, 0]),
+            vec![false, true, false, true]
+        );
+        assert!(!system.is_key_double_tapped(KeyCode::KeyD));
+    }
+
+    //=====================================================================
+    // Simulation Helpers
+    //=====================================================================
+
+    /// Tests a simulated hold presses once and keeps the key down.
+    #[test]
+    fn simulate_hold_presses_only_on_first_frame() {
+        let mut system = StateTracker::new();
+
+        system.simulate_hold(KeyCode::KeyC, 1);
+        assert!(system.is_key_pressed(KeyCode::KeyC));
+
+        system.simulate_hold(KeyCode::KeyC, 29);
+        assert!(system.is_key_down(KeyCode::KeyC));
+        assert!(!system.is_key_pressed(KeyCode::KeyC));
+        assert!(system.is_key_pressed_buffered(KeyCode::KeyC, 29));
+        assert!(!system.is_key_pressed_buffered(KeyCode::KeyC, 28));
+
+        assert!(system.simulate_release(KeyCode::KeyC).is_key_released(KeyCode::KeyC));
+        assert!(!system.is_key_down(KeyCode::KeyC));
+    }
+
+    /// Tests a simulated tap is pressed and released in one frame.
+    #[test]
+    fn simulate_tap_presses_and_releases() {
+        let mut system = StateTracker::new();
+
+        system.simulate_tap(KeyCode::Space);
+        assert!(system.is_key_pressed(KeyCode::Space));
+        assert!(system.is_key_released(KeyCode::Space));
+        assert!(!system.is_key_down(KeyCode::Space));
+
+        system.simulate_frame(&[]);
+        assert!(!system.is_key_pressed(KeyCode::Space));
+    }
+
+    //=====================================================================
+    // Buffered Presses
+    //=====================================================================
+
+    /// Tests a press stays buffered for the window, then expires.
+    #[test]
+    fn buffered_press_lasts_for_window() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[key_down(KeyCode::Space)]);
+        assert!(system.is_key_pressed_buffered(KeyCode::Space, 0));
+
+        frame(&mut system, &[key_up(KeyCode::Space)]);
+        frame(&mut system, &[]);
+        assert!(!system.is_key_pressed_buffered(KeyCode::Space, 1));
+        assert!(system.is_key_pressed_buffered(KeyCode::Space, 2));
+
+        frame(&mut system, &[]);
+        assert!(!system.is_key_pressed_buffered(KeyCode::Space, 2));
+        assert!(!system.is_key_pressed_buffered(KeyCode::KeyA, 10));
+    }
+
+    /// Tests holding a key does not refresh the buffered press.
+    #[test]
+    fn buffered_press_counts_from_transition() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[key_down(KeyCode::Space)]);
+        frame(&mut system, &[key_down(KeyCode::Space)]);
+        frame(&mut system, &[]);
+
+        assert!(system.is_key_down(KeyCode::Space));
+        assert!(!system.is_key_pressed_buffered(KeyCode::Space, 1));
+
+        // A tapped key is buffered like a press
+        frame(&mut system, &[key_up(KeyCode::Space)]);
+        frame(&mut system, &[InputEvent::KeyTapped {
+            key: KeyCode::Space,
+            modifiers: Modifiers::NONE,
+        }]);
+        frame(&mut system, &[]);
+        assert!(system.is_key_pressed_buffered(KeyCode::Space, 1));
+    }
+
+    //=====================================================================
+    // Sorted Iteration
+    //=====================================================================
+
+    /// Tests sorted queries ignore insertion (and hash) order.
+    #[test]
+    fn sorted_iteration_is_independent_of_press_order() {
+        let keys = [KeyCode::KeyW, KeyCode::Space, KeyCode::KeyA, KeyCode::Digit1];
+        let mut forward = StateTracker::new();
+        let mut reverse = StateTracker::new();
+
+        frame(&mut forward, &keys.map(key_down));
+        let mut reversed = keys;
+        reversed.reverse();
+        frame(&mut reverse, &reversed.map(key_down));
+
+        let mut expected = keys.to_vec();
+        expected.sort();
+        assert_eq!(forward.keys_down_sorted(), expected);
+        assert_eq!(reverse.keys_down_sorted(), expected);
+        assert_eq!(forward.keys_pressed_sorted(), expected);
+    }
+
+    /// Tests sorted release and button queries.
+    #[test]
+    fn sorted_iteration_covers_releases_and_buttons() {
+        let mut system = StateTracker::new();
+        frame(&mut system, &[
+            mouse_down(MouseButton::Right),
+            mouse_down(MouseButton::Left),
+            key_down(KeyCode::KeyS),
+            key_down(KeyCode::KeyD),
+        ]);
+        assert_eq!(system.buttons_down_sorted(), vec![MouseButton::Left, MouseButton::Right]);
+        assert_eq!(system.buttons_pressed_sorted(), vec![MouseButton::Left, MouseButton::Right]);
+
+        frame(&mut system, &[
+            key_up(KeyCode::KeyS),
+            key_up(KeyCode::KeyD),
+            mouse_up(MouseButton::Right),
+            mouse_up(MouseButton::Left),
+        ]);
+        assert_eq!(system.keys_released_sorted(), vec![KeyCode::KeyD, KeyCode::KeyS]);
+        assert_eq!(system.buttons_released_sorted(), vec![MouseButton::Left, MouseButton::Right]);
+        assert!(system.keys_down_sorted().is_empty());
+    }
+
     //=====================================================================
     // Edge Cases
     //=====================================================================
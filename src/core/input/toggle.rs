@@ -0,0 +1,130 @@
+//=========================================================================
+// Toggle State
+//=========================================================================
+//
+// Flip-on-press booleans for toggle controls (crouch, flashlight).
+//
+// Owned by game code (e.g., a scene) and fed one press flag per frame:
+//   state.is_key_pressed(key) / context.action_pressed(action) → update()
+//
+// Keyed by any copyable id, so the same helper serves keys and actions.
+//
+//=========================================================================
+
+//=== External Dependencies ===============================================
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+//=== ToggleState =========================================================
+
+/// Independent on/off toggles that flip each time their input is pressed.
+///
+/// All toggles start off.
+///
+/// ```
+/// use aetheric_engine::core::input::{KeyCode, ToggleState};
+///
+/// let mut toggles = ToggleState::new();
+///
+/// assert!(toggles.update(KeyCode::KeyF, true));   // pressed: on
+/// assert!(toggles.update(KeyCode::KeyF, false));  // held or idle: unchanged
+/// assert!(!toggles.update(KeyCode::KeyF, true));  // pressed again: off
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ToggleState<K> {
+    states: HashMap<K, bool>,
+}
+
+impl<K: Copy + Eq + Hash> ToggleState<K> {
+    /// Creates a set of toggles, all off.
+    pub fn new() -> Self {
+        Self { states: HashMap::new() }
+    }
+
+    /// Flips `id` if `pressed`, then returns its current value.
+    ///
+    /// Call once per frame with the press flag (not the held flag), e.g.
+    /// `toggles.update(KeyCode::KeyC, state.is_key_pressed(KeyCode::KeyC))`.
+    pub fn update(&mut self, id: K, pressed: bool) -> bool {
+        let on = self.states.entry(id).or_insert(false);
+        if pressed {
+            *on = !*on;
+        }
+        *on
+    }
+
+    /// Returns `true` if `id` is currently on.
+    pub fn is_on(&self, id: K) -> bool {
+        self.states.get(&id).copied().unwrap_or(false)
+    }
+
+    /// Forces `id` on or off (e.g., stand up when entering a cutscene).
+    pub fn set(&mut self, id: K, on: bool) {
+        self.states.insert(id, on);
+    }
+
+    /// Turns every toggle off.
+    pub fn reset(&mut self) {
+        self.states.clear();
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::input::event::KeyCode;
+
+    #[test]
+    fn three_presses_yield_on_off_on() {
+        let mut toggles = ToggleState::new();
+
+        let values: Vec<bool> =
+            (0..3).map(|_| toggles.update(KeyCode::KeyC, true)).collect();
+
+        assert_eq!(values, vec![true, false, true]);
+        assert!(toggles.is_on(KeyCode::KeyC));
+    }
+
+    #[test]
+    fn frames_without_press_keep_value() {
+        let mut toggles = ToggleState::new();
+        toggles.update(KeyCode::KeyF, true);
+
+        for _ in 0..5 {
+            assert!(toggles.update(KeyCode::KeyF, false));
+        }
+    }
+
+    #[test]
+    fn toggles_are_independent() {
+        let mut toggles = ToggleState::new();
+        toggles.update(KeyCode::KeyC, true);
+        toggles.update(KeyCode::KeyF, true);
+        toggles.update(KeyCode::KeyF, true);
+
+        assert!(toggles.is_on(KeyCode::KeyC));
+        assert!(!toggles.is_on(KeyCode::KeyF));
+        assert!(!toggles.is_on(KeyCode::KeyX));
+    }
+
+    #[test]
+    fn set_and_reset_override_state() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum Toggle {
+            Crouch,
+        }
+
+        let mut toggles = ToggleState::new();
+        toggles.set(Toggle::Crouch, true);
+        assert!(toggles.is_on(Toggle::Crouch));
+
+        toggles.reset();
+        assert!(!toggles.is_on(Toggle::Crouch));
+        assert!(toggles.update(Toggle::Crouch, true));
+    }
+}
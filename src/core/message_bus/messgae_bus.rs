@@ -13,12 +13,24 @@
 //
 // Pattern: push → read (N consumers) → clear → repeat
 //
+// One-shot types (push_oneshot) are cleared automatically by the engine at
+// the end of each frame, so user events need no manual clear.
+//
+// Latest-value slots (set_latest) hold a single value per type, separate
+// from the queues, for state such as the selected entity.
+//
+// Checkpoints: types registered with register_checkpointable are cloned by
+// checkpoint() and put back by restore(); other types are left untouched.
+//
+// Preallocation: register_type creates a type's queue at setup with reserved
+// capacity, so hot types never allocate on their first push mid-frame.
+//
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
-use std::any::TypeId;
-use std::collections::HashMap;
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
 
 //=== Internal Dependencies ===============================================
 
@@ -34,6 +46,42 @@ pub trait Message: Send + 'static {}
 // Blanket implementation
 impl<T: Send + 'static> Message for T {}
 
+/// Message types that can be captured in a [`Checkpoint`] (must be `Clone`).
+///
+/// Implemented automatically; a type still has to opt in with
+/// [`MessageBus::register_checkpointable`].
+pub trait Checkpointable: Message + Clone {}
+
+impl<T: Message + Clone> Checkpointable for T {}
+
+/// Clones a type-erased queue known to hold `Vec<M>`.
+type CloneQueueFn = fn(&dyn MessageQueue) -> Box<dyn MessageQueue>;
+
+fn clone_queue<M: Checkpointable>(queue: &dyn MessageQueue) -> Box<dyn MessageQueue> {
+    let messages = queue
+        .as_any()
+        .downcast_ref::<Vec<M>>()
+        .expect("Type mismatch in MessageBus queue");
+    Box::new(messages.clone())
+}
+
+//=== Checkpoint ==========================================================
+
+/// Saved copy of the checkpointable queues of a [`MessageBus`].
+///
+/// Created by [`MessageBus::checkpoint`], applied with
+/// [`MessageBus::restore`]; can be restored any number of times.
+pub struct Checkpoint {
+    queues: HashMap<TypeId, Box<dyn MessageQueue>>,
+}
+
+impl Checkpoint {
+    /// Returns the number of saved messages across all types.
+    pub fn total_message_count(&self) -> usize {
+        self.queues.values().map(|queue| queue.len()).sum()
+    }
+}
+
 //=========================================================================
 
 /// Type-safe message queue for batched inter-system communication.
@@ -42,6 +90,9 @@ impl<T: Send + 'static> Message for T {}
 /// messages during updates and process them at tick boundaries.
 pub struct MessageBus {
     queues: HashMap<TypeId, Box<dyn MessageQueue>>,
+    oneshot_types: HashSet<TypeId>,
+    latest: HashMap<TypeId, Box<dyn Any + Send>>,
+    checkpointable: HashMap<TypeId, CloneQueueFn>,
 }
 
 impl MessageBus {
@@ -49,25 +100,42 @@ impl MessageBus {
     pub fn new() -> Self {
         MessageBus {
             queues: HashMap::new(),
+            oneshot_types: HashSet::new(),
+            latest: HashMap::new(),
+            checkpointable: HashMap::new(),
         }
     }
 
+    //--- Setup ------------------------------------------------------------
+
+    /// Creates the queue for message type M with room for `capacity` messages.
+    ///
+    /// Call at setup for types pushed every frame: pushes up to `capacity`
+    /// per frame then never allocate, since clearing keeps the capacity. An
+    /// existing queue is grown to fit `capacity` messages, never shrunk.
+    pub fn register_type<M: Message>(&mut self, capacity: usize) {
+        let queue = self.queue_mut::<M>();
+        queue.reserve(capacity.saturating_sub(queue.len()));
+    }
+
     //--- Message Operations -----------------------------------------------
 
     /// Pushes a message into the queue for its type.
     pub fn push<M: Message>(&mut self, msg: M) {
-        let type_id = TypeId::of::<M>();
-
-        let boxed_queue: &mut Box<dyn MessageQueue> = self.queues
-            .entry(type_id)
-            .or_insert_with(|| Box::new(Vec::<M>::new()));
-
-        let queue: &mut Vec<M> = boxed_queue
-            .as_any_mut()
-            .downcast_mut::<Vec<M>>()
-            .expect("Type mismatch in MessageBus queue");
+        self.queue_mut::<M>().push(msg);
+    }
 
-        queue.push(msg);
+    /// Pushes a message and registers its type as one-shot.
+    ///
+    /// One-shot types are cleared by [`clear_oneshot`](Self::clear_oneshot),
+    /// which the engine calls at the end of each frame (after scene updates
+    /// and transitions). Messages are visible for the rest of the frame they
+    /// are pushed in; messages pushed from the tick callback are visible to
+    /// scenes in the following frame. Registration applies to the whole type,
+    /// including messages added later with [`push`](Self::push).
+    pub fn push_oneshot<M: Message>(&mut self, msg: M) {
+        self.oneshot_types.insert(TypeId::of::<M>());
+        self.push(msg);
     }
 
     /// Returns a slice of all messages of type M currently queued.
@@ -83,6 +151,66 @@ impl MessageBus {
             .unwrap_or(&[])
     }
 
+    //--- Latest Values ----------------------------------------------------
+
+    /// Stores `msg` as the single latest value of its type.
+    ///
+    /// Replaces any previous value. Latest values live in their own slot,
+    /// independent of the queue used by [`push`](Self::push)/[`read`](Self::read),
+    /// and are never cleared by the engine.
+    pub fn set_latest<M: Message>(&mut self, msg: M) {
+        self.latest.insert(TypeId::of::<M>(), Box::new(msg));
+    }
+
+    /// Returns the most recent value stored with [`set_latest`](Self::set_latest).
+    pub fn latest<M: Message>(&self) -> Option<&M> {
+        self.latest
+            .get(&TypeId::of::<M>())
+            .and_then(|value| value.downcast_ref::<M>())
+    }
+
+    //--- Checkpoints ------------------------------------------------------
+
+    /// Opts message type M into [`checkpoint`](Self::checkpoint)/[`restore`](Self::restore).
+    pub fn register_checkpointable<M: Checkpointable>(&mut self) {
+        self.checkpointable.insert(TypeId::of::<M>(), clone_queue::<M>);
+    }
+
+    /// Clones the queues of all registered checkpointable types.
+    ///
+    /// Unregistered types, latest-value slots and one-shot registrations are
+    /// not captured; rollback code must rebuild those itself.
+    pub fn checkpoint(&self) -> Checkpoint {
+        let queues = self
+            .checkpointable
+            .iter()
+            .filter_map(|(type_id, clone)| {
+                self.queues.get(type_id).map(|queue| (*type_id, clone(queue.as_ref())))
+            })
+            .collect();
+
+        Checkpoint { queues }
+    }
+
+    /// Resets every registered checkpointable queue to its state in `checkpoint`.
+    ///
+    /// Registered types with no messages at checkpoint time are cleared.
+    /// Other types keep their current messages.
+    pub fn restore(&mut self, checkpoint: &Checkpoint) {
+        for (type_id, clone) in &self.checkpointable {
+            match checkpoint.queues.get(type_id) {
+                Some(saved) => {
+                    self.queues.insert(*type_id, clone(saved.as_ref()));
+                }
+                None => {
+                    if let Some(queue) = self.queues.get_mut(type_id) {
+                        queue.clear_queue();
+                    }
+                }
+            }
+        }
+    }
+
     //--- Query API --------------------------------------------------------
 
     /// Returns true if there are any messages of type M queued.
@@ -103,6 +231,25 @@ impl MessageBus {
             .unwrap_or(0)
     }
 
+    /// Returns each message type with queued messages and its count.
+    ///
+    /// Intended for debugging and inspection (e.g., a debug overlay).
+    /// Types whose queues are empty (including cleared ones) are skipped.
+    /// Iteration order is unspecified.
+    pub fn active_types(&self) -> impl Iterator<Item = (TypeId, usize)> + '_ {
+        self.queues
+            .iter()
+            .filter(|(_, queue)| !queue.is_empty())
+            .map(|(&type_id, queue)| (type_id, queue.len()))
+    }
+
+    /// Returns the total number of queued messages across all types.
+    pub fn total_message_count(&self) -> usize {
+        self.queues.values().map(|queue| queue.len()).sum()
+    }
+
+    //--- Clearing ---------------------------------------------------------
+
     /// Clears all messages of type M, preserving allocated capacity.
     ///
     /// Does not deallocate the underlying Vec, allowing efficient reuse
@@ -115,6 +262,17 @@ impl MessageBus {
         }
     }
 
+    /// Clears all queues for one-shot message types, preserving capacity.
+    ///
+    /// Called by the engine at the end of each frame; types stay registered.
+    pub fn clear_oneshot(&mut self) {
+        for type_id in &self.oneshot_types {
+            if let Some(queue) = self.queues.get_mut(type_id) {
+                queue.clear_queue();
+            }
+        }
+    }
+
     /// Clears all queues for all message types, preserving capacity.
     ///
     /// Iterates through all queues and calls clear() on each, preserving
@@ -124,6 +282,26 @@ impl MessageBus {
             queue.clear_queue();
         }
     }
+
+    //--- Internal Helpers -------------------------------------------------
+
+    /// Returns the queue for M, creating an empty one on first use.
+    fn queue_mut<M: Message>(&mut self) -> &mut Vec<M> {
+        self.queues
+            .entry(TypeId::of::<M>())
+            .or_insert_with(|| Box::new(Vec::<M>::new()))
+            .as_any_mut()
+            .downcast_mut::<Vec<M>>()
+            .expect("Type mismatch in MessageBus queue")
+    }
+}
+
+//--- Trait Implementations -----------------------------------------------
+
+impl Default for MessageBus {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 //=========================================================================
@@ -332,4 +510,170 @@ mod tests {
         assert_eq!(msgs.len(), 1);
         assert_eq!(msgs[0].value, 99);
     }
+
+    #[test]
+    fn active_types_lists_non_empty_queues_with_counts() {
+        let mut bus = MessageBus::new();
+        bus.push(TestMessage { value: 1 });
+        bus.push(TestMessage { value: 2 });
+        bus.push(OtherMessage { text: "a".to_string() });
+
+        let mut types: Vec<_> = bus.active_types().collect();
+        types.sort_by_key(|&(_, count)| count);
+
+        assert_eq!(
+            types,
+            vec![(TypeId::of::<OtherMessage>(), 1), (TypeId::of::<TestMessage>(), 2)]
+        );
+        assert_eq!(bus.total_message_count(), 3);
+    }
+
+    #[test]
+    fn cleared_types_drop_out_of_active_types() {
+        let mut bus = MessageBus::new();
+        bus.push(TestMessage { value: 1 });
+        bus.push(OtherMessage { text: "a".to_string() });
+
+        bus.clear::<TestMessage>();
+
+        let types: Vec<_> = bus.active_types().collect();
+        assert_eq!(types, vec![(TypeId::of::<OtherMessage>(), 1)]);
+        assert_eq!(bus.total_message_count(), 1);
+
+        bus.clear_all();
+        assert_eq!(bus.active_types().count(), 0);
+        assert_eq!(bus.total_message_count(), 0);
+    }
+
+    #[test]
+    fn oneshot_messages_are_cleared_by_sweep() {
+        let mut bus = MessageBus::new();
+        bus.push_oneshot(TestMessage { value: 1 });
+        bus.push(OtherMessage { text: "kept".to_string() });
+
+        assert_eq!(bus.count::<TestMessage>(), 1);
+
+        bus.clear_oneshot();
+
+        assert_eq!(bus.count::<TestMessage>(), 0);
+        assert_eq!(bus.count::<OtherMessage>(), 1);
+    }
+
+    #[test]
+    fn oneshot_registration_persists_across_sweeps() {
+        let mut bus = MessageBus::new();
+        bus.push_oneshot(TestMessage { value: 1 });
+        bus.clear_oneshot();
+
+        bus.push(TestMessage { value: 2 });
+        bus.clear_oneshot();
+
+        assert!(!bus.has_messages::<TestMessage>());
+    }
+
+    #[test]
+    fn set_latest_keeps_only_newest_value() {
+        let mut bus = MessageBus::new();
+        assert_eq!(bus.latest::<TestMessage>(), None);
+
+        bus.set_latest(TestMessage { value: 1 });
+        bus.set_latest(TestMessage { value: 2 });
+
+        assert_eq!(bus.latest::<TestMessage>(), Some(&TestMessage { value: 2 }));
+        assert_eq!(bus.latest::<OtherMessage>(), None);
+    }
+
+    #[test]
+    fn latest_values_are_separate_from_queues() {
+        let mut bus = MessageBus::new();
+        bus.set_latest(TestMessage { value: 7 });
+        bus.push(TestMessage { value: 1 });
+
+        bus.clear_all();
+
+        assert!(!bus.has_messages::<TestMessage>());
+        assert_eq!(bus.latest::<TestMessage>(), Some(&TestMessage { value: 7 }));
+    }
+
+    #[test]
+    fn restore_reproduces_checkpointed_counts() {
+        let mut bus = MessageBus::new();
+        bus.register_checkpointable::<TestMessage>();
+        bus.push(TestMessage { value: 1 });
+        bus.push(TestMessage { value: 2 });
+
+        let checkpoint = bus.checkpoint();
+        assert_eq!(checkpoint.total_message_count(), 2);
+
+        bus.push(TestMessage { value: 3 });
+        bus.clear::<TestMessage>();
+        bus.push(TestMessage { value: 4 });
+
+        bus.restore(&checkpoint);
+        assert_eq!(bus.read::<TestMessage>(), &[TestMessage { value: 1 }, TestMessage { value: 2 }]);
+
+        // A checkpoint can be restored repeatedly
+        bus.clear_all();
+        bus.restore(&checkpoint);
+        assert_eq!(bus.count::<TestMessage>(), 2);
+    }
+
+    #[test]
+    fn unregistered_types_are_skipped_by_checkpoints() {
+        let mut bus = MessageBus::new();
+        bus.register_checkpointable::<TestMessage>();
+        bus.push(OtherMessage { text: "kept".to_string() });
+
+        let checkpoint = bus.checkpoint();
+        assert_eq!(checkpoint.total_message_count(), 0);
+
+        bus.push(TestMessage { value: 9 });
+        bus.push(OtherMessage { text: "new".to_string() });
+        bus.restore(&checkpoint);
+
+        // Registered type was empty at checkpoint; other type is untouched
+        assert_eq!(bus.count::<TestMessage>(), 0);
+        assert_eq!(bus.count::<OtherMessage>(), 2);
+    }
+
+    //--- Preallocation ----------------------------------------------------
+
+    fn queue_capacity<M: Message>(bus: &MessageBus) -> usize {
+        bus.queues
+            .get(&TypeId::of::<M>())
+            .and_then(|queue| queue.as_any().downcast_ref::<Vec<M>>())
+            .map_or(0, Vec::capacity)
+    }
+
+    #[test]
+    fn registered_type_pushes_without_reallocating() {
+        let mut bus = MessageBus::new();
+        bus.register_type::<TestMessage>(64);
+
+        let reserved = queue_capacity::<TestMessage>(&bus);
+        assert!(reserved >= 64);
+        assert!(!bus.has_messages::<TestMessage>());
+
+        for value in 0..64 {
+            bus.push(TestMessage { value });
+        }
+        assert_eq!(queue_capacity::<TestMessage>(&bus), reserved);
+
+        bus.clear::<TestMessage>();
+        bus.push(TestMessage { value: 0 });
+        assert_eq!(queue_capacity::<TestMessage>(&bus), reserved);
+    }
+
+    #[test]
+    fn registering_again_keeps_queued_messages() {
+        let mut bus = MessageBus::new();
+        bus.push(TestMessage { value: 1 });
+        bus.register_type::<TestMessage>(16);
+
+        assert_eq!(bus.read::<TestMessage>(), &[TestMessage { value: 1 }]);
+        assert!(queue_capacity::<TestMessage>(&bus) >= 16);
+
+        bus.register_type::<TestMessage>(0);
+        assert!(queue_capacity::<TestMessage>(&bus) >= 16);
+    }
 }
@@ -5,4 +5,4 @@
 mod messgae_bus;
 mod message_queue;
 
-pub use messgae_bus::{Message, MessageBus};
+pub use messgae_bus::{Checkpoint, Checkpointable, Message, MessageBus};
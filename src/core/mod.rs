@@ -9,16 +9,19 @@
 //
 // Thread Model:
 //   Platform Thread ──(MPSC)──► Core Thread ──► Systems
+//   Platform Thread ◄─(MPSC)─── Core Thread (platform commands)
+//   Any Thread ──(EngineCommand)──► Core Thread (drained each tick)
 //
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
+use std::panic::{self, AssertUnwindSafe};
 use std::thread;
 use std::time::{Duration, Instant};
 
-use crossbeam_channel::Receiver;
-use log::{info, warn};
+use crossbeam_channel::{Receiver, Sender};
+use log::{error, info, warn};
 
 //=== Module Declarations =================================================
 
@@ -34,10 +37,82 @@ pub(crate) mod platform_bridge;
 pub use input::{Action, InputSystem};
 pub use globals::{GlobalContext, GlobalSystems};
 pub use scene::{SceneKey, SceneManager};
+pub use platform_bridge::interface::WindowId;
+pub use platform_bridge::event_collector::CoreExitReason;
 
 //=== Internal Dependencies ===============================================
 
-use platform_bridge::{EventCollector, PlatformEvent, TickControl};
+use input::InputObserver;
+use platform_bridge::{
+    EventCollector, Heartbeat, PlatformCommand, PlatformEvent, TickControl,
+};
+
+//=== Tick Callback =======================================================
+
+/// Per-tick closure invoked after all systems have updated.
+pub(crate) type TickFn = Box<dyn FnMut(&mut GlobalContext) + Send>;
+
+/// Closure invoked with `(elapsed, target)` when a tick overruns its budget.
+pub(crate) type OverrunFn = Box<dyn FnMut(Duration, Duration) + Send>;
+
+/// Closure receiving engine lifecycle events on the logic thread.
+pub(crate) type LifecycleFn = Box<dyn FnMut(EngineLifecycleEvent) + Send>;
+
+//=== EngineLifecycleEvent ================================================
+
+/// Typed engine event delivered to a lifecycle observer.
+///
+/// Complements the `log` output for embedders that want to react to engine
+/// state programmatically. Installed with
+/// [`EngineBuilder::with_lifecycle_observer`](crate::EngineBuilder::with_lifecycle_observer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineLifecycleEvent {
+    /// The logic thread entered its loop; initial scenes have been entered.
+    CoreStarted,
+
+    /// The platform opened a window (seen by the core at the next tick).
+    WindowCreated(WindowId),
+
+    /// A tick ran over its time budget.
+    Overrun {
+        /// Time the tick took.
+        elapsed: Duration,
+
+        /// Tick budget (`1 / TPS`).
+        target: Duration,
+    },
+
+    /// The logic thread stopped cleanly. Not sent if it panicked.
+    CoreExited(CoreExitReason),
+}
+
+//=== EngineCommand =======================================================
+
+/// Runtime control message sent into the logic thread from other threads.
+///
+/// Get a sender with [`Engine::command_sender`](crate::Engine::command_sender)
+/// before running. Commands are drained at the start of each tick, in order.
+/// More controls may be added, so matches need a wildcard arm.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum EngineCommand {
+    /// Stops updating systems, scenes and the tick callback.
+    ///
+    /// Platform input arriving while paused still updates the input state
+    /// (so keys released meanwhile are up on resume) but produces no
+    /// actions; platform commands, TPS changes and exit requests are still
+    /// handled.
+    Pause,
+
+    /// Resumes updates after [`Pause`](Self::Pause).
+    Resume,
+
+    /// Changes the tick rate, like [`GlobalContext::set_tps`].
+    SetTps(f64),
+
+    /// Ends the core loop after this tick, like [`GlobalContext::request_exit`].
+    Exit,
+}
 
 //=== CoreSystemsOrchestrator =============================================
 
@@ -48,6 +123,15 @@ use platform_bridge::{EventCollector, PlatformEvent, TickControl};
 pub(crate) struct CoreSystemsOrchestrator<S: SceneKey, A: Action> {
     context: GlobalContext,
     systems: GlobalSystems<S, A>,
+    tick_fn: Option<TickFn>,
+    overrun_fn: Option<OverrunFn>,
+    lifecycle_fn: Option<LifecycleFn>,
+    engine_commands: Option<Receiver<EngineCommand>>,
+    paused: bool,
+    heartbeat: Option<Heartbeat>,
+    precise_timing: bool,
+    redraw_enabled: bool,
+    frame_duration: Duration,
 }
 
 impl<S: SceneKey, A: Action> CoreSystemsOrchestrator<S, A> {
@@ -57,6 +141,15 @@ impl<S: SceneKey, A: Action> CoreSystemsOrchestrator<S, A> {
         Self {
             context: GlobalContext::new(),
             systems: GlobalSystems::new(),
+            tick_fn: None,
+            overrun_fn: None,
+            lifecycle_fn: None,
+            engine_commands: None,
+            paused: false,
+            heartbeat: None,
+            precise_timing: false,
+            redraw_enabled: true,
+            frame_duration: Duration::from_secs_f64(1.0 / 60.0),
         }
     }
 
@@ -73,71 +166,323 @@ impl<S: SceneKey, A: Action> CoreSystemsOrchestrator<S, A> {
         init_fn(&mut self.systems);
     }
 
+    /// Installs a closure that runs once per tick after systems update.
+    pub(crate) fn set_tick_fn(&mut self, tick_fn: TickFn) {
+        self.tick_fn = Some(tick_fn);
+    }
+
+    /// Installs a closure called instead of the slow-tick warning.
+    pub(crate) fn set_overrun_handler(&mut self, overrun_fn: OverrunFn) {
+        self.overrun_fn = Some(overrun_fn);
+    }
+
+    /// Installs a closure receiving lifecycle events.
+    pub(crate) fn set_lifecycle_observer(&mut self, lifecycle_fn: LifecycleFn) {
+        self.lifecycle_fn = Some(lifecycle_fn);
+    }
+
+    /// Installs the receiving end of the runtime command channel.
+    pub(crate) fn set_command_receiver(&mut self, receiver: Receiver<EngineCommand>) {
+        self.engine_commands = Some(receiver);
+    }
+
+    /// Installs a counter bumped once per tick, watched by the platform.
+    pub(crate) fn set_heartbeat(&mut self, heartbeat: Heartbeat) {
+        self.heartbeat = Some(heartbeat);
+    }
+
+    /// Installs a callback invoked for every raw input event processed.
+    pub(crate) fn set_input_observer(&mut self, observer: InputObserver) {
+        self.context.input_state.set_observer(observer);
+    }
+
+    /// Seeds the shared random number generator.
+    pub(crate) fn set_seed(&mut self, seed: u64) {
+        self.context.rng.reseed(seed);
+    }
+
+    /// Enables spin-then-sleep frame pacing for sub-millisecond tick timing.
+    pub(crate) fn set_precise_timing(&mut self, enabled: bool) {
+        self.precise_timing = enabled;
+    }
+
     //--- Thread Lifecycle -------------------------------------------------
 
     /// Spawns the main logic thread running at fixed TPS.
     ///
-    /// Thread exits on `WindowClosed` event or channel disconnect.
+    /// Thread exits on `WindowClosed` event, channel disconnect, or an exit
+    /// request from game code; the join result reports which.
+    /// Platform commands queued on the context are forwarded via `commands`.
+    ///
+    /// If the loop panics, `PlatformCommand::Shutdown` is sent so the platform
+    /// tears down immediately; the panic then resumes and surfaces at join.
     ///
     /// # Panics
     /// Panics if `tps <= 0.0`.
     pub(crate) fn spawn_core_thread(
         mut self,
         receiver: Receiver<PlatformEvent>,
+        commands: Sender<PlatformCommand>,
         tps: f64
-    ) -> thread::JoinHandle<()> {
+    ) -> thread::JoinHandle<CoreExitReason> {
         assert!(tps > 0.0, "TPS must be positive, got {}", tps);
 
-        let frame_duration = Duration::from_secs_f64(1.0 / tps);
+        self.set_tps(tps);
 
         thread::spawn(move || {
-            self.run_loop(receiver, frame_duration);
+            let shutdown = commands.clone();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                self.run_loop(receiver, commands)
+            }));
+
+            match result {
+                Ok(reason) => reason,
+                Err(payload) => {
+                    error!("Core thread panicked: {}", panic_message(&*payload));
+                    let _ = shutdown.send(PlatformCommand::Shutdown);
+                    panic::resume_unwind(payload)
+                }
+            }
         })
     }
 
-    fn run_loop(&mut self, receiver: Receiver<PlatformEvent>, frame_duration: Duration) {
+    fn run_loop(
+        &mut self,
+        receiver: Receiver<PlatformEvent>,
+        commands: Sender<PlatformCommand>,
+    ) -> CoreExitReason {
         let mut event_collector = EventCollector::new(receiver);
 
-        // Initialize scene manager by calling on_enter for initial scenes
-        self.systems.scene_manager.start(&self.context);
+        self.start();
+        self.emit(EngineLifecycleEvent::CoreStarted);
 
         loop {
             let frame_start = Instant::now();
 
-            // Collect events from platform thread
-            if event_collector.collect_frame() == TickControl::Exit {
-                info!("Core thread exiting cleanly.");
-                break;
+            if let TickControl::Exit(reason) = self.tick(&mut event_collector, &commands) {
+                info!("Core thread exiting cleanly ({:?}).", reason);
+                self.emit(EngineLifecycleEvent::CoreExited(reason));
+                return reason;
             }
 
-            // Transfer events to context
-            self.context.frame_input_events = event_collector.take_batches();
+            // Frame pacing (reads the duration each tick so TPS can change)
+            self.pace_frame(frame_start);
+        }
+    }
+
+    /// Initializes the scene manager by calling `on_enter` for initial scenes.
+    pub(crate) fn start(&mut self) {
+        self.systems.scene_manager.start(&mut self.context);
+    }
+
+    /// Runs a single tick without frame pacing.
+    ///
+    /// Used by `run_loop` and by tests to drive the core headlessly.
+    pub(crate) fn tick(
+        &mut self,
+        event_collector: &mut EventCollector,
+        commands: &Sender<PlatformCommand>,
+    ) -> TickControl {
+        if let Some(heartbeat) = &self.heartbeat {
+            heartbeat.beat();
+        }
+
+        // Collect events from platform thread
+        if let TickControl::Exit(reason) = event_collector.collect_frame() {
+            return TickControl::Exit(reason);
+        }
+
+        // Apply runtime commands sent since the last tick
+        self.apply_engine_commands();
 
+        // Transfer events and window state to context
+        self.context.frame_input_events = event_collector.take_batches();
+        self.sync_windows(event_collector);
+
+        if self.paused {
+            // Track input state only, so releases during the pause aren't lost
+            self.systems
+                .input
+                .track_state(&mut self.context.input_state, &self.context.frame_input_events);
+        } else {
             // Update all systems (input, scenes, transitions)
             self.systems.update(&mut self.context);
 
-            // Frame pacing
-            Self::maintain_frame_rate(frame_start, frame_duration);
+            // Run the user tick callback, if any
+            if let Some(tick_fn) = self.tick_fn.as_mut() {
+                tick_fn(&mut self.context);
+            }
+        }
+
+        // Apply a TPS change requested during this tick
+        if let Some(tps) = self.context.pending_tps.take() {
+            info!("Tick rate changed to {} TPS", tps);
+            self.set_tps(tps);
+        }
+
+        // Forward platform commands queued during the update
+        self.sync_redraw();
+        self.send_platform_commands(commands);
+
+        if self.context.exit_requested {
+            return TickControl::Exit(CoreExitReason::ExitRequested);
+        }
+
+        TickControl::Continue
+    }
+
+    //--- Test Access ------------------------------------------------------
+
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn context(&self) -> &GlobalContext {
+        &self.context
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn context_mut(&mut self) -> &mut GlobalContext {
+        &mut self.context
+    }
+
+    //--- Platform Synchronization -----------------------------------------
+
+    fn sync_windows(&mut self, event_collector: &EventCollector) {
+        for &window in event_collector.windows() {
+            if !self.context.windows.contains(&window) {
+                self.emit(EngineLifecycleEvent::WindowCreated(window));
+            }
+        }
+
+        self.context.windows.clear();
+        self.context.windows.extend_from_slice(event_collector.windows());
+        self.context.active_window = event_collector.active_window();
+    }
+
+    //--- Runtime Commands -------------------------------------------------
+
+    fn apply_engine_commands(&mut self) {
+        while let Some(command) = self.engine_commands.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            match command {
+                EngineCommand::Pause => {
+                    info!("Core systems paused");
+                    self.paused = true;
+                }
+                EngineCommand::Resume => {
+                    info!("Core systems resumed");
+                    self.paused = false;
+                }
+                EngineCommand::SetTps(tps) => self.context.set_tps(tps),
+                EngineCommand::Exit => self.context.request_exit(),
+            }
+        }
+    }
+
+    //--- Lifecycle Events -------------------------------------------------
+
+    fn emit(&mut self, event: EngineLifecycleEvent) {
+        if let Some(lifecycle_fn) = self.lifecycle_fn.as_mut() {
+            lifecycle_fn(event);
+        }
+    }
+
+    //--- Timing -----------------------------------------------------------
+
+    fn set_tps(&mut self, tps: f64) {
+        self.frame_duration = Duration::from_secs_f64(1.0 / tps);
+        self.context.tps = tps;
+    }
+
+    /// Queues `SetRedraw` when the scenes' redraw demand changes.
+    fn sync_redraw(&mut self) {
+        let needs_redraw = self.context.needs_redraw();
+        if needs_redraw != self.redraw_enabled {
+            self.redraw_enabled = needs_redraw;
+            self.context.platform_commands.push(PlatformCommand::SetRedraw(needs_redraw));
+        }
+    }
+
+    fn send_platform_commands(&mut self, commands: &Sender<PlatformCommand>) {
+        for command in self.context.platform_commands.drain(..) {
+            if commands.send(command).is_err() {
+                warn!("Platform command channel disconnected, dropping commands");
+                break;
+            }
         }
     }
 
     //--- Frame Pacing -----------------------------------------------------
 
-    fn maintain_frame_rate(frame_start: Instant, frame_duration: Duration) {
-        let elapsed = frame_start.elapsed();
+    /// Waits out the tick's budget, reporting it if the tick ran long.
+    ///
+    /// Overruns go to the overrun handler if one is installed, otherwise
+    /// they are logged.
+    fn pace_frame(&mut self, frame_start: Instant) {
+        let target = self.frame_duration;
+        let Some(elapsed) = Self::maintain_frame_rate(frame_start, target, self.precise_timing)
+        else {
+            return;
+        };
 
-        if elapsed >= frame_duration {
-            warn!(
+        self.emit(EngineLifecycleEvent::Overrun { elapsed, target });
+        match self.overrun_fn.as_mut() {
+            Some(overrun_fn) => overrun_fn(elapsed, target),
+            None => warn!(
                 "Core thread slow: {:.2}ms (target: {:.2}ms)",
                 elapsed.as_secs_f64() * 1000.0,
-                frame_duration.as_secs_f64() * 1000.0
-            );
-        } else {
-            thread::sleep(frame_duration - elapsed);
+                target.as_secs_f64() * 1000.0
+            ),
+        }
+    }
+
+    /// Waits out the remainder of the frame.
+    ///
+    /// Returns the elapsed time without waiting if the frame is already over
+    /// budget.
+    ///
+    /// `thread::sleep` alone can oversleep by several milliseconds. With
+    /// `precise` set, sleeps until `SPIN_MARGIN` before the deadline and
+    /// busy-waits the rest, trading CPU time for accuracy.
+    fn maintain_frame_rate(
+        frame_start: Instant,
+        frame_duration: Duration,
+        precise: bool,
+    ) -> Option<Duration> {
+        const SPIN_MARGIN: Duration = Duration::from_millis(2);
+
+        let elapsed = frame_start.elapsed();
+
+        if elapsed >= frame_duration {
+            return Some(elapsed);
+        }
+
+        let remaining = frame_duration - elapsed;
+        if !precise {
+            thread::sleep(remaining);
+            return None;
+        }
+
+        if remaining > SPIN_MARGIN {
+            thread::sleep(remaining - SPIN_MARGIN);
+        }
+
+        let deadline = frame_start + frame_duration;
+        while Instant::now() < deadline {
+            std::hint::spin_loop();
         }
+        None
     }
 }
 
+//=== Helpers =============================================================
+
+/// Extracts the message from a panic payload, if it is a string.
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("<non-string panic payload>")
+}
+
 //=========================================================================
 // Unit Tests
 //=========================================================================
@@ -146,15 +491,17 @@ impl<S: SceneKey, A: Action> CoreSystemsOrchestrator<S, A> {
 mod tests {
     use super::*;
     use crossbeam_channel::unbounded;
-    use crate::core::input::event::{KeyCode, Modifiers, InputEvent};
 
+    #[allow(dead_code)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     enum TestScene {
         Main,
+        Pause,
     }
 
     impl SceneKey for TestScene {}
 
+    #[allow(dead_code)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     enum TestAction {
         Jump,
@@ -167,23 +514,142 @@ mod tests {
     #[test]
     fn spawn_core_thread_exits_on_window_closed() {
         let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
         let orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
-        let handle = orchestrator.spawn_core_thread(rx, 60.0);
+        let handle = orchestrator.spawn_core_thread(rx, cmd_tx, 60.0);
 
         tx.send(PlatformEvent::WindowClosed).unwrap();
 
-        assert!(handle.join().is_ok());
+        assert_eq!(handle.join().unwrap(), CoreExitReason::WindowClosed);
     }
 
     #[test]
     fn spawn_core_thread_exits_on_channel_disconnect() {
         let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
         let orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
-        let handle = orchestrator.spawn_core_thread(rx, 60.0);
+        let handle = orchestrator.spawn_core_thread(rx, cmd_tx, 60.0);
 
         drop(tx);
 
-        assert!(handle.join().is_ok());
+        assert_eq!(handle.join().unwrap(), CoreExitReason::ChannelDisconnected);
+    }
+
+    #[test]
+    fn spawn_core_thread_exits_on_request() {
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_tick_fn(Box::new(|context| context.request_exit()));
+        let handle = orchestrator.spawn_core_thread(rx, cmd_tx, 60.0);
+
+        assert_eq!(handle.join().unwrap(), CoreExitReason::ExitRequested);
+    }
+
+    #[test]
+    fn lifecycle_observer_sees_start_then_exit() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        let sink = Arc::clone(&events);
+        orchestrator.set_lifecycle_observer(Box::new(move |event| {
+            sink.lock().unwrap().push(event);
+        }));
+        orchestrator.set_tick_fn(Box::new(|context| context.request_exit()));
+
+        tx.send(PlatformEvent::WindowCreated(WindowId::from_raw(1))).unwrap();
+        let handle = orchestrator.spawn_core_thread(rx, cmd_tx, 60.0);
+        handle.join().unwrap();
+
+        let events: Vec<_> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .copied()
+            .filter(|event| !matches!(event, EngineLifecycleEvent::Overrun { .. }))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                EngineLifecycleEvent::CoreStarted,
+                EngineLifecycleEvent::WindowCreated(WindowId::from_raw(1)),
+                EngineLifecycleEvent::CoreExited(CoreExitReason::ExitRequested),
+            ]
+        );
+    }
+
+    #[test]
+    fn scene_panic_sends_shutdown_to_platform() {
+        use crate::core::scene::Scene;
+
+        struct PanickingScene;
+
+        impl Scene<TestScene> for PanickingScene {
+            fn update(&mut self, _context: &GlobalContext) {
+                panic!("scene exploded");
+            }
+        }
+
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, cmd_rx) = unbounded();
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.init_systems(|systems| {
+            systems.scene_manager.register_default(TestScene::Main, PanickingScene);
+        });
+        let handle = orchestrator.spawn_core_thread(rx, cmd_tx, 60.0);
+
+        assert_eq!(
+            cmd_rx.recv_timeout(Duration::from_secs(5)),
+            Ok(PlatformCommand::Shutdown)
+        );
+        assert!(handle.join().is_err());
+    }
+
+    #[test]
+    fn watchdog_detects_stalled_update() {
+        use crate::core::platform_bridge::Watchdog;
+        use crate::core::scene::Scene;
+
+        /// Blocks in `update` until the test lets it go.
+        struct StallingScene(Receiver<()>);
+
+        impl Scene<TestScene> for StallingScene {
+            fn update(&mut self, _context: &GlobalContext) {
+                let _ = self.0.recv();
+            }
+        }
+
+        let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let (release, stall) = unbounded();
+        let heartbeat = Heartbeat::new();
+        let threshold = Duration::from_millis(50);
+        let mut watchdog = Watchdog::new(heartbeat.clone(), threshold);
+
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_heartbeat(heartbeat.clone());
+        orchestrator.init_systems(|systems| {
+            systems.scene_manager.register_default(TestScene::Main, StallingScene(stall));
+        });
+        let handle = orchestrator.spawn_core_thread(rx, cmd_tx, 1000.0);
+
+        // First tick beats, then blocks inside the scene update
+        while heartbeat.count() == 0 {
+            thread::yield_now();
+        }
+        let stalled_at = heartbeat.count();
+        assert!(!watchdog.check(Instant::now()));
+
+        thread::sleep(threshold * 3);
+        assert_eq!(heartbeat.count(), stalled_at);
+        assert!(watchdog.check(Instant::now()));
+
+        tx.send(PlatformEvent::WindowClosed).unwrap();
+        drop(release);
+        assert_eq!(handle.join().unwrap(), CoreExitReason::WindowClosed);
     }
 
     //--- Panics -----------------------------------------------------------
@@ -192,15 +658,489 @@ mod tests {
     #[should_panic(expected = "TPS must be positive, got 0")]
     fn spawn_panics_on_zero_tps() {
         let (_, rx) = unbounded();
+        let (cmd_tx, _) = unbounded();
         let orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
-        orchestrator.spawn_core_thread(rx, 0.0);
+        orchestrator.spawn_core_thread(rx, cmd_tx, 0.0);
     }
 
     #[test]
     #[should_panic(expected = "TPS must be positive, got -10")]
     fn spawn_panics_on_negative_tps() {
         let (_, rx) = unbounded();
+        let (cmd_tx, _) = unbounded();
         let orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
-        orchestrator.spawn_core_thread(rx, -10.0);
+        orchestrator.spawn_core_thread(rx, cmd_tx, -10.0);
+    }
+
+    //--- Windows ----------------------------------------------------------
+
+    #[test]
+    fn window_requests_are_forwarded_to_platform() {
+        let (_tx, rx) = unbounded::<PlatformEvent>();
+        let (cmd_tx, cmd_rx) = unbounded();
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+
+        orchestrator.context.open_window("Inspector", 400, 300);
+        orchestrator.context.close_window(WindowId::from_raw(7));
+        orchestrator.send_platform_commands(&cmd_tx);
+        drop(rx);
+
+        assert_eq!(
+            cmd_rx.try_recv().unwrap(),
+            PlatformCommand::OpenWindow { title: "Inspector".to_string(), width: 400, height: 300 }
+        );
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::CloseWindow(WindowId::from_raw(7)));
+        assert!(orchestrator.context.platform_commands.is_empty());
+    }
+
+    #[test]
+    fn cursor_requests_are_forwarded_to_platform() {
+        let (cmd_tx, cmd_rx) = unbounded();
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+
+        orchestrator.context.set_cursor_visible(false);
+        orchestrator.context.set_cursor_grab(true);
+        orchestrator.context.set_relative_mouse(true);
+        orchestrator.send_platform_commands(&cmd_tx);
+
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::SetCursorVisible(false));
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::SetCursorGrab(true));
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::SetRelativeMouse(true));
+    }
+
+    #[test]
+    fn window_control_requests_are_forwarded_to_platform() {
+        let (cmd_tx, cmd_rx) = unbounded();
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+
+        orchestrator.context.set_always_on_top(true);
+        orchestrator.context.minimize_window();
+        orchestrator.context.maximize_window();
+        orchestrator.context.set_window_title("Paused");
+        orchestrator.send_platform_commands(&cmd_tx);
+
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::SetAlwaysOnTop(true));
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::Minimize);
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::Maximize);
+        assert_eq!(cmd_rx.try_recv().unwrap(), PlatformCommand::SetTitle("Paused".to_string()));
+        assert!(orchestrator.context.platform_commands.is_empty());
+    }
+
+    #[test]
+    fn redraw_changes_are_sent_once() {
+        use crate::core::scene::Scene;
+
+        struct StaticScene;
+
+        impl Scene<TestScene> for StaticScene {
+            fn update(&mut self, _context: &GlobalContext) {}
+
+            fn needs_redraw(&self) -> bool {
+                false
+            }
+        }
+
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.init_systems(|systems| {
+            systems.scene_manager.register_default(TestScene::Main, StaticScene);
+        });
+
+        orchestrator.tick(&mut collector, &cmd_tx);
+        orchestrator.tick(&mut collector, &cmd_tx);
+
+        assert_eq!(cmd_rx.try_recv(), Ok(PlatformCommand::SetRedraw(false)));
+        assert!(cmd_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn sync_windows_exposes_active_window_on_context() {
+        let (tx, rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        let main = WindowId::from_raw(1);
+        let inspector = WindowId::from_raw(2);
+
+        tx.send(PlatformEvent::WindowCreated(main)).unwrap();
+        tx.send(PlatformEvent::WindowCreated(inspector)).unwrap();
+        tx.send(PlatformEvent::Inputs {
+            window: inspector,
+            discrete: vec![],
+            continuous: vec![crate::core::input::InputEvent::MouseMoved { x: 3.0, y: 4.0 }],
+        }).unwrap();
+
+        collector.collect_frame();
+        orchestrator.sync_windows(&collector);
+
+        assert_eq!(orchestrator.context.windows(), &[main, inspector]);
+        assert_eq!(orchestrator.context.active_window(), Some(inspector));
+    }
+
+    //--- Frame Pacing -----------------------------------------------------
+
+    /// Median overshoot past the frame deadline over several frames.
+    fn median_overshoot(frame_duration: Duration, precise: bool) -> Duration {
+        let mut overshoots: Vec<Duration> = (0..24)
+            .map(|_| {
+                let frame_start = Instant::now();
+                CoreSystemsOrchestrator::<TestScene, TestAction>::maintain_frame_rate(
+                    frame_start,
+                    frame_duration,
+                    precise,
+                );
+                frame_start.elapsed().saturating_sub(frame_duration)
+            })
+            .collect();
+        overshoots.sort();
+        overshoots[overshoots.len() / 2]
+    }
+
+    #[test]
+    fn precise_timing_never_undershoots() {
+        let frame_duration = Duration::from_secs_f64(1.0 / 240.0);
+        let frame_start = Instant::now();
+
+        CoreSystemsOrchestrator::<TestScene, TestAction>::maintain_frame_rate(
+            frame_start,
+            frame_duration,
+            true,
+        );
+
+        assert!(frame_start.elapsed() >= frame_duration);
+    }
+
+    #[test]
+    fn precise_timing_is_tighter_than_sleep() {
+        // Medians and a generous slack keep this stable under CI jitter
+        let frame_duration = Duration::from_secs_f64(1.0 / 240.0);
+
+        let sleep_overshoot = median_overshoot(frame_duration, false);
+        let precise_overshoot = median_overshoot(frame_duration, true);
+
+        assert!(
+            precise_overshoot <= sleep_overshoot + Duration::from_micros(250),
+            "precise {:?} vs sleep {:?}",
+            precise_overshoot,
+            sleep_overshoot
+        );
+        assert!(precise_overshoot < Duration::from_millis(1), "precise {:?}", precise_overshoot);
+    }
+
+    #[test]
+    fn overrun_handler_receives_elapsed_and_target() {
+        use std::sync::{Arc, Mutex};
+
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_tps(100.0);
+        orchestrator.set_tick_fn(Box::new(|_| thread::sleep(Duration::from_millis(20))));
+
+        let overruns = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&overruns);
+        orchestrator.set_overrun_handler(Box::new(move |elapsed, target| {
+            recorder.lock().unwrap().push((elapsed, target));
+        }));
+
+        let frame_start = Instant::now();
+        orchestrator.tick(&mut collector, &cmd_tx);
+        orchestrator.pace_frame(frame_start);
+
+        let overruns = overruns.lock().unwrap();
+        assert_eq!(overruns.len(), 1);
+        let (elapsed, target) = overruns[0];
+        assert!(elapsed >= Duration::from_millis(20), "elapsed {:?}", elapsed);
+        assert_eq!(target, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn overrun_handler_not_called_within_budget() {
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_tps(1000.0);
+        orchestrator.set_overrun_handler(Box::new(|_, _| panic!("unexpected overrun")));
+
+        orchestrator.pace_frame(Instant::now());
+    }
+
+    //--- Tick Callback ----------------------------------------------------
+
+    #[test]
+    fn tick_fn_runs_once_per_tick_and_reads_input() {
+        use std::sync::{Arc, Mutex};
+        use crate::core::input::{InputEvent, KeyCode, Modifiers};
+
+        let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&seen);
+        orchestrator.set_tick_fn(Box::new(move |context| {
+            recorder.lock().unwrap().push(context.input_state.is_key_pressed(KeyCode::Space));
+        }));
+
+        tx.send(PlatformEvent::Inputs {
+            window: WindowId::from_raw(1),
+            discrete: vec![InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE }],
+            continuous: vec![],
+        }).unwrap();
+
+        for _ in 0..3 {
+            assert_eq!(orchestrator.tick(&mut collector, &cmd_tx), TickControl::Continue);
+        }
+
+        assert_eq!(*seen.lock().unwrap(), vec![true, false, false]);
+    }
+
+    #[test]
+    fn transition_queued_on_bus_activates_scene_next_tick() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use crate::core::scene::{Scene, SceneTransition};
+
+        struct CountingScene {
+            enters: Arc<AtomicUsize>,
+            updates: Arc<AtomicUsize>,
+        }
+
+        impl Scene<TestScene> for CountingScene {
+            fn on_enter(&mut self, _context: &GlobalContext) -> Option<SceneTransition<TestScene>> {
+                self.enters.fetch_add(1, Ordering::SeqCst);
+                None
+            }
+
+            fn update(&mut self, _context: &GlobalContext) {
+                self.updates.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+
+        let enters = Arc::new(AtomicUsize::new(0));
+        let updates = Arc::new(AtomicUsize::new(0));
+        let pause = CountingScene { enters: Arc::clone(&enters), updates: Arc::clone(&updates) };
+        orchestrator.init_systems(|systems| {
+            systems.scene_manager.register_default(TestScene::Main, CountingScene {
+                enters: Arc::new(AtomicUsize::new(0)),
+                updates: Arc::new(AtomicUsize::new(0)),
+            });
+            systems.scene_manager.register_scene(TestScene::Pause, pause);
+        });
+
+        let mut queued = false;
+        orchestrator.set_tick_fn(Box::new(move |context| {
+            if !queued {
+                context.message_bus.push(SceneTransition::Push(TestScene::Pause));
+                queued = true;
+            }
+        }));
+
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert_eq!(enters.load(Ordering::SeqCst), 0);
+
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert_eq!(enters.load(Ordering::SeqCst), 1);
+        assert_eq!(updates.load(Ordering::SeqCst), 0);
+
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert_eq!(enters.load(Ordering::SeqCst), 1);
+        assert_eq!(updates.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn set_tps_changes_frame_duration_after_tick() {
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_tick_fn(Box::new(|context| {
+            if context.tps() == 60.0 {
+                context.set_tps(30.0);
+            }
+        }));
+
+        assert_eq!(orchestrator.frame_duration, Duration::from_secs_f64(1.0 / 60.0));
+
+        orchestrator.tick(&mut collector, &cmd_tx);
+
+        assert_eq!(orchestrator.frame_duration, Duration::from_secs_f64(1.0 / 30.0));
+        assert_eq!(orchestrator.context.tps(), 30.0);
+    }
+
+    #[test]
+    fn set_tps_ignores_invalid_values() {
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_tick_fn(Box::new(|context| {
+            context.set_tps(0.0);
+            context.set_tps(f64::NAN);
+        }));
+
+        orchestrator.tick(&mut collector, &cmd_tx);
+
+        assert_eq!(orchestrator.context.tps(), 60.0);
+    }
+
+    #[test]
+    fn tick_exits_without_running_tick_fn_on_close() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        orchestrator.set_tick_fn(Box::new(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        tx.send(PlatformEvent::WindowClosed).unwrap();
+
+        assert_eq!(
+            orchestrator.tick(&mut collector, &cmd_tx),
+            TickControl::Exit(CoreExitReason::WindowClosed)
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
     }
-}
\ No newline at end of file
+
+    //--- Runtime Commands -------------------------------------------------
+
+    #[test]
+    fn pause_command_stops_scene_updates_until_resume() {
+        use crate::core::scene::Scene;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct CountingScene(Arc<AtomicUsize>);
+
+        impl Scene<TestScene> for CountingScene {
+            fn update(&mut self, _context: &GlobalContext) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let (engine_tx, engine_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_command_receiver(engine_rx);
+
+        let updates = Arc::new(AtomicUsize::new(0));
+        let scene = CountingScene(Arc::clone(&updates));
+        orchestrator.init_systems(|systems| {
+            systems.scene_manager.register_default(TestScene::Main, scene);
+        });
+
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert_eq!(updates.load(Ordering::SeqCst), 1);
+
+        engine_tx.send(EngineCommand::Pause).unwrap();
+        orchestrator.tick(&mut collector, &cmd_tx);
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert_eq!(updates.load(Ordering::SeqCst), 1);
+
+        engine_tx.send(EngineCommand::Resume).unwrap();
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert_eq!(updates.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn release_during_pause_is_applied_on_resume() {
+        use crate::core::input::{InputEvent, KeyCode, Modifiers};
+
+        let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let (engine_tx, engine_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_command_receiver(engine_rx);
+        let window = WindowId::from_raw(1);
+        tx.send(PlatformEvent::WindowCreated(window)).unwrap();
+
+        let send_key = |event: InputEvent| {
+            tx.send(PlatformEvent::Inputs { window, discrete: vec![event], continuous: vec![] })
+                .unwrap();
+        };
+
+        send_key(InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE });
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert!(orchestrator.context.input_state.is_key_down(KeyCode::Space));
+
+        engine_tx.send(EngineCommand::Pause).unwrap();
+        orchestrator.tick(&mut collector, &cmd_tx);
+        send_key(InputEvent::KeyUp { key: KeyCode::Space, modifiers: Modifiers::NONE });
+        orchestrator.tick(&mut collector, &cmd_tx);
+
+        engine_tx.send(EngineCommand::Resume).unwrap();
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert!(!orchestrator.context.input_state.is_key_down(KeyCode::Space));
+    }
+
+    #[test]
+    fn press_during_pause_does_not_start_a_cooldown() {
+        use crate::core::input::{InputContext, InputEvent, KeyCode, Modifiers};
+
+        let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let (engine_tx, engine_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_command_receiver(engine_rx);
+        orchestrator.init_systems(|systems| {
+            systems.input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+            systems.input.set_action_cooldown(TestAction::Jump, 10);
+        });
+        let window = WindowId::from_raw(1);
+        tx.send(PlatformEvent::WindowCreated(window)).unwrap();
+
+        let send_key = |event: InputEvent| {
+            tx.send(PlatformEvent::Inputs { window, discrete: vec![event], continuous: vec![] })
+                .unwrap();
+        };
+
+        engine_tx.send(EngineCommand::Pause).unwrap();
+        orchestrator.tick(&mut collector, &cmd_tx);
+        send_key(InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE });
+        orchestrator.tick(&mut collector, &cmd_tx);
+        send_key(InputEvent::KeyUp { key: KeyCode::Space, modifiers: Modifiers::NONE });
+        orchestrator.tick(&mut collector, &cmd_tx);
+
+        engine_tx.send(EngineCommand::Resume).unwrap();
+        send_key(InputEvent::KeyDown { key: KeyCode::Space, modifiers: Modifiers::NONE });
+        orchestrator.tick(&mut collector, &cmd_tx);
+        assert_eq!(orchestrator.systems.input.published_actions(), vec![TestAction::Jump]);
+    }
+
+    #[test]
+    fn exit_command_ends_loop_even_while_paused() {
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let (engine_tx, engine_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        let mut orchestrator = CoreSystemsOrchestrator::<TestScene, TestAction>::new();
+        orchestrator.set_command_receiver(engine_rx);
+
+        engine_tx.send(EngineCommand::Pause).unwrap();
+        engine_tx.send(EngineCommand::SetTps(30.0)).unwrap();
+        engine_tx.send(EngineCommand::Exit).unwrap();
+
+        assert_eq!(
+            orchestrator.tick(&mut collector, &cmd_tx),
+            TickControl::Exit(CoreExitReason::ExitRequested)
+        );
+        assert_eq!(orchestrator.context.tps(), 30.0);
+    }
+}
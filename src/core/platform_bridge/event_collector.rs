@@ -6,6 +6,7 @@
 //
 // Architecture:
 //   Receiver<PlatformEvent> → collect_frame() → input_batches → TickControl
+//                                             → window tracking (open/active)
 //
 // Bounded polling prevents starvation. Idle sleep reduces CPU usage.
 //
@@ -21,7 +22,7 @@ use log::warn;
 
 //=== Internal Dependencies ===============================================
 
-use super::PlatformEvent;
+use super::{PlatformEvent, WindowId};
 use crate::core::input::event::InputEvent;
 
 //=== TickControl =========================================================
@@ -30,15 +31,34 @@ use crate::core::input::event::InputEvent;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum TickControl {
     Continue,
-    Exit,
+    Exit(CoreExitReason),
+}
+
+//=== CoreExitReason ======================================================
+
+/// Why the core thread stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreExitReason {
+    /// The platform reported that the primary window was closed.
+    WindowClosed,
+
+    /// The platform side of the channel was dropped without a close event.
+    ChannelDisconnected,
+
+    /// Game code requested shutdown via `GlobalContext::request_exit`.
+    ExitRequested,
 }
 
 //=== EventCollector ======================================================
 
 /// Collects platform events with bounded polling and batch extraction.
+///
+/// Also tracks open windows and the window that most recently delivered input.
 pub(crate) struct EventCollector {
     receiver: Receiver<PlatformEvent>,
     input_batches: Vec<Vec<InputEvent>>,
+    windows: Vec<WindowId>,
+    active_window: Option<WindowId>,
 }
 
 impl EventCollector {
@@ -46,6 +66,8 @@ impl EventCollector {
         Self {
             receiver,
             input_batches: Vec::with_capacity(4),
+            windows: Vec::new(),
+            active_window: None,
         }
     }
 
@@ -62,12 +84,14 @@ impl EventCollector {
             match self.receiver.try_recv() {
                 Ok(event) => {
                     had_event = true;
-                    if self.handle_event(event) == TickControl::Exit {
-                        return TickControl::Exit;
+                    if let TickControl::Exit(reason) = self.handle_event(event) {
+                        return TickControl::Exit(reason);
                     }
                     drained += 1;
                 }
-                Err(TryRecvError::Disconnected) => return TickControl::Exit,
+                Err(TryRecvError::Disconnected) => {
+                    return TickControl::Exit(CoreExitReason::ChannelDisconnected);
+                }
                 Err(TryRecvError::Empty) => break,
             }
         }
@@ -84,10 +108,21 @@ impl EventCollector {
     }
 
     /// Returns collected input batches for this frame.
+    #[cfg(test)]
     pub(crate) fn batches(&self) -> &[Vec<InputEvent>] {
         &self.input_batches
     }
 
+    /// Returns all open windows in creation order (primary first).
+    pub(crate) fn windows(&self) -> &[WindowId] {
+        &self.windows
+    }
+
+    /// Returns the window that most recently delivered input, if any.
+    pub(crate) fn active_window(&self) -> Option<WindowId> {
+        self.active_window
+    }
+
     /// Takes ownership of collected input batches, leaving empty vec.
     ///
     /// Efficient transfer without allocation. The internal buffer is
@@ -98,7 +133,8 @@ impl EventCollector {
 
     fn handle_event(&mut self, event: PlatformEvent) -> TickControl {
         match event {
-            PlatformEvent::Inputs { discrete, continuous } => {
+            PlatformEvent::Inputs { window, discrete, continuous } => {
+                self.active_window = Some(window);
                 if !discrete.is_empty() {
                     self.input_batches.push(discrete);
                 }
@@ -107,7 +143,24 @@ impl EventCollector {
                 }
                 TickControl::Continue
             }
-            PlatformEvent::WindowClosed => TickControl::Exit,
+            PlatformEvent::WindowCreated(window) => {
+                if !self.windows.contains(&window) {
+                    self.windows.push(window);
+                }
+                TickControl::Continue
+            }
+            PlatformEvent::WindowDestroyed(window) => {
+                self.windows.retain(|&w| w != window);
+                if self.active_window == Some(window) {
+                    self.active_window = None;
+                }
+                TickControl::Continue
+            }
+            PlatformEvent::InputBurst { window, discrete } => {
+                warn!("Input burst: {} discrete events from {:?} in one frame", discrete, window);
+                TickControl::Continue
+            }
+            PlatformEvent::WindowClosed => TickControl::Exit(CoreExitReason::WindowClosed),
         }
     }
 }
@@ -122,6 +175,9 @@ mod tests {
     use crossbeam_channel::unbounded;
     use crate::core::input::{KeyCode, Modifiers};
 
+    const MAIN: WindowId = WindowId::from_raw(1);
+    const INSPECTOR: WindowId = WindowId::from_raw(2);
+
     #[test]
     fn collect_handles_empty_queue() {
         let (_tx, rx) = unbounded::<PlatformEvent>();
@@ -139,6 +195,7 @@ mod tests {
         let mut collector = EventCollector::new(rx);
 
         tx.send(PlatformEvent::Inputs {
+            window: MAIN,
             discrete: vec![InputEvent::KeyDown {
                 key: KeyCode::KeyA,
                 modifiers: Modifiers::NONE
@@ -147,6 +204,7 @@ mod tests {
         }).unwrap();
 
         tx.send(PlatformEvent::Inputs {
+            window: MAIN,
             discrete: vec![],
             continuous: vec![InputEvent::MouseMoved { x: 10.0, y: 20.0 }]
         }).unwrap();
@@ -166,7 +224,7 @@ mod tests {
 
         let result = collector.collect_frame();
 
-        assert_eq!(result, TickControl::Exit);
+        assert_eq!(result, TickControl::Exit(CoreExitReason::WindowClosed));
     }
 
     #[test]
@@ -175,6 +233,7 @@ mod tests {
         let mut collector = EventCollector::new(rx);
 
         tx.send(PlatformEvent::Inputs {
+            window: MAIN,
             discrete: vec![InputEvent::KeyDown {
                 key: KeyCode::Space,
                 modifiers: Modifiers::NONE
@@ -186,6 +245,7 @@ mod tests {
         assert_eq!(collector.batches().len(), 1);
 
         tx.send(PlatformEvent::Inputs {
+            window: MAIN,
             discrete: vec![],
             continuous: vec![]
         }).unwrap();
@@ -203,6 +263,74 @@ mod tests {
 
         let result = collector.collect_frame();
 
-        assert_eq!(result, TickControl::Exit);
+        assert_eq!(result, TickControl::Exit(CoreExitReason::ChannelDisconnected));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn collect_tracks_active_window_from_inputs() {
+        let (tx, rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+
+        tx.send(PlatformEvent::Inputs {
+            window: MAIN,
+            discrete: vec![],
+            continuous: vec![InputEvent::MouseMoved { x: 1.0, y: 1.0 }]
+        }).unwrap();
+        tx.send(PlatformEvent::Inputs {
+            window: INSPECTOR,
+            discrete: vec![InputEvent::KeyDown {
+                key: KeyCode::KeyA,
+                modifiers: Modifiers::NONE
+            }],
+            continuous: vec![]
+        }).unwrap();
+
+        collector.collect_frame();
+
+        assert_eq!(collector.active_window(), Some(INSPECTOR));
+    }
+
+    #[test]
+    fn collect_forwards_cursor_leave() {
+        let (tx, rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+
+        tx.send(PlatformEvent::Inputs {
+            window: MAIN,
+            discrete: vec![InputEvent::CursorEntered, InputEvent::CursorLeft],
+            continuous: vec![]
+        }).unwrap();
+
+        collector.collect_frame();
+
+        assert_eq!(
+            collector.batches(),
+            &[vec![InputEvent::CursorEntered, InputEvent::CursorLeft]]
+        );
+        assert_eq!(collector.active_window(), Some(MAIN));
+    }
+
+    #[test]
+    fn collect_tracks_window_lifecycle() {
+        let (tx, rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+
+        tx.send(PlatformEvent::WindowCreated(MAIN)).unwrap();
+        tx.send(PlatformEvent::WindowCreated(INSPECTOR)).unwrap();
+        collector.collect_frame();
+        assert_eq!(collector.windows(), &[MAIN, INSPECTOR]);
+
+        tx.send(PlatformEvent::Inputs {
+            window: INSPECTOR,
+            discrete: vec![],
+            continuous: vec![InputEvent::MouseMoved { x: 1.0, y: 1.0 }]
+        }).unwrap();
+        tx.send(PlatformEvent::WindowDestroyed(INSPECTOR)).unwrap();
+
+        let result = collector.collect_frame();
+
+        assert_eq!(result, TickControl::Continue);
+        assert_eq!(collector.windows(), &[MAIN]);
+        assert_eq!(collector.active_window(), None);
+    }
+}
@@ -2,31 +2,112 @@
 // Platform Bridge Interface
 //=========================================================================
 //
-// Platform-to-core interface types (events and errors).
+// Platform-to-core interface types (events, commands and errors).
 //
 // Defines the contract for communication between platform and core threads.
 //
+//   Platform ──PlatformEvent──► Core
+//   Platform ◄─PlatformCommand─ Core
+//
 //=========================================================================
 
 //=== Internal Dependencies ===============================================
 
 use crate::core::input::event::InputEvent;
 
+//=== WindowId ============================================================
+
+/// Platform-agnostic window identifier.
+///
+/// Assigned by the platform layer when a window is created. Input batches
+/// are tagged with the id of the window that produced them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowId(u64);
+
+impl WindowId {
+    /// Wraps a raw platform window id.
+    pub(crate) const fn from_raw(raw: u64) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw platform window id.
+    pub(crate) const fn raw(self) -> u64 {
+        self.0
+    }
+}
+
 //=== PlatformEvent =======================================================
 
 /// Events sent from platform to core via MPSC.
 #[derive(Debug, Clone)]
 pub(crate) enum PlatformEvent {
-    /// Batched input events for a frame.
+    /// Batched input events for a frame, tagged with the originating window.
     Inputs {
+        window: WindowId,
         discrete: Vec<InputEvent>,
         continuous: Vec<InputEvent>,
     },
 
-    /// Window close requested.
+    /// A window was created (primary or secondary).
+    WindowCreated(WindowId),
+
+    /// A secondary window was closed and destroyed.
+    WindowDestroyed(WindowId),
+
+    /// Diagnostic: a window buffered an unusually large number of discrete
+    /// events in a single frame (sent after the matching `Inputs` batch).
+    InputBurst {
+        window: WindowId,
+        discrete: usize,
+    },
+
+    /// Primary window close requested (application shutdown).
     WindowClosed,
 }
 
+//=== PlatformCommand =====================================================
+
+/// Commands sent from core to platform via the reverse channel.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PlatformCommand {
+    /// Opens a secondary window.
+    OpenWindow {
+        title: String,
+        width: u32,
+        height: u32,
+    },
+
+    /// Closes a secondary window (the primary window cannot be closed this way).
+    CloseWindow(WindowId),
+
+    /// Shows or hides the cursor over the primary window.
+    SetCursorVisible(bool),
+
+    /// Confines (or releases) the cursor to the primary window.
+    SetCursorGrab(bool),
+
+    /// Recenters the cursor in the primary window after every frame.
+    SetRelativeMouse(bool),
+
+    /// Keeps the primary window above other windows (or back to normal).
+    SetAlwaysOnTop(bool),
+
+    /// Minimizes the primary window.
+    Minimize,
+
+    /// Maximizes the primary window.
+    Maximize,
+
+    /// Changes the primary window's title.
+    SetTitle(String),
+
+    /// Enables or pauses continuous redraws (sent only when it changes).
+    SetRedraw(bool),
+
+    /// The core thread panicked; the platform should tear down promptly.
+    Shutdown,
+}
+
 //=== PlatformError =======================================================
 
 /// Platform initialization and runtime errors.
@@ -37,6 +118,9 @@ pub(crate) enum PlatformError {
 
     /// Event loop execution error.
     EventLoopExecution(String),
+
+    /// A window command arrived before the primary window was created.
+    NoWindow,
 }
 
 impl std::fmt::Display for PlatformError {
@@ -44,8 +128,9 @@ impl std::fmt::Display for PlatformError {
         match self {
             Self::EventLoopCreation(e) => write!(f, "Event loop creation failed: {}", e),
             Self::EventLoopExecution(e) => write!(f, "Event loop error: {}", e),
+            Self::NoWindow => write!(f, "No window has been created yet"),
         }
     }
 }
 
-impl std::error::Error for PlatformError {}
\ No newline at end of file
+impl std::error::Error for PlatformError {}
@@ -9,8 +9,9 @@
 // core code (Dependency Inversion Principle).
 //
 // Components:
-// - `interface`: Event types and error definitions (the contract)
+// - `interface`: Event, command and error definitions (the contract)
 // - `event_collector`: Core-side event collection and buffering
+// - `watchdog`: Core heartbeat and platform-side hang detection
 //
 //=========================================================================
 
@@ -18,8 +19,10 @@
 
 pub(crate) mod event_collector;
 pub(crate) mod interface;
+pub(crate) mod watchdog;
 
 //=== Internal API ========================================================
 
-pub(crate) use event_collector::{EventCollector, TickControl};
-pub(crate) use interface::{PlatformError, PlatformEvent};
\ No newline at end of file
+pub(crate) use event_collector::{CoreExitReason, EventCollector, TickControl};
+pub(crate) use interface::{PlatformCommand, PlatformError, PlatformEvent, WindowId};
+pub(crate) use watchdog::{Heartbeat, Watchdog};
\ No newline at end of file
@@ -0,0 +1,146 @@
+//=========================================================================
+// Watchdog
+//=========================================================================
+//
+// Detects a hung core thread from the platform side.
+//
+// Architecture:
+//   Core Thread ──beat()──> Heartbeat (AtomicU64) <──check()── Watchdog
+//                                                    (platform, per loop)
+//
+// The core bumps the counter once per tick. If the counter stops advancing
+// for longer than the threshold, the watchdog logs an error once; it logs
+// again when the core recovers.
+//
+//=========================================================================
+
+//=== External Dependencies ===============================================
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{error, info};
+
+//=== Heartbeat ===========================================================
+
+/// Tick counter shared between the core thread and the watchdog.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Heartbeat(Arc<AtomicU64>);
+
+impl Heartbeat {
+    /// Creates a counter starting at zero.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one core iteration.
+    pub(crate) fn beat(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the number of beats so far.
+    pub(crate) fn count(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+//=== Watchdog ============================================================
+
+/// Platform-side monitor that reports when the heartbeat stops advancing.
+pub(crate) struct Watchdog {
+    heartbeat: Heartbeat,
+    threshold: Duration,
+    last_count: u64,
+    last_change: Instant,
+    stalled: bool,
+}
+
+impl Watchdog {
+    /// Watches `heartbeat`, reporting stalls longer than `threshold`.
+    pub(crate) fn new(heartbeat: Heartbeat, threshold: Duration) -> Self {
+        Self {
+            last_count: heartbeat.count(),
+            heartbeat,
+            threshold,
+            last_change: Instant::now(),
+            stalled: false,
+        }
+    }
+
+    /// Samples the heartbeat at `now`. Returns `true` while the core is stalled.
+    ///
+    /// Logs an error when a stall is first detected and an info message when
+    /// the heartbeat resumes.
+    pub(crate) fn check(&mut self, now: Instant) -> bool {
+        let count = self.heartbeat.count();
+        if count != self.last_count {
+            if self.stalled {
+                info!(target: "platform", "Core thread heartbeat resumed");
+            }
+            self.last_count = count;
+            self.last_change = now;
+            self.stalled = false;
+            return false;
+        }
+
+        let silent_for = now.saturating_duration_since(self.last_change);
+        if !self.stalled && silent_for > self.threshold {
+            error!(
+                target: "platform",
+                "Core thread unresponsive for {:?} (threshold {:?}); is a scene blocked?",
+                silent_for,
+                self.threshold
+            );
+            self.stalled = true;
+        }
+        self.stalled
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: Duration = Duration::from_millis(500);
+
+    #[test]
+    fn beating_core_is_not_stalled() {
+        let heartbeat = Heartbeat::new();
+        let mut watchdog = Watchdog::new(heartbeat.clone(), THRESHOLD);
+        let start = Instant::now();
+
+        for i in 1..=5 {
+            heartbeat.beat();
+            assert!(!watchdog.check(start + THRESHOLD * i));
+        }
+    }
+
+    #[test]
+    fn silent_heartbeat_is_detected_after_threshold() {
+        let heartbeat = Heartbeat::new();
+        let mut watchdog = Watchdog::new(heartbeat.clone(), THRESHOLD);
+        let start = Instant::now();
+
+        heartbeat.beat();
+        assert!(!watchdog.check(start));
+        assert!(!watchdog.check(start + THRESHOLD));
+        assert!(watchdog.check(start + THRESHOLD * 2));
+    }
+
+    #[test]
+    fn resumed_heartbeat_clears_stall() {
+        let heartbeat = Heartbeat::new();
+        let mut watchdog = Watchdog::new(heartbeat.clone(), THRESHOLD);
+        let start = Instant::now();
+
+        assert!(watchdog.check(start + THRESHOLD * 2));
+
+        heartbeat.beat();
+        assert!(!watchdog.check(start + THRESHOLD * 3));
+    }
+}
@@ -11,12 +11,18 @@
 //
 // Flow:
 //   update() → collect_active_scenes() → Scene::update()
+//   process_transitions() → late_update() → Scene::late_update()
 //
 //=========================================================================
 
+//=== External Dependencies ===============================================
+
+use std::any::Any;
+
 //=== Internal Dependencies ===============================================
 
 use crate::core::globals::GlobalContext;
+use crate::core::input::InputContext;
 
 //=== Module Declarations =================================================
 
@@ -24,7 +30,26 @@ mod scene_manager;
 
 //=== Public API ==========================================================
 
-pub use scene_manager::{SceneKey, SceneManager, SceneTransition};
+pub use scene_manager::{
+    SceneHandle, SceneKey, SceneManager, SceneTransition, TransitionError, UpdateOrder,
+};
+
+//=== AsAnyMut ============================================================
+
+/// Type-erased mutable access, used to downcast boxed scenes.
+///
+/// Implemented automatically for every `'static` type; scenes never
+/// implement it by hand.
+pub trait AsAnyMut {
+    /// Downcasts to `&mut dyn Any` for type-specific operations.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAnyMut for T {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
 
 //=== Scene Trait =========================================================
 
@@ -50,25 +75,129 @@ pub use scene_manager::{SceneKey, SceneManager, SceneTransition};
 ///     }
 /// }
 /// ```
-pub trait Scene<S: SceneKey>: Send {
+///
+/// Scenes are `'static` and get [`AsAnyMut`] for free, which lets
+/// [`SceneManager::with_scene_mut`] hand back the concrete type.
+pub trait Scene<S: SceneKey>: Send + AsAnyMut {
     /// Called when scene enters the active stack.
     ///
     /// Default implementation does nothing. Override to initialize scene state.
-    fn on_enter(&mut self, _context: &GlobalContext) {}
+    ///
+    /// Return a transition to queue it for the next tick, e.g. a loading
+    /// scene moving on once setup completes. Return `None` otherwise.
+    fn on_enter(&mut self, _context: &GlobalContext) -> Option<SceneTransition<S>> {
+        None
+    }
 
     /// Called when scene exits the active stack.
     ///
     /// Default implementation does nothing. Override to cleanup scene state.
     fn on_exit(&mut self, _context: &GlobalContext) {}
 
+    /// Called when the scene stops being updated because a scene pushed or
+    /// moved above it blocks updates, while it stays on the stack.
+    ///
+    /// Default implementation does nothing. Override to suspend timers or
+    /// audio. See [`SceneManager::newly_deactivated`].
+    fn on_pause(&mut self, _context: &GlobalContext) {}
+
+    /// Called when a paused scene is updated again because the scene
+    /// covering it left or became transparent.
+    ///
+    /// Default implementation does nothing. See
+    /// [`SceneManager::newly_activated`].
+    fn on_resume(&mut self, _context: &GlobalContext) {}
+
     /// Called every tick while scene is active on stack.
     fn update(&mut self, context: &GlobalContext);
 
-    /// Whether scenes below this one should receive updates.
+    /// Called every tick after all updates and scene transitions.
+    ///
+    /// Runs for the active scenes of the post-transition stack, so a scene
+    /// pushed this tick gets `on_enter` and then `late_update` before its
+    /// first `update`. Use for logic that must see the settled frame
+    /// (camera follow, UI sync). Default implementation does nothing.
+    fn late_update(&mut self, _context: &GlobalContext) {}
+
+    /// Whether scenes below this one should receive updates and be drawn.
     ///
-    /// Transparent scenes (e.g., pause menus) allow underlying scenes
+    /// Transparent scenes (e.g., HUD overlays) allow underlying scenes
     /// to continue updating. Opaque scenes block updates to lower stack.
+    /// Provides the default for [`updates_below`](Self::updates_below) and
+    /// [`renders_below`](Self::renders_below).
     fn is_transparent(&self) -> bool {
         false
     }
+
+    /// Whether scenes below this one should receive updates.
+    ///
+    /// Defaults to [`is_transparent`](Self::is_transparent).
+    fn updates_below(&self) -> bool {
+        self.is_transparent()
+    }
+
+    /// Whether scenes below this one should be drawn.
+    ///
+    /// Defaults to [`is_transparent`](Self::is_transparent). A pause menu
+    /// returns `false` from `updates_below` and `true` here to freeze the
+    /// game while still showing it behind the menu.
+    fn renders_below(&self) -> bool {
+        self.is_transparent()
+    }
+
+    /// Whether this scene changed and the window should be redrawn.
+    ///
+    /// Checked each tick for visible scenes; the window is redrawn while any
+    /// of them returns `true`. A static menu can return `false` to let the
+    /// GPU idle. Defaults to `true`.
+    fn needs_redraw(&self) -> bool {
+        true
+    }
+
+    /// Input context to map with while this scene is the top of the stack.
+    ///
+    /// When the scene becomes top, the engine switches to this context
+    /// before the next tick's input mapping, and restores the context it
+    /// replaced once the scene is no longer top. Saves pairing
+    /// `set_input_context` calls in `on_enter`/`on_exit`. Defaults to `None`
+    /// (leave the context alone).
+    fn input_context(&self) -> Option<InputContext> {
+        None
+    }
+}
+
+//=== ConfiguredScene =====================================================
+
+/// A scene constructed from a config value by the scene manager.
+///
+/// Kept separate from [`Scene`] because an associated type there would make
+/// `dyn Scene<S>` require the config type at every use. Register with
+/// [`SceneManager::register_scene_with`].
+///
+/// ```rust
+/// # use aetheric_engine::prelude::*;
+/// # use aetheric_engine::core::scene::ConfiguredScene;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// # enum GameScene { Level }
+/// # impl SceneKey for GameScene {}
+/// struct LevelScene { enemies: u32 }
+///
+/// impl Scene<GameScene> for LevelScene {
+///     fn update(&mut self, _context: &GlobalContext) {}
+/// }
+///
+/// impl ConfiguredScene<GameScene> for LevelScene {
+///     type Config = u32;
+///
+///     fn from_config(enemies: u32) -> Self {
+///         Self { enemies }
+///     }
+/// }
+/// ```
+pub trait ConfiguredScene<S: SceneKey>: Scene<S> + Sized {
+    /// Construction parameters (difficulty, level id, ...).
+    type Config;
+
+    /// Builds the scene from its config.
+    fn from_config(config: Self::Config) -> Self;
 }
@@ -7,20 +7,25 @@
 // Scenes are stored in a HashMap by key and referenced via a stack
 // of keys. This allows scenes to maintain state between activations.
 //
+// Rejected transitions are logged and recorded as TransitionErrors, readable
+// via transition_errors() until the next process_transitions call.
+//
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use std::hash::Hash;
+use std::marker::PhantomData;
 
 use log::{debug, warn};
 
 //=== Internal Dependencies ===============================================
 
 use crate::core::globals::GlobalContext;
-use super::Scene;
+use crate::core::input::InputContext;
+use super::{ConfiguredScene, Scene};
 
 //=== Scene Transition ====================================================
 
@@ -28,30 +33,94 @@ use super::Scene;
 ///
 /// Scenes are managed via a stack-based system where transitions control
 /// the flow between different game states (menus, gameplay, pause, etc.).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Transitions are queued by pushing them onto
+/// [`GlobalContext::message_bus`]; this is the only transition path.
+/// [`SceneManager::process_transitions`] applies them at the end of each
+/// update, so a transition queued during tick N takes effect by the end of
+/// the next scene update and the affected scenes update from then on.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum SceneTransition<K: SceneKey> {
     /// Adds a new scene to the top of the stack.
     Push(K),
 
+    /// Inserts a scene directly beneath the current top scene.
+    ///
+    /// The inserted scene is revealed once the top scene is removed
+    /// (e.g., a level loaded behind a loading screen).
+    PushBelow(K),
+
+    /// Pushes several scenes in order; the last one ends up on top.
+    ///
+    /// Each scene receives `on_enter`. Duplicates and unregistered scenes are
+    /// skipped with a warning (e.g., `PushMany(vec![Scene::Hud, Scene::Dialog])`).
+    PushMany(Vec<K>),
+
     /// Removes a specific scene from the stack by key.
     Remove(K),
 
     /// Replaces a specific scene with another scene.
     Replace(K, K),
 
+    /// Exchanges the stack positions of two scenes already in the stack.
+    ///
+    /// No lifecycle hooks fire, including `on_pause`/`on_resume` for the two
+    /// scenes; the active set is recomputed on the next update.
+    Swap(K, K),
+
     /// Clears all scenes from the stack.
     Clear,
 
+    /// Clears the stack, then pushes a single scene (e.g., back to the title).
+    ///
+    /// Every scene in the stack receives `on_exit` before the new scene
+    /// receives `on_enter`. Ignored if the scene is not registered.
+    Reset(K),
+
     /// No transition occurs.
+    #[default]
     Empty,
 }
 
-impl<K: SceneKey> Default for SceneTransition<K> {
-    fn default() -> Self {
-        Self::Empty
+//=== Transition Error ====================================================
+
+/// Why a queued [`SceneTransition`] was (partly) skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionError<K: SceneKey> {
+    /// The scene was never registered with the manager.
+    Unregistered(K),
+
+    /// The scene is already in the stack.
+    AlreadyInStack(K),
+
+    /// The scene must be in the stack but is not (replace, swap).
+    NotInStack(K),
+
+    /// `PushBelow` needs a top scene, but the stack is empty.
+    EmptyStack(K),
+
+    /// The stack is at its [maximum depth](SceneManager::set_max_stack_depth).
+    StackFull(K),
+}
+
+impl<K: SceneKey> fmt::Display for TransitionError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unregistered(key) => write!(f, "Scene {:?} is not registered", key),
+            Self::AlreadyInStack(key) => write!(f, "Scene {:?} is already in the stack", key),
+            Self::NotInStack(key) => write!(f, "Scene {:?} is not in the stack", key),
+            Self::EmptyStack(key) => {
+                write!(f, "Cannot push scene {:?} below top of an empty stack", key)
+            }
+            Self::StackFull(key) => {
+                write!(f, "Cannot push scene {:?}: scene stack is at its depth limit", key)
+            }
+        }
     }
 }
 
+impl<K: SceneKey> std::error::Error for TransitionError<K> {}
+
 //=== Scene Key Trait =====================================================
 
 /// Marker trait for scene identifiers.
@@ -60,6 +129,45 @@ impl<K: SceneKey> Default for SceneTransition<K> {
 /// Typically implemented by game-specific enums.
 pub trait SceneKey: Clone + Copy + Eq + Hash + Debug + Send + 'static {}
 
+//=== Update Order ========================================================
+
+/// Direction in which [`SceneManager::update`] walks the active scenes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpdateOrder {
+    /// Lowest active scene first, topmost last.
+    BottomUp,
+
+    /// Topmost scene first, so overlays can consume input before gameplay.
+    #[default]
+    TopDown,
+}
+
+//=== Scene Handle ========================================================
+
+/// Typed token returned by [`SceneManager::register_scene`].
+///
+/// Remembers the scene's concrete type so it can be reconfigured later with
+/// [`SceneManager::with_handle_mut`] without naming the type again.
+pub struct SceneHandle<S: SceneKey, T> {
+    key: S,
+    _scene: PhantomData<fn() -> T>,
+}
+
+impl<S: SceneKey, T> SceneHandle<S, T> {
+    /// Returns the key the scene was registered under.
+    pub fn key(&self) -> S {
+        self.key
+    }
+}
+
+impl<S: SceneKey, T> Clone for SceneHandle<S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: SceneKey, T> Copy for SceneHandle<S, T> {}
+
 //=== Scene Manager =======================================================
 
 /// Manages scene lifecycle and stack-based scene switching.
@@ -71,6 +179,16 @@ pub trait SceneKey: Clone + Copy + Eq + Hash + Debug + Send + 'static {}
 pub struct SceneManager<S: SceneKey> {
     scenes: HashMap<S, Box<dyn Scene<S>>>,
     stack: Vec<S>,
+    update_order: UpdateOrder,
+    coalesce_transitions: bool,
+    max_stack_depth: Option<usize>,
+    transition_errors: Vec<TransitionError<S>>,
+    follow_ups: Vec<SceneTransition<S>>,
+    last_active: Vec<S>,
+    newly_activated: Vec<S>,
+    newly_deactivated: Vec<S>,
+    entered: Vec<S>,
+    swapped: Vec<S>,
 }
 
 impl<S: SceneKey> SceneManager<S> {
@@ -84,6 +202,16 @@ impl<S: SceneKey> SceneManager<S> {
         Self {
             scenes: HashMap::new(),
             stack: Vec::new(),
+            update_order: UpdateOrder::default(),
+            coalesce_transitions: false,
+            max_stack_depth: None,
+            transition_errors: Vec::new(),
+            follow_ups: Vec::new(),
+            last_active: Vec::new(),
+            newly_activated: Vec::new(),
+            newly_deactivated: Vec::new(),
+            entered: Vec::new(),
+            swapped: Vec::new(),
         }
     }
 
@@ -108,13 +236,23 @@ impl<S: SceneKey> SceneManager<S> {
     /// // Accessed via Engine::init
     /// // systems.scene_manager.register_scene(GameScene::Main, MainScene);
     /// ```
-    pub fn register_scene<T>(&mut self, key: S, scene: T)
+    pub fn register_scene<T>(&mut self, key: S, scene: T) -> SceneHandle<S, T>
     where
         T: Scene<S> + 'static,
     {
-        if self.scenes.insert(key, Box::new(scene)).is_some() {
-            warn!("Scene {:?} was already registered and has been replaced", key);
-        }
+        self.register_boxed(key, Box::new(scene));
+        SceneHandle { key, _scene: PhantomData }
+    }
+
+    /// Registers a scene built from `config` via [`ConfiguredScene::from_config`].
+    ///
+    /// Use [`register_scene`](Self::register_scene) for scenes constructed
+    /// directly.
+    pub fn register_scene_with<T>(&mut self, key: S, config: T::Config) -> SceneHandle<S, T>
+    where
+        T: ConfiguredScene<S> + 'static,
+    {
+        self.register_scene(key, T::from_config(config))
     }
 
     /// Registers a scene and immediately adds it to the stack as the default scene.
@@ -142,8 +280,20 @@ impl<S: SceneKey> SceneManager<S> {
     where
         T: Scene<S> + 'static,
     {
+        self.register_default_boxed(key, Box::new(scene));
+    }
+
+    /// Registers an already-boxed scene (used by `EngineBuilder`).
+    pub(crate) fn register_boxed(&mut self, key: S, scene: Box<dyn Scene<S>>) {
+        if self.scenes.insert(key, scene).is_some() {
+            warn!("Scene {:?} was already registered and has been replaced", key);
+        }
+    }
+
+    /// Registers an already-boxed scene and adds it to the stack.
+    pub(crate) fn register_default_boxed(&mut self, key: S, scene: Box<dyn Scene<S>>) {
         // Register the scene
-        self.register_scene(key, scene);
+        self.register_boxed(key, scene);
 
         // Add to stack if not already present
         if self.stack.contains(&key) {
@@ -155,24 +305,151 @@ impl<S: SceneKey> SceneManager<S> {
     }
 
     /// Initializes the scene manager by calling on_enter on the initial scene.
-    pub fn start(&mut self, context: &GlobalContext) {
+    ///
+    /// A transition returned by `on_enter` is queued on the message bus for
+    /// the first tick.
+    pub fn start(&mut self, context: &mut GlobalContext) {
         if let Some(&initial) = self.stack.first() {
             debug!("Starting scene manager with initial scene: {:?}", initial);
-            if let Some(scene) = self.scenes.get_mut(&initial) {
-                scene.on_enter(context);
+            if self.scenes.contains_key(&initial) {
+                self.enter(initial, context);
             } else {
                 warn!("Initial scene {:?} not registered", initial);
             }
         }
+        self.last_active = self.collect_active_scenes();
+        self.queue_follow_ups(context);
+    }
+
+    //--- Scene Access -----------------------------------------------------
+
+    /// Runs `f` on the registered scene `key`, downcast to its concrete type.
+    ///
+    /// Returns `None` if no scene is registered under `key` or it is not a `T`.
+    /// Useful for configuring a scene after registration (e.g., difficulty).
+    pub fn with_scene_mut<T, R>(&mut self, key: S, f: impl FnOnce(&mut T) -> R) -> Option<R>
+    where
+        T: Scene<S> + 'static,
+    {
+        let scene = self.scenes.get_mut(&key)?;
+        // Deref the box first: Box<dyn Scene> is itself `Any`
+        (**scene).as_any_mut().downcast_mut::<T>().map(f)
+    }
+
+    /// Like [`with_scene_mut`](Self::with_scene_mut), typed by a handle from
+    /// [`register_scene`](Self::register_scene).
+    pub fn with_handle_mut<T, R>(
+        &mut self,
+        handle: SceneHandle<S, T>,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Option<R>
+    where
+        T: Scene<S> + 'static,
+    {
+        self.with_scene_mut(handle.key, f)
+    }
+
+    /// Runs `f` on each active scene, bottom to top.
+    ///
+    /// Active scenes are the ones [`update`](Self::update) would update this
+    /// tick. Useful for debug overlays or broadcasting a command to the stack.
+    pub fn for_each_active<F>(&mut self, mut f: F)
+    where
+        F: FnMut(S, &mut dyn Scene<S>),
+    {
+        for key in self.collect_active_scenes() {
+            if let Some(scene) = self.scenes.get_mut(&key) {
+                f(key, scene.as_mut());
+            }
+        }
+    }
+
+    //--- Queries ----------------------------------------------------------
+
+    /// Returns the scenes that should be drawn this tick, bottom to top.
+    ///
+    /// Walks down from the top of the stack until a scene whose
+    /// [`renders_below`](Scene::renders_below) is `false`.
+    pub fn visible_scenes(&self) -> Vec<S> {
+        self.collect_scenes(|scene| scene.renders_below())
+    }
+
+    /// Returns the registered scene's [`is_transparent`](Scene::is_transparent),
+    /// or `None` if `key` is not registered.
+    ///
+    /// Works whether or not the scene is in the stack.
+    pub fn is_scene_transparent(&self, key: S) -> Option<bool> {
+        self.scenes.get(&key).map(|scene| scene.is_transparent())
+    }
+
+    /// Returns the scene at the top of the stack, if any.
+    pub fn top(&self) -> Option<S> {
+        self.stack.last().copied()
+    }
+
+    /// Returns the input context the registered scene `key` declares via
+    /// [`Scene::input_context`], or `None` if it declares none or is not
+    /// registered.
+    pub fn scene_input_context(&self, key: S) -> Option<InputContext> {
+        self.scenes.get(&key).and_then(|scene| scene.input_context())
+    }
+
+    /// Returns the keys of all registered scenes, active or not, in no
+    /// particular order.
+    ///
+    /// For debug tooling and startup validation (e.g. checking that every
+    /// key a transition may reference is registered).
+    pub fn registered_keys(&self) -> impl Iterator<Item = S> + '_ {
+        self.scenes.keys().copied()
+    }
+
+    /// Returns the scenes that became active in the last
+    /// [`process_transitions`](Self::process_transitions) call, bottom to top.
+    ///
+    /// Includes scenes pushed this tick as well as uncovered ones; only the
+    /// latter get [`on_resume`](Scene::on_resume).
+    pub fn newly_activated(&self) -> &[S] {
+        &self.newly_activated
+    }
+
+    /// Returns the scenes that stopped being active in the last
+    /// [`process_transitions`](Self::process_transitions) call, bottom to top.
+    ///
+    /// Includes scenes removed this tick as well as covered ones; only the
+    /// latter get [`on_pause`](Scene::on_pause).
+    pub fn newly_deactivated(&self) -> &[S] {
+        &self.newly_deactivated
     }
 
     //--- Update Loop ------------------------------------------------------
 
+    /// Sets the direction in which active scenes are updated.
+    ///
+    /// Input consumption only reaches lower scenes with
+    /// [`UpdateOrder::TopDown`] (the default).
+    pub fn set_update_order(&mut self, order: UpdateOrder) {
+        self.update_order = order;
+    }
+
+    /// Returns the direction in which active scenes are updated.
+    pub fn update_order(&self) -> UpdateOrder {
+        self.update_order
+    }
+
     /// Updates active scenes.
     ///
-    /// Calls update on all transparent scenes and the topmost opaque scene.
+    /// Walks down from the top of the stack, updating each scene until one
+    /// whose [`updates_below`](Scene::updates_below) is `false`.
+    ///
+    /// Scenes are updated top-down (topmost first) by default, so overlays
+    /// can call [`GlobalContext::consume_input`] before lower scenes run.
+    /// See [`set_update_order`](Self::set_update_order).
+    ///
+    /// Afterwards, [`GlobalContext::needs_redraw`] reflects whether any
+    /// visible scene reported [`needs_redraw`](Scene::needs_redraw).
     pub fn update(&mut self, context: &GlobalContext) {
         if self.stack.is_empty() {
+            context.redraw_needed.set(true);
             return;
         }
 
@@ -181,6 +458,31 @@ impl<S: SceneKey> SceneManager<S> {
 
         // Update all active scenes
         self.update_scenes(&scenes_to_update, context);
+
+        // Aggregate redraw requests from visible scenes
+        let needs_redraw = self.visible_scenes().iter().any(|key| {
+            self.scenes.get(key).is_some_and(|scene| scene.needs_redraw())
+        });
+        context.redraw_needed.set(needs_redraw);
+    }
+
+    /// Calls [`Scene::late_update`] on the active scenes.
+    ///
+    /// Call after [`process_transitions`](Self::process_transitions) so the
+    /// active set reflects this tick's transitions. Walks scenes in the same
+    /// [`UpdateOrder`] as [`update`](Self::update).
+    pub fn late_update(&mut self, context: &GlobalContext) {
+        let active = self.collect_active_scenes();
+        let mut late_update = |key: &S| {
+            if let Some(scene) = self.scenes.get_mut(key) {
+                scene.late_update(context);
+            }
+        };
+
+        match self.update_order {
+            UpdateOrder::BottomUp => active.iter().for_each(&mut late_update),
+            UpdateOrder::TopDown => active.iter().rev().for_each(&mut late_update),
+        }
     }
 
     //--- Transition Processing --------------------------------------------
@@ -190,45 +492,244 @@ impl<S: SceneKey> SceneManager<S> {
     /// Should be called at the tick boundary after scene updates.
     /// Transitions are processed in FIFO order, with appropriate lifecycle
     /// callbacks (on_enter/on_exit) invoked for affected scenes.
+    ///
+    /// # Ordering
+    ///
+    /// Each transition applies to the stack left by the one before it, so
+    /// `[Push(A), Replace(A, B), Push(C)]` on an empty stack yields `[B, C]`.
+    /// A rejected transition leaves the stack as it was and the rest of the
+    /// queue still applies. Queue order is the order transitions were pushed
+    /// to the message bus: follow-ups from the previous call first, then
+    /// those pushed by scenes in [update order](Self::set_update_order).
+    ///
+    /// Rejected transitions are recorded in
+    /// [`transition_errors`](Self::transition_errors). Transitions returned
+    /// by `on_enter` are queued for the next call, not processed in this one.
+    ///
+    /// With [coalescing](Self::set_transition_coalescing) enabled, a `Push(k)`
+    /// immediately followed by `Remove(k)` is dropped without lifecycle calls.
+    ///
+    /// Afterwards, scenes that stayed on the stack but were covered or
+    /// uncovered get [`on_pause`](Scene::on_pause) or
+    /// [`on_resume`](Scene::on_resume).
     pub fn process_transitions(&mut self, context: &mut GlobalContext) {
+        self.transition_errors.clear();
+        self.entered.clear();
+        self.swapped.clear();
+
         // Read all scene transitions from message bus
-        for transition in context.message_bus.read::<SceneTransition<S>>() {
-            match transition {
+        let transitions = context.message_bus.read::<SceneTransition<S>>();
+        let mut index = 0;
+        while index < transitions.len() {
+            if self.coalesce_transitions && self.cancels_out(&transitions[index..]) {
+                debug!("Coalesced push/remove pair {:?}", &transitions[index..index + 2]);
+                index += 2;
+                continue;
+            }
+
+            match &transitions[index] {
                 SceneTransition::Push(key) => self.push_internal(*key, context),
+                SceneTransition::PushBelow(key) => self.push_below_internal(*key, context),
+                SceneTransition::PushMany(keys) => {
+                    for &key in keys.iter() {
+                        self.push_internal(key, context);
+                    }
+                }
                 SceneTransition::Remove(key) => self.remove_internal(*key, context),
                 SceneTransition::Replace(old_key, new_key) => {
                     self.replace_internal(*old_key, *new_key, context)
                 }
+                SceneTransition::Swap(a, b) => self.swap_internal(*a, *b),
                 SceneTransition::Clear => self.clear_internal(context),
+                SceneTransition::Reset(key) => self.reset_internal(*key, context),
                 SceneTransition::Empty => {}
             }
+            index += 1;
         }
 
         // Clear processed transitions
         context.message_bus.clear::<SceneTransition<S>>();
+
+        // Pause covered scenes, resume uncovered ones
+        self.track_activity(context);
+
+        // Queue transitions returned by on_enter for the next tick
+        self.queue_follow_ups(context);
+    }
+
+    /// Returns the transitions rejected by the last
+    /// [`process_transitions`](Self::process_transitions) call, in order.
+    pub fn transition_errors(&self) -> &[TransitionError<S>] {
+        &self.transition_errors
+    }
+
+    /// Enables or disables push/remove coalescing (off by default).
+    ///
+    /// When enabled, a `Push(k)` directly followed by `Remove(k)` in the same
+    /// batch cancels out: neither `on_enter` nor `on_exit` fires. Only
+    /// adjacent pairs cancel, so a pair interleaved with other transitions is
+    /// processed normally. Pairs that would not have pushed (`k` already in
+    /// the stack or unregistered) are never coalesced.
+    pub fn set_transition_coalescing(&mut self, enabled: bool) {
+        self.coalesce_transitions = enabled;
+    }
+
+    /// Returns `true` if push/remove coalescing is enabled.
+    pub fn transition_coalescing(&self) -> bool {
+        self.coalesce_transitions
+    }
+
+    /// Caps the number of scenes on the stack (`None` = unlimited, the default).
+    ///
+    /// Pushes at the cap are rejected with [`TransitionError::StackFull`]
+    /// and a warning, so a runaway push loop surfaces early instead of
+    /// growing the stack. Scenes already on the stack are kept.
+    pub fn set_max_stack_depth(&mut self, depth: Option<usize>) {
+        self.max_stack_depth = depth;
+    }
+
+    /// Returns the stack depth cap, if any.
+    pub fn max_stack_depth(&self) -> Option<usize> {
+        self.max_stack_depth
     }
 
     //--- Internal Helpers -------------------------------------------------
 
+    /// Returns `true` if `pending` starts with a push that its next
+    /// transition immediately undoes.
+    fn cancels_out(&self, pending: &[SceneTransition<S>]) -> bool {
+        match pending {
+            [SceneTransition::Push(pushed), SceneTransition::Remove(removed), ..] => {
+                pushed == removed
+                    && !self.stack.contains(pushed)
+                    && self.scenes.contains_key(pushed)
+                    && !self.at_depth_limit()
+            }
+            _ => false,
+        }
+    }
+
+    /// Diffs the active set against the last call's, then pauses or resumes
+    /// the flipped scenes that neither entered nor left the stack this tick.
+    ///
+    /// Scenes moved by a `Swap` this tick are left alone.
+    fn track_activity(&mut self, context: &GlobalContext) {
+        let active = self.collect_active_scenes();
+        let last_active = std::mem::replace(&mut self.last_active, active);
+        self.newly_activated =
+            self.last_active.iter().filter(|key| !last_active.contains(key)).copied().collect();
+        self.newly_deactivated =
+            last_active.iter().filter(|key| !self.last_active.contains(key)).copied().collect();
+
+        for &key in &self.newly_deactivated {
+            if self.stack.contains(&key)
+                && !self.entered.contains(&key)
+                && !self.swapped.contains(&key)
+            {
+                if let Some(scene) = self.scenes.get_mut(&key) {
+                    scene.on_pause(context);
+                }
+            }
+        }
+        for &key in &self.newly_activated {
+            if !self.entered.contains(&key) && !self.swapped.contains(&key) {
+                if let Some(scene) = self.scenes.get_mut(&key) {
+                    scene.on_resume(context);
+                }
+            }
+        }
+    }
+
+    /// Calls `on_enter` on `key`, keeping any follow-up transition it returns.
+    fn enter(&mut self, key: S, context: &GlobalContext) {
+        self.entered.push(key);
+        if let Some(scene) = self.scenes.get_mut(&key) {
+            if let Some(transition) = scene.on_enter(context) {
+                debug!("Scene {:?} queued follow-up transition {:?}", key, transition);
+                self.follow_ups.push(transition);
+            }
+        }
+    }
+
+    /// Moves follow-up transitions onto the message bus.
+    fn queue_follow_ups(&mut self, context: &mut GlobalContext) {
+        for transition in self.follow_ups.drain(..) {
+            context.message_bus.push(transition);
+        }
+    }
+
+    /// Returns `true` if the stack is at its depth cap.
+    fn at_depth_limit(&self) -> bool {
+        self.max_stack_depth.is_some_and(|max| self.stack.len() >= max)
+    }
+
+    /// Rejects a push of `key` if the stack is at its depth cap.
+    fn reject_if_full(&mut self, key: S) -> bool {
+        if !self.at_depth_limit() {
+            return false;
+        }
+        warn!(
+            "Scene stack at depth limit ({}), rejecting push of {:?}",
+            self.stack.len(),
+            key
+        );
+        self.transition_errors.push(TransitionError::StackFull(key));
+        true
+    }
+
     fn push_internal(&mut self, key: S, context: &GlobalContext) {
         // Check if scene is already in the stack
         if self.stack.contains(&key) {
             warn!("Scene {:?} is already in the stack, skipping push", key);
+            self.transition_errors.push(TransitionError::AlreadyInStack(key));
             return;
         }
 
         // Check if scene is registered
         if !self.scenes.contains_key(&key) {
             warn!("Attempted to push unregistered scene {:?}", key);
+            self.transition_errors.push(TransitionError::Unregistered(key));
+            return;
+        }
+
+        if self.reject_if_full(key) {
             return;
         }
 
         debug!("Pushing scene {:?} onto stack", key);
         self.stack.push(key);
 
-        if let Some(scene) = self.scenes.get_mut(&key) {
-            scene.on_enter(context);
+        self.enter(key, context);
+    }
+
+    fn push_below_internal(&mut self, key: S, context: &GlobalContext) {
+        if self.stack.is_empty() {
+            warn!("Cannot push scene {:?} below top of an empty stack", key);
+            self.transition_errors.push(TransitionError::EmptyStack(key));
+            return;
+        }
+
+        if self.stack.contains(&key) {
+            warn!("Scene {:?} is already in the stack, skipping push below", key);
+            self.transition_errors.push(TransitionError::AlreadyInStack(key));
+            return;
+        }
+
+        if !self.scenes.contains_key(&key) {
+            warn!("Attempted to push unregistered scene {:?} below top", key);
+            self.transition_errors.push(TransitionError::Unregistered(key));
+            return;
+        }
+
+        if self.reject_if_full(key) {
+            return;
         }
+
+        let pos = self.stack.len() - 1;
+        debug!("Pushing scene {:?} below top at position {}", key, pos);
+        self.stack.insert(pos, key);
+
+        self.enter(key, context);
     }
 
     fn remove_internal(&mut self, key: S, context: &GlobalContext) {
@@ -248,18 +749,21 @@ impl<S: SceneKey> SceneManager<S> {
         // Check if old scene exists in stack
         let Some(pos) = self.stack.iter().position(|&k| k == old_key) else {
             warn!("Scene {:?} not found in stack, skipping replacement", old_key);
+            self.transition_errors.push(TransitionError::NotInStack(old_key));
             return;
         };
 
         // Check if new scene is already in the stack
         if self.stack.contains(&new_key) {
             warn!("Scene {:?} is already in the stack, skipping replacement", new_key);
+            self.transition_errors.push(TransitionError::AlreadyInStack(new_key));
             return;
         }
 
         // Check if new scene is registered
         if !self.scenes.contains_key(&new_key) {
             warn!("Attempted to replace with unregistered scene {:?}", new_key);
+            self.transition_errors.push(TransitionError::Unregistered(new_key));
             return;
         }
 
@@ -274,9 +778,23 @@ impl<S: SceneKey> SceneManager<S> {
         self.stack[pos] = new_key;
 
         // Call on_enter for new scene
-        if let Some(scene) = self.scenes.get_mut(&new_key) {
-            scene.on_enter(context);
-        }
+        self.enter(new_key, context);
+    }
+
+    fn swap_internal(&mut self, a: S, b: S) {
+        let pos_a = self.stack.iter().position(|&k| k == a);
+        let pos_b = self.stack.iter().position(|&k| k == b);
+
+        let (Some(pos_a), Some(pos_b)) = (pos_a, pos_b) else {
+            warn!("Cannot swap {:?} and {:?}: both must be in the stack", a, b);
+            let missing = if pos_a.is_none() { a } else { b };
+            self.transition_errors.push(TransitionError::NotInStack(missing));
+            return;
+        };
+
+        debug!("Swapping scenes {:?} (position {}) and {:?} (position {})", a, pos_a, b, pos_b);
+        self.stack.swap(pos_a, pos_b);
+        self.swapped.extend([a, b]);
     }
 
     fn clear_internal(&mut self, context: &GlobalContext) {
@@ -292,21 +810,38 @@ impl<S: SceneKey> SceneManager<S> {
         self.stack.clear();
     }
 
+    fn reset_internal(&mut self, key: S, context: &GlobalContext) {
+        if !self.scenes.contains_key(&key) {
+            warn!("Attempted to reset to unregistered scene {:?}", key);
+            self.transition_errors.push(TransitionError::Unregistered(key));
+            return;
+        }
+
+        debug!("Resetting scene stack to {:?}", key);
+        self.clear_internal(context);
+        self.push_internal(key, context);
+    }
+
     fn collect_active_scenes(&self) -> Vec<S> {
-        let mut active = Vec::new();
+        self.collect_scenes(|scene| scene.updates_below())
+    }
 
-        // Iterate stack top-down, stop at first opaque scene
+    /// Collects scenes top-down until `passes_below` returns false.
+    fn collect_scenes(&self, passes_below: impl Fn(&dyn Scene<S>) -> bool) -> Vec<S> {
+        let mut collected = Vec::new();
+
+        // Iterate stack top-down, stop at first blocking scene
         for &key in self.stack.iter().rev() {
-            active.insert(0, key);
+            collected.insert(0, key);
 
             if let Some(scene) = self.scenes.get(&key) {
-                if !scene.is_transparent() {
+                if !passes_below(scene.as_ref()) {
                     break;
                 }
             }
         }
 
-        active
+        collected
     }
 
     fn update_scenes(
@@ -314,20 +849,35 @@ impl<S: SceneKey> SceneManager<S> {
         scenes_to_update: &[S],
         context: &GlobalContext,
     ) {
-        // Update all active scenes
-        for &key in scenes_to_update {
-            if let Some(scene) = self.scenes.get_mut(&key) {
+        let mut update = |key: &S| {
+            if let Some(scene) = self.scenes.get_mut(key) {
                 scene.update(context);
             }
+        };
+
+        // `scenes_to_update` is bottom-to-top
+        match self.update_order {
+            UpdateOrder::BottomUp => scenes_to_update.iter().for_each(&mut update),
+            UpdateOrder::TopDown => scenes_to_update.iter().rev().for_each(&mut update),
         }
     }
 }
 
+//--- Trait Implementations -----------------------------------------------
+
+impl<S: SceneKey> Default for SceneManager<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 //=== Tests ===============================================================
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
 
     // Mock types for testing
     #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
@@ -348,19 +898,960 @@ mod tests {
     }
 
     #[test]
-    fn transition_is_copy_and_eq() {
+    fn transition_is_clone_and_eq() {
         let t1 = SceneTransition::Push(TestScene::A);
-        let t2 = t1;
+        let t2 = t1.clone();
         assert_eq!(t1, t2);
 
         let t3 = SceneTransition::Remove(TestScene::B);
-        let t4 = t3;
+        let t4 = t3.clone();
         assert_eq!(t3, t4);
 
         let t5 = SceneTransition::Replace(TestScene::A, TestScene::B);
-        let t6 = t5;
+        let t6 = t5.clone();
         assert_eq!(t5, t6);
     }
 
-    // TODO: Add SceneManager tests when Scene trait is available
+    //--- Stack Layering Tests ---------------------------------------------
+
+    struct LayerScene {
+        updates_below: bool,
+        renders_below: bool,
+    }
+
+    impl LayerScene {
+        fn opaque() -> Self {
+            Self { updates_below: false, renders_below: false }
+        }
+
+        fn pause_menu() -> Self {
+            Self { updates_below: false, renders_below: true }
+        }
+
+        fn background_sim() -> Self {
+            Self { updates_below: true, renders_below: false }
+        }
+    }
+
+    impl Scene<TestScene> for LayerScene {
+        fn update(&mut self, _context: &GlobalContext) {}
+
+        fn updates_below(&self) -> bool {
+            self.updates_below
+        }
+
+        fn renders_below(&self) -> bool {
+            self.renders_below
+        }
+    }
+
+    struct TransparentScene;
+
+    impl Scene<TestScene> for TransparentScene {
+        fn update(&mut self, _context: &GlobalContext) {}
+
+        fn is_transparent(&self) -> bool {
+            true
+        }
+    }
+
+    fn stacked(top: LayerScene) -> SceneManager<TestScene> {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, LayerScene::opaque());
+        manager.register_default(TestScene::C, top);
+        manager
+    }
+
+    #[test]
+    fn pause_menu_blocks_updates_but_renders_below() {
+        let manager = stacked(LayerScene::pause_menu());
+
+        assert_eq!(manager.collect_active_scenes(), vec![TestScene::C]);
+        assert_eq!(manager.visible_scenes(), vec![TestScene::B, TestScene::C]);
+    }
+
+    #[test]
+    fn updates_below_independent_of_render_flag() {
+        let manager = stacked(LayerScene::background_sim());
+
+        assert_eq!(manager.collect_active_scenes(), vec![TestScene::B, TestScene::C]);
+        assert_eq!(manager.visible_scenes(), vec![TestScene::C]);
+    }
+
+    #[test]
+    fn layering_defaults_follow_is_transparent() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, TransparentScene);
+
+        assert_eq!(manager.collect_active_scenes(), vec![TestScene::A, TestScene::B]);
+        assert_eq!(manager.visible_scenes(), vec![TestScene::A, TestScene::B]);
+    }
+
+    #[test]
+    fn is_scene_transparent_reports_registered_scenes() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, TransparentScene);
+
+        assert_eq!(manager.is_scene_transparent(TestScene::A), Some(false));
+        assert_eq!(manager.is_scene_transparent(TestScene::B), Some(true));
+        assert_eq!(manager.is_scene_transparent(TestScene::C), None);
+    }
+
+    //--- PushBelow Tests --------------------------------------------------
+
+    #[derive(Default)]
+    struct Counters {
+        enters: AtomicUsize,
+        exits: AtomicUsize,
+        updates: AtomicUsize,
+        pauses: AtomicUsize,
+        resumes: AtomicUsize,
+    }
+
+    struct CountingScene(Arc<Counters>);
+
+    impl Scene<TestScene> for CountingScene {
+        fn on_enter(&mut self, _context: &GlobalContext) -> Option<SceneTransition<TestScene>> {
+            self.0.enters.fetch_add(1, Ordering::SeqCst);
+            None
+        }
+
+        fn on_exit(&mut self, _context: &GlobalContext) {
+            self.0.exits.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn update(&mut self, _context: &GlobalContext) {
+            self.0.updates.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_pause(&mut self, _context: &GlobalContext) {
+            self.0.pauses.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_resume(&mut self, _context: &GlobalContext) {
+            self.0.resumes.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn apply(
+        manager: &mut SceneManager<TestScene>,
+        transition: SceneTransition<TestScene>,
+    ) -> GlobalContext {
+        let mut context = GlobalContext::new();
+        context.message_bus.push(transition);
+        manager.process_transitions(&mut context);
+        context
+    }
+
+    #[test]
+    fn push_below_inserts_beneath_top() {
+        let below = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, LayerScene::opaque());
+        manager.register_scene(TestScene::C, CountingScene(Arc::clone(&below)));
+
+        apply(&mut manager, SceneTransition::PushBelow(TestScene::C));
+
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::C, TestScene::B]);
+        assert_eq!(below.enters.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn push_below_keeps_top_scene_updating() {
+        let top = Arc::new(Counters::default());
+        let below = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, CountingScene(Arc::clone(&top)));
+        manager.register_scene(TestScene::B, CountingScene(Arc::clone(&below)));
+
+        let context = apply(&mut manager, SceneTransition::PushBelow(TestScene::B));
+        manager.update(&context);
+
+        assert_eq!(manager.stack, vec![TestScene::B, TestScene::A]);
+        assert_eq!(top.updates.load(Ordering::SeqCst), 1);
+        assert_eq!(below.updates.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn push_below_on_empty_stack_is_noop() {
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::A, LayerScene::opaque());
+
+        apply(&mut manager, SceneTransition::PushBelow(TestScene::A));
+
+        assert!(manager.stack.is_empty());
+    }
+
+    #[test]
+    fn push_below_rejects_unregistered_and_duplicate_scenes() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, LayerScene::opaque());
+
+        apply(&mut manager, SceneTransition::PushBelow(TestScene::C));
+        apply(&mut manager, SceneTransition::PushBelow(TestScene::A));
+
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::B]);
+    }
+
+    //--- Swap Tests -------------------------------------------------------
+
+    #[test]
+    fn swap_exchanges_stack_positions() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, LayerScene::opaque());
+        manager.register_default(TestScene::C, LayerScene::opaque());
+
+        apply(&mut manager, SceneTransition::Swap(TestScene::A, TestScene::C));
+
+        assert_eq!(manager.stack, vec![TestScene::C, TestScene::B, TestScene::A]);
+        assert_eq!(manager.collect_active_scenes(), vec![TestScene::A]);
+    }
+
+    #[test]
+    fn swap_fires_no_lifecycle_hooks() {
+        let counters = Arc::new(Counters::default());
+        let covering = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, CountingScene(Arc::clone(&counters)));
+        manager.register_default(TestScene::B, CountingScene(Arc::clone(&covering)));
+
+        let context = apply(&mut manager, SceneTransition::Swap(TestScene::A, TestScene::B));
+        manager.update(&context);
+
+        assert_eq!(manager.stack, vec![TestScene::B, TestScene::A]);
+        assert_eq!(counters.enters.load(Ordering::SeqCst), 0);
+        assert_eq!(counters.updates.load(Ordering::SeqCst), 1);
+        for scene in [&counters, &covering] {
+            assert_eq!(scene.pauses.load(Ordering::SeqCst), 0);
+            assert_eq!(scene.resumes.load(Ordering::SeqCst), 0);
+        }
+    }
+
+    #[test]
+    fn swap_with_missing_scene_is_noop() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, LayerScene::opaque());
+        manager.register_scene(TestScene::C, LayerScene::opaque());
+
+        apply(&mut manager, SceneTransition::Swap(TestScene::A, TestScene::C));
+
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::B]);
+    }
+
+    //--- Input Consumption Tests ------------------------------------------
+
+    struct ConsumingOverlay;
+
+    impl Scene<TestScene> for ConsumingOverlay {
+        fn update(&mut self, context: &GlobalContext) {
+            context.consume_input();
+        }
+
+        fn is_transparent(&self) -> bool {
+            true
+        }
+    }
+
+    struct ObservingScene(Arc<AtomicUsize>);
+
+    impl Scene<TestScene> for ObservingScene {
+        fn update(&mut self, context: &GlobalContext) {
+            if context.is_input_consumed() {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        fn is_transparent(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn overlay_consumes_input_before_lower_scene() {
+        let consumed_seen = Arc::new(AtomicUsize::new(0));
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, ObservingScene(Arc::clone(&consumed_seen)));
+        manager.register_default(TestScene::B, ConsumingOverlay);
+        let context = GlobalContext::new();
+
+        manager.update(&context);
+
+        assert_eq!(consumed_seen.load(Ordering::SeqCst), 1);
+        assert!(context.is_input_consumed());
+    }
+
+    #[test]
+    fn scene_above_consumer_does_not_see_consumption() {
+        let consumed_seen = Arc::new(AtomicUsize::new(0));
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, ConsumingOverlay);
+        manager.register_default(TestScene::B, ObservingScene(Arc::clone(&consumed_seen)));
+        let context = GlobalContext::new();
+
+        manager.update(&context);
+
+        assert_eq!(consumed_seen.load(Ordering::SeqCst), 0);
+    }
+
+    //--- Update Order Tests -----------------------------------------------
+
+    struct OrderScene(TestScene, Arc<Mutex<Vec<TestScene>>>);
+
+    impl Scene<TestScene> for OrderScene {
+        fn update(&mut self, _context: &GlobalContext) {
+            self.1.lock().unwrap().push(self.0);
+        }
+
+        fn is_transparent(&self) -> bool {
+            true
+        }
+    }
+
+    fn update_order_of(order: UpdateOrder) -> Vec<TestScene> {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = SceneManager::new();
+        for key in [TestScene::A, TestScene::B, TestScene::C] {
+            manager.register_default(key, OrderScene(key, Arc::clone(&log)));
+        }
+        manager.set_update_order(order);
+
+        manager.update(&GlobalContext::new());
+
+        let order = log.lock().unwrap().clone();
+        order
+    }
+
+    #[test]
+    fn update_order_defaults_to_top_down() {
+        assert_eq!(SceneManager::<TestScene>::new().update_order(), UpdateOrder::TopDown);
+    }
+
+    #[test]
+    fn top_down_updates_topmost_first() {
+        assert_eq!(
+            update_order_of(UpdateOrder::TopDown),
+            vec![TestScene::C, TestScene::B, TestScene::A]
+        );
+    }
+
+    #[test]
+    fn bottom_up_updates_lowest_first() {
+        assert_eq!(
+            update_order_of(UpdateOrder::BottomUp),
+            vec![TestScene::A, TestScene::B, TestScene::C]
+        );
+    }
+
+    //--- Reset Tests ------------------------------------------------------
+
+    /// Records lifecycle hooks as `"<scene> enter"` / `"<scene> exit"`.
+    struct LoggingScene(TestScene, Arc<Mutex<Vec<String>>>);
+
+    impl Scene<TestScene> for LoggingScene {
+        fn on_enter(&mut self, _context: &GlobalContext) -> Option<SceneTransition<TestScene>> {
+            self.1.lock().unwrap().push(format!("{:?} enter", self.0));
+            None
+        }
+
+        fn on_exit(&mut self, _context: &GlobalContext) {
+            self.1.lock().unwrap().push(format!("{:?} exit", self.0));
+        }
+
+        fn update(&mut self, _context: &GlobalContext) {}
+    }
+
+    #[test]
+    fn reset_exits_all_scenes_then_enters_target_once() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LoggingScene(TestScene::A, Arc::clone(&log)));
+        manager.register_default(TestScene::B, LoggingScene(TestScene::B, Arc::clone(&log)));
+        manager.register_scene(TestScene::C, LoggingScene(TestScene::C, Arc::clone(&log)));
+
+        apply(&mut manager, SceneTransition::Reset(TestScene::C));
+
+        assert_eq!(manager.stack, vec![TestScene::C]);
+        assert_eq!(*log.lock().unwrap(), vec!["A exit", "B exit", "C enter"]);
+    }
+
+    #[test]
+    fn reset_to_scene_already_in_stack_reenters_it() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, CountingScene(Arc::clone(&counters)));
+        manager.register_default(TestScene::B, LayerScene::opaque());
+
+        apply(&mut manager, SceneTransition::Reset(TestScene::A));
+
+        assert_eq!(manager.stack, vec![TestScene::A]);
+        assert_eq!(counters.exits.load(Ordering::SeqCst), 1);
+        assert_eq!(counters.enters.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn reset_to_unregistered_scene_is_noop() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, CountingScene(Arc::clone(&counters)));
+
+        apply(&mut manager, SceneTransition::Reset(TestScene::C));
+
+        assert_eq!(manager.stack, vec![TestScene::A]);
+        assert_eq!(counters.exits.load(Ordering::SeqCst), 0);
+    }
+
+    //--- Transition Error Tests -------------------------------------------
+
+    #[test]
+    fn pushing_unregistered_scene_records_error() {
+        let mut manager = SceneManager::new();
+
+        apply(&mut manager, SceneTransition::Push(TestScene::A));
+
+        assert_eq!(manager.transition_errors(), &[TransitionError::Unregistered(TestScene::A)]);
+        assert!(manager.stack.is_empty());
+    }
+
+    #[test]
+    fn errors_are_recorded_in_order_and_reset_per_call() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, LayerScene::opaque());
+
+        let mut context = GlobalContext::new();
+        context.message_bus.push(SceneTransition::Push(TestScene::A));
+        context.message_bus.push(SceneTransition::Swap(TestScene::A, TestScene::B));
+        context.message_bus.push(SceneTransition::Push(TestScene::B));
+        manager.process_transitions(&mut context);
+
+        assert_eq!(manager.transition_errors(), &[
+            TransitionError::AlreadyInStack(TestScene::A),
+            TransitionError::NotInStack(TestScene::B),
+        ]);
+
+        manager.process_transitions(&mut context);
+        assert!(manager.transition_errors().is_empty());
+    }
+
+    //--- PushMany Tests ---------------------------------------------------
+
+    #[test]
+    fn push_many_pushes_in_order_with_last_on_top() {
+        let counters: Vec<_> = (0..3).map(|_| Arc::new(Counters::default())).collect();
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::A, CountingScene(Arc::clone(&counters[0])));
+        manager.register_scene(TestScene::B, CountingScene(Arc::clone(&counters[1])));
+        manager.register_scene(TestScene::C, CountingScene(Arc::clone(&counters[2])));
+
+        apply(
+            &mut manager,
+            SceneTransition::PushMany(vec![TestScene::A, TestScene::B, TestScene::C]),
+        );
+
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::B, TestScene::C]);
+        for counter in &counters {
+            assert_eq!(counter.enters.load(Ordering::SeqCst), 1);
+        }
+    }
+
+    #[test]
+    fn push_many_skips_duplicates_and_unregistered() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, CountingScene(Arc::clone(&counters)));
+
+        apply(
+            &mut manager,
+            SceneTransition::PushMany(vec![TestScene::A, TestScene::B, TestScene::C, TestScene::B]),
+        );
+
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::B]);
+        assert_eq!(counters.enters.load(Ordering::SeqCst), 1);
+    }
+
+    //--- Redraw Tests -----------------------------------------------------
+
+    struct StaticScene {
+        transparent: bool,
+    }
+
+    impl Scene<TestScene> for StaticScene {
+        fn update(&mut self, _context: &GlobalContext) {}
+
+        fn is_transparent(&self) -> bool {
+            self.transparent
+        }
+
+        fn needs_redraw(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn static_scene_produces_clean_frame() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, StaticScene { transparent: false });
+        let context = GlobalContext::new();
+
+        manager.update(&context);
+
+        assert!(!context.needs_redraw());
+    }
+
+    #[test]
+    fn any_visible_dirty_scene_forces_redraw() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, StaticScene { transparent: true });
+        let context = GlobalContext::new();
+
+        manager.update(&context);
+
+        assert!(context.needs_redraw());
+    }
+
+    #[test]
+    fn hidden_dirty_scene_does_not_force_redraw() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_default(TestScene::B, StaticScene { transparent: false });
+        let context = GlobalContext::new();
+
+        manager.update(&context);
+
+        assert!(!context.needs_redraw());
+    }
+
+    #[test]
+    fn empty_stack_needs_redraw() {
+        let mut manager = SceneManager::<TestScene>::new();
+        let context = GlobalContext::new();
+        context.redraw_needed.set(false);
+
+        manager.update(&context);
+
+        assert!(context.needs_redraw());
+    }
+
+    //--- Scene Access Tests -----------------------------------------------
+
+    #[test]
+    fn with_scene_mut_downcasts_to_concrete_scene() {
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::A, LayerScene::opaque());
+
+        let result = manager.with_scene_mut(TestScene::A, |scene: &mut LayerScene| {
+            scene.updates_below = true;
+            scene.updates_below
+        });
+
+        assert_eq!(result, Some(true));
+        assert!(manager.scenes[&TestScene::A].updates_below());
+    }
+
+    #[test]
+    fn with_scene_mut_rejects_wrong_type_or_key() {
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::A, TransparentScene);
+
+        assert!(manager.with_scene_mut(TestScene::A, |_: &mut LayerScene| ()).is_none());
+        assert!(manager.with_scene_mut(TestScene::B, |_: &mut TransparentScene| ()).is_none());
+    }
+
+    struct LevelConfig {
+        difficulty: u8,
+        updates_below: bool,
+    }
+
+    struct LevelScene {
+        difficulty: u8,
+        updates_below: bool,
+    }
+
+    impl Scene<TestScene> for LevelScene {
+        fn update(&mut self, _context: &GlobalContext) {}
+
+        fn updates_below(&self) -> bool {
+            self.updates_below
+        }
+    }
+
+    impl ConfiguredScene<TestScene> for LevelScene {
+        type Config = LevelConfig;
+
+        fn from_config(config: LevelConfig) -> Self {
+            Self { difficulty: config.difficulty, updates_below: config.updates_below }
+        }
+    }
+
+    #[test]
+    fn register_scene_with_builds_from_config() {
+        let mut manager = SceneManager::new();
+        let config = LevelConfig { difficulty: 3, updates_below: true };
+        let handle = manager.register_scene_with::<LevelScene>(TestScene::B, config);
+
+        assert_eq!(manager.with_handle_mut(handle, |scene| scene.difficulty), Some(3));
+        assert!(manager.scenes[&TestScene::B].updates_below());
+    }
+
+    #[test]
+    fn handle_remembers_scene_type() {
+        let mut manager = SceneManager::new();
+        let handle = manager.register_scene(TestScene::C, LayerScene::opaque());
+
+        manager.with_handle_mut(handle, |scene| scene.renders_below = true);
+
+        assert_eq!(handle.key(), TestScene::C);
+        assert_eq!(manager.with_handle_mut(handle, |scene| scene.renders_below), Some(true));
+    }
+
+    #[test]
+    fn for_each_active_visits_only_uncovered_scenes() {
+        let mut manager = stacked(LayerScene::background_sim());
+
+        let mut visited = Vec::new();
+        manager.for_each_active(|key, _| visited.push(key));
+
+        assert_eq!(visited, vec![TestScene::B, TestScene::C]);
+    }
+
+    #[test]
+    fn for_each_active_on_empty_stack_does_nothing() {
+        let mut manager = SceneManager::<TestScene>::new();
+        manager.for_each_active(|_, _| panic!("No scene should be active"));
+    }
+
+    #[test]
+    fn registered_keys_lists_every_registered_scene() {
+        let mut manager = SceneManager::new();
+        assert_eq!(manager.registered_keys().count(), 0);
+
+        manager.register_scene(TestScene::A, TransparentScene);
+        manager.register_scene(TestScene::B, LayerScene::opaque());
+        manager.register_default(TestScene::C, LayerScene::opaque());
+
+        let mut keys: Vec<TestScene> = manager.registered_keys().collect();
+        keys.sort_by_key(|key| *key as u8);
+        assert_eq!(keys, vec![TestScene::A, TestScene::B, TestScene::C]);
+    }
+
+    //--- Follow-up Transition Tests ---------------------------------------
+
+    /// Pushes `next` from `on_enter`, like a loading screen that is done.
+    struct LoadingScene {
+        next: TestScene,
+    }
+
+    impl Scene<TestScene> for LoadingScene {
+        fn on_enter(&mut self, _context: &GlobalContext) -> Option<SceneTransition<TestScene>> {
+            Some(SceneTransition::Push(self.next))
+        }
+
+        fn update(&mut self, _context: &GlobalContext) {}
+    }
+
+    #[test]
+    fn on_enter_transition_is_processed_next_tick() {
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::A, LoadingScene { next: TestScene::B });
+        manager.register_scene(TestScene::B, LayerScene::opaque());
+        let mut context = GlobalContext::new();
+
+        context.message_bus.push(SceneTransition::Push(TestScene::A));
+        manager.process_transitions(&mut context);
+        assert_eq!(manager.stack, vec![TestScene::A]);
+
+        manager.process_transitions(&mut context);
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::B]);
+        assert!(!context.message_bus.has_messages::<SceneTransition<TestScene>>());
+    }
+
+    #[test]
+    fn initial_scene_follow_up_is_queued_for_first_tick() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LoadingScene { next: TestScene::C });
+        manager.register_scene(TestScene::C, LayerScene::opaque());
+        let mut context = GlobalContext::new();
+
+        manager.start(&mut context);
+        assert_eq!(manager.stack, vec![TestScene::A]);
+
+        manager.process_transitions(&mut context);
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::C]);
+    }
+
+    //--- Coalescing Tests -------------------------------------------------
+
+    fn apply_all(
+        manager: &mut SceneManager<TestScene>,
+        transitions: &[SceneTransition<TestScene>],
+    ) {
+        let mut context = GlobalContext::new();
+        for transition in transitions {
+            context.message_bus.push(transition.clone());
+        }
+        manager.process_transitions(&mut context);
+    }
+
+    #[test]
+    fn push_then_remove_cancels_out_when_coalescing() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, CountingScene(Arc::clone(&counters)));
+        manager.register_scene(TestScene::C, LayerScene::opaque());
+        manager.set_transition_coalescing(true);
+
+        apply_all(
+            &mut manager,
+            &[
+                SceneTransition::Push(TestScene::B),
+                SceneTransition::Remove(TestScene::B),
+                SceneTransition::Push(TestScene::C),
+            ],
+        );
+
+        assert_eq!(counters.enters.load(Ordering::SeqCst), 0);
+        assert_eq!(counters.exits.load(Ordering::SeqCst), 0);
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::C]);
+    }
+
+    #[test]
+    fn interleaved_pair_is_not_coalesced() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, CountingScene(Arc::clone(&counters)));
+        manager.register_scene(TestScene::C, LayerScene::opaque());
+        manager.set_transition_coalescing(true);
+
+        apply_all(
+            &mut manager,
+            &[
+                SceneTransition::Push(TestScene::B),
+                SceneTransition::Push(TestScene::C),
+                SceneTransition::Remove(TestScene::B),
+            ],
+        );
+
+        assert_eq!(counters.enters.load(Ordering::SeqCst), 1);
+        assert_eq!(counters.exits.load(Ordering::SeqCst), 1);
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::C]);
+    }
+
+    #[test]
+    fn coalescing_is_off_by_default() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::B, CountingScene(Arc::clone(&counters)));
+        assert!(!manager.transition_coalescing());
+
+        apply_all(
+            &mut manager,
+            &[SceneTransition::Push(TestScene::B), SceneTransition::Remove(TestScene::B)],
+        );
+
+        assert_eq!(counters.enters.load(Ordering::SeqCst), 1);
+        assert_eq!(counters.exits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn remove_of_scene_already_in_stack_is_not_coalesced() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::B, CountingScene(Arc::clone(&counters)));
+        manager.set_transition_coalescing(true);
+
+        apply_all(
+            &mut manager,
+            &[SceneTransition::Push(TestScene::B), SceneTransition::Remove(TestScene::B)],
+        );
+
+        assert!(manager.stack.is_empty());
+        assert_eq!(counters.exits.load(Ordering::SeqCst), 1);
+    }
+
+    //--- Depth Limit Tests ------------------------------------------------
+
+    #[test]
+    fn push_beyond_depth_limit_is_rejected() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, LayerScene::opaque());
+        manager.register_scene(TestScene::C, CountingScene(Arc::clone(&counters)));
+        manager.set_max_stack_depth(Some(2));
+
+        apply_all(
+            &mut manager,
+            &[
+                SceneTransition::Push(TestScene::B),
+                SceneTransition::Push(TestScene::C),
+                SceneTransition::PushBelow(TestScene::C),
+            ],
+        );
+
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::B]);
+        assert_eq!(counters.enters.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            manager.transition_errors(),
+            &[TransitionError::StackFull(TestScene::C), TransitionError::StackFull(TestScene::C)]
+        );
+    }
+
+    #[test]
+    fn depth_limit_leaves_room_after_remove() {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, LayerScene::opaque());
+        manager.set_max_stack_depth(Some(1));
+
+        apply_all(
+            &mut manager,
+            &[SceneTransition::Remove(TestScene::A), SceneTransition::Push(TestScene::B)],
+        );
+
+        assert_eq!(manager.stack, vec![TestScene::B]);
+        assert!(manager.transition_errors().is_empty());
+    }
+
+    //--- Pause/Resume Tests -----------------------------------------------
+
+    fn started_with_counting_base(counters: &Arc<Counters>) -> SceneManager<TestScene> {
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, CountingScene(Arc::clone(counters)));
+        manager.register_scene(TestScene::B, LayerScene::opaque());
+        manager.register_scene(TestScene::C, LayerScene::background_sim());
+        manager.start(&mut GlobalContext::new());
+        manager
+    }
+
+    #[test]
+    fn opaque_push_pauses_covered_scene() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = started_with_counting_base(&counters);
+
+        apply(&mut manager, SceneTransition::Push(TestScene::B));
+
+        assert_eq!(manager.newly_deactivated(), &[TestScene::A]);
+        assert_eq!(manager.newly_activated(), &[TestScene::B]);
+        assert_eq!(counters.pauses.load(Ordering::SeqCst), 1);
+        assert_eq!(counters.exits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn removing_cover_resumes_scene_once() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = started_with_counting_base(&counters);
+        apply(&mut manager, SceneTransition::Push(TestScene::B));
+
+        apply(&mut manager, SceneTransition::Remove(TestScene::B));
+
+        assert_eq!(manager.newly_activated(), &[TestScene::A]);
+        assert_eq!(manager.newly_deactivated(), &[TestScene::B]);
+        assert_eq!(counters.resumes.load(Ordering::SeqCst), 1);
+
+        apply(&mut manager, SceneTransition::Empty);
+        assert!(manager.newly_activated().is_empty());
+        assert_eq!(counters.resumes.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn transparent_push_keeps_scene_running() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = started_with_counting_base(&counters);
+
+        apply(&mut manager, SceneTransition::Push(TestScene::C));
+
+        assert!(manager.newly_deactivated().is_empty());
+        assert_eq!(counters.pauses.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn entering_and_exiting_scenes_are_not_paused_or_resumed() {
+        let counters = Arc::new(Counters::default());
+        let mut manager = SceneManager::new();
+        manager.register_default(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, CountingScene(Arc::clone(&counters)));
+        manager.start(&mut GlobalContext::new());
+
+        apply(&mut manager, SceneTransition::Push(TestScene::B));
+        apply(&mut manager, SceneTransition::Remove(TestScene::B));
+
+        assert_eq!(manager.newly_deactivated(), &[TestScene::B]);
+        assert_eq!(counters.pauses.load(Ordering::SeqCst), 0);
+        assert_eq!(counters.resumes.load(Ordering::SeqCst), 0);
+    }
+
+    //--- Ordering Tests ---------------------------------------------------
+
+    fn apply_queue(
+        manager: &mut SceneManager<TestScene>,
+        transitions: Vec<SceneTransition<TestScene>>,
+    ) {
+        let mut context = GlobalContext::new();
+        for transition in transitions {
+            context.message_bus.push(transition);
+        }
+        manager.process_transitions(&mut context);
+    }
+
+    #[test]
+    fn mixed_transitions_apply_in_queue_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = SceneManager::new();
+        for key in [TestScene::A, TestScene::B, TestScene::C] {
+            manager.register_scene(key, LoggingScene(key, Arc::clone(&log)));
+        }
+
+        apply_queue(&mut manager, vec![
+            SceneTransition::Push(TestScene::A),
+            SceneTransition::Replace(TestScene::A, TestScene::B),
+            SceneTransition::Push(TestScene::C),
+        ]);
+
+        assert_eq!(manager.stack, vec![TestScene::B, TestScene::C]);
+        assert_eq!(*log.lock().unwrap(), vec!["A enter", "A exit", "B enter", "C enter"]);
+    }
+
+    #[test]
+    fn later_transitions_see_earlier_results() {
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::B, LayerScene::opaque());
+        manager.register_scene(TestScene::C, LayerScene::opaque());
+
+        apply_queue(&mut manager, vec![
+            SceneTransition::Push(TestScene::A),
+            SceneTransition::Push(TestScene::B),
+            SceneTransition::Swap(TestScene::A, TestScene::B),
+            SceneTransition::PushBelow(TestScene::C),
+        ]);
+
+        assert_eq!(manager.stack, vec![TestScene::B, TestScene::C, TestScene::A]);
+    }
+
+    #[test]
+    fn rejected_transition_does_not_stop_the_queue() {
+        let mut manager = SceneManager::new();
+        manager.register_scene(TestScene::A, LayerScene::opaque());
+        manager.register_scene(TestScene::C, LayerScene::opaque());
+
+        apply_queue(&mut manager, vec![
+            SceneTransition::Push(TestScene::A),
+            SceneTransition::Replace(TestScene::A, TestScene::B),
+            SceneTransition::Push(TestScene::C),
+        ]);
+
+        assert_eq!(manager.stack, vec![TestScene::A, TestScene::C]);
+        assert_eq!(manager.transition_errors(), &[TransitionError::Unregistered(TestScene::B)]);
+    }
 }
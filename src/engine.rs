@@ -8,22 +8,53 @@
 //     EngineBuilder  ──build()──>  Engine  ──run()──>  [Runtime]
 //         │                          │
 //         ├─ with_tps()              └─ spawns threads
-//         └─ with_channel_capacity()    runs platform
-//                                       blocks until exit
+//         ├─ with_tick_fn()             runs platform
+//         ├─ with_overrun_handler()     blocks until exit
+//         ├─ with_tap_coalescing()
+//         ├─ with_mouse_clamping()
+//         ├─ with_unmapped_key_warnings()
+//         ├─ with_max_discrete_events()
+//         ├─ with_early_input_flush()
+//         ├─ with_precise_timing()
+//         ├─ with_watchdog()
+//         ├─ with_seed()
+//         ├─ with_input_observer()
+//         ├─ with_lifecycle_observer()
+//         ├─ with_initial_scene()
+//         └─ with_channel_capacity()
+//
+//     Engine  ──embed()──>  EmbeddedEngine  (host drives the winit loop)
 // ```
 //
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
-use crossbeam_channel::{bounded, Receiver, Sender};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
 use log::{error, info};
+use winit::{
+    application::ApplicationHandler,
+    event::{DeviceEvent, DeviceId, StartCause, WindowEvent},
+    event_loop::{ActiveEventLoop, EventLoop},
+    window::WindowId as WinitWindowId,
+};
 
 //=== Internal Dependencies ===============================================
 
-use crate::core::platform_bridge::PlatformEvent;
-use crate::core::{Action, CoreSystemsOrchestrator, GlobalSystems, SceneKey};
-use crate::platform::Platform;
+use crate::core::input::{InputEvent, InputObserver};
+use crate::core::platform_bridge::{
+    CoreExitReason, Heartbeat, PlatformCommand, PlatformError, PlatformEvent, Watchdog,
+};
+use crate::core::scene::Scene;
+use crate::core::{
+    panic_message, Action, CoreSystemsOrchestrator, EngineCommand, EngineLifecycleEvent,
+    GlobalContext, GlobalSystems, LifecycleFn, OverrunFn, SceneKey, TickFn,
+};
+use crate::error::{BuilderError, EngineError};
+use crate::platform::{Platform, DEFAULT_MAX_DISCRETE};
 
 //=== EngineBuilder =======================================================
 
@@ -94,6 +125,19 @@ use crate::platform::Platform;
 pub struct EngineBuilder<S: SceneKey, A: Action> {
     tps: f64,
     channel_capacity: usize,
+    tap_coalescing: bool,
+    mouse_clamping: bool,
+    unmapped_key_warnings: bool,
+    max_discrete_events: usize,
+    early_input_flush: Option<usize>,
+    precise_timing: bool,
+    watchdog_threshold: Option<Duration>,
+    seed: Option<u64>,
+    input_observer: Option<InputObserver>,
+    tick_fn: Option<TickFn>,
+    overrun_fn: Option<OverrunFn>,
+    lifecycle_fn: Option<LifecycleFn>,
+    initial_scenes: Vec<(S, Box<dyn Scene<S>>)>,
     _phantom: std::marker::PhantomData<(S, A)>,
 }
 
@@ -103,6 +147,19 @@ impl<S: SceneKey, A: Action> EngineBuilder<S, A> {
         Self {
             tps: 60.0,
             channel_capacity: 128,
+            tap_coalescing: false,
+            mouse_clamping: false,
+            unmapped_key_warnings: cfg!(debug_assertions),
+            max_discrete_events: DEFAULT_MAX_DISCRETE,
+            early_input_flush: None,
+            precise_timing: false,
+            watchdog_threshold: None,
+            seed: None,
+            input_observer: None,
+            tick_fn: None,
+            overrun_fn: None,
+            lifecycle_fn: None,
+            initial_scenes: Vec::new(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -115,11 +172,9 @@ impl<S: SceneKey, A: Action> EngineBuilder<S, A> {
     ///
     /// Default: 60.0
     ///
-    /// # Panics
-    ///
-    /// Panics if `tps <= 0.0`.
+    /// Must be positive and finite; validated by [`build`](Self::build) (panics) or
+    /// [`try_build`](Self::try_build) (returns an error).
     pub fn with_tps(mut self, tps: f64) -> Self {
-        assert!(tps > 0.0, "TPS must be positive, got {}", tps);
         self.tps = tps;
         self
     }
@@ -132,29 +187,301 @@ impl<S: SceneKey, A: Action> EngineBuilder<S, A> {
     ///
     /// Default: 128
     ///
-    /// # Panics
-    ///
-    /// Panics if `capacity == 0`.
+    /// Must be non-zero; validated by [`build`](Self::build) (panics) or
+    /// [`try_build`](Self::try_build) (returns an error).
     pub fn with_channel_capacity(mut self, capacity: usize) -> Self {
-        assert!(capacity > 0, "Channel capacity must be positive");
         self.channel_capacity = capacity;
         self
     }
 
+    /// Collapses same-frame key press/release pairs into a single tap event.
+    ///
+    /// Reduces channel traffic when keys are tapped rapidly. Raw queries and
+    /// actions behave the same as without coalescing: a tapped key reports
+    /// both `is_key_pressed` and `is_key_released` for that tick.
+    ///
+    /// Default: false
+    pub fn with_tap_coalescing(mut self, enabled: bool) -> Self {
+        self.tap_coalescing = enabled;
+        self
+    }
+
+    /// Clamps reported mouse positions to the window's bounds.
+    ///
+    /// Some platforms keep reporting cursor positions outside the window
+    /// (e.g., negative coordinates while dragging). With clamping enabled,
+    /// positions are limited to `0..=width` / `0..=height` of the window the
+    /// event came from. Use `mouse_in_window` to tell whether the cursor is
+    /// actually over the window.
+    ///
+    /// Default: false (raw positions)
+    pub fn with_mouse_clamping(mut self, enabled: bool) -> Self {
+        self.mouse_clamping = enabled;
+        self
+    }
+
+    /// Logs a warning the first time each key without a [`KeyCode`] is
+    /// pressed, naming the winit key code.
+    ///
+    /// Such keys are dropped by the platform layer and can never be bound;
+    /// the warning surfaces mapping gaps during development.
+    ///
+    /// Default: enabled in debug builds, disabled in release builds
+    ///
+    /// [`KeyCode`]: crate::core::input::KeyCode
+    pub fn with_unmapped_key_warnings(mut self, enabled: bool) -> Self {
+        self.unmapped_key_warnings = enabled;
+        self
+    }
+
+    /// Caps the discrete input events (keys, buttons) buffered per window
+    /// between flushes.
+    ///
+    /// Past the cap the oldest events are dropped with a warning, so an
+    /// input flood cannot grow the buffer unbounded. The newest input is
+    /// kept as the most relevant. A cap of 0 is treated as 1.
+    ///
+    /// Default: 4096 (never reached in normal use)
+    pub fn with_max_discrete_events(mut self, max: usize) -> Self {
+        self.max_discrete_events = max;
+        self
+    }
+
+    /// Sends key and button input as soon as `threshold` discrete events are
+    /// buffered, instead of waiting for the window's next redraw.
+    ///
+    /// A threshold of 1 flushes every key or button event immediately, for
+    /// the lowest latency on twitchy input at the cost of more channel
+    /// traffic. Mouse movement still coalesces until the redraw. Events
+    /// flushed separately are not collapsed by tap coalescing. A threshold
+    /// of 0 is treated as 1.
+    ///
+    /// Default: disabled (flush on redraw)
+    pub fn with_early_input_flush(mut self, threshold: usize) -> Self {
+        self.early_input_flush = Some(threshold);
+        self
+    }
+
+    /// Paces ticks with sleep-then-spin instead of plain `thread::sleep`.
+    ///
+    /// OS sleeps can overshoot by 1–15ms, which shows up as jitter at high
+    /// TPS. Precise timing sleeps until just before the deadline and then
+    /// busy-waits, at the cost of extra CPU usage on the logic thread.
+    ///
+    /// Default: false
+    pub fn with_precise_timing(mut self, enabled: bool) -> Self {
+        self.precise_timing = enabled;
+        self
+    }
+
+    /// Logs an error when the logic thread stops ticking for `threshold`.
+    ///
+    /// The core thread bumps a heartbeat counter every tick and the platform
+    /// checks it on each event loop iteration, so a scene that deadlocks or
+    /// loops forever in `update` is reported instead of failing silently.
+    /// Choose a threshold well above the longest expected tick.
+    ///
+    /// Default: disabled
+    pub fn with_watchdog(mut self, threshold: Duration) -> Self {
+        self.watchdog_threshold = Some(threshold);
+        self
+    }
+
+    /// Seeds [`GlobalContext::rng`] for reproducible random sequences.
+    ///
+    /// Ticks run on a single thread at a fixed step, so the same seed and
+    /// the same draws in the same order replay identically (replays,
+    /// lockstep netcode).
+    ///
+    /// Default: seeded from the system clock (different every run)
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets a closure called with every raw input event on the logic thread.
+    ///
+    /// Runs as each event is applied to the input state, in arrival order
+    /// (including injected input), so debugging tools can log or visualize
+    /// the raw stream. Keep it cheap; it runs inside the tick.
+    ///
+    /// Default: none (no per-event overhead)
+    pub fn with_input_observer<F>(mut self, observer: F) -> Self
+    where
+        F: FnMut(&InputEvent) + Send + 'static,
+    {
+        self.input_observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Sets a closure to run once per tick on the logic thread.
+    ///
+    /// Invoked after input, scenes and transitions have updated, so the
+    /// context reflects the current tick. A lightweight alternative to
+    /// registering a [`Scene`](crate::core::scene::Scene) for simple logic.
+    ///
+    /// ```no_run
+    /// # use aetheric_engine::prelude::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameScene { Main }
+    /// # impl SceneKey for GameScene {}
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameAction { Jump }
+    /// # impl Action for GameAction {}
+    /// EngineBuilder::<GameScene, GameAction>::new()
+    ///     .with_tick_fn(|context| {
+    ///         if context.input_state.is_key_pressed(KeyCode::Space) {
+    ///             println!("Space pressed");
+    ///         }
+    ///     })
+    ///     .build()
+    ///     .run();
+    /// ```
+    pub fn with_tick_fn<F>(mut self, tick_fn: F) -> Self
+    where
+        F: FnMut(&mut GlobalContext) + Send + 'static,
+    {
+        self.tick_fn = Some(Box::new(tick_fn));
+        self
+    }
+
+    /// Sets a closure called when a tick runs over its time budget.
+    ///
+    /// Receives the tick's elapsed time and the target duration (`1 / TPS`),
+    /// e.g. to lower simulation detail or record metrics. Runs on the logic
+    /// thread and replaces the default "Core thread slow" warning.
+    pub fn with_overrun_handler<F>(mut self, overrun_fn: F) -> Self
+    where
+        F: FnMut(Duration, Duration) + Send + 'static,
+    {
+        self.overrun_fn = Some(Box::new(overrun_fn));
+        self
+    }
+
+    /// Sets a closure receiving typed [`EngineLifecycleEvent`]s.
+    ///
+    /// Called on the logic thread when the core starts and exits, when a
+    /// window is created and when a tick overruns (alongside the overrun
+    /// handler). Complements logging; keep it cheap.
+    ///
+    /// Default: none
+    pub fn with_lifecycle_observer<F>(mut self, observer: F) -> Self
+    where
+        F: FnMut(EngineLifecycleEvent) + Send + 'static,
+    {
+        self.lifecycle_fn = Some(Box::new(observer));
+        self
+    }
+
+    /// Registers a scene and places it on the stack at startup.
+    ///
+    /// Shorthand for calling
+    /// [`register_default`](crate::core::SceneManager::register_default) in
+    /// [`Engine::init`]. Repeated calls stack scenes in call order.
+    ///
+    /// ```no_run
+    /// # use aetheric_engine::prelude::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameScene { Title }
+    /// # impl SceneKey for GameScene {}
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameAction { Jump }
+    /// # impl Action for GameAction {}
+    /// struct TitleScene;
+    ///
+    /// impl Scene<GameScene> for TitleScene {
+    ///     fn update(&mut self, _context: &GlobalContext) {}
+    /// }
+    ///
+    /// EngineBuilder::<GameScene, GameAction>::new()
+    ///     .with_initial_scene(GameScene::Title, TitleScene)
+    ///     .build()
+    ///     .run();
+    /// ```
+    pub fn with_initial_scene<T>(mut self, key: S, scene: T) -> Self
+    where
+        T: Scene<S> + 'static,
+    {
+        self.initial_scenes.push((key, Box::new(scene)));
+        self
+    }
+
     /// Builds the engine instance.
     ///
     /// Consumes the builder and produces a configured [`Engine`] ready for
     /// initialization or execution. Call [`Engine::init`] to initialize
     /// systems before running, or call [`Engine::run`] directly.
     /// All engine systems are automatically created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration is invalid (non-finite or `tps <= 0.0`,
+    /// or `channel_capacity == 0`). Use [`try_build`](Self::try_build) to
+    /// handle these cases instead.
     pub fn build(self) -> Engine<S, A> {
+        self.try_build().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds the engine instance, returning an error for invalid settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuilderError`] naming the first invalid field.
+    pub fn try_build(self) -> Result<Engine<S, A>, BuilderError> {
+        if !self.tps.is_finite() || self.tps <= 0.0 {
+            return Err(BuilderError::InvalidTps(self.tps));
+        }
+        if self.channel_capacity == 0 {
+            return Err(BuilderError::InvalidChannelCapacity);
+        }
+
         info!("Building engine (TPS: {}, channel: {})", self.tps, self.channel_capacity);
 
-        Engine {
-            orchestrator: CoreSystemsOrchestrator::new(),
+        let mut orchestrator = CoreSystemsOrchestrator::new();
+        if let Some(tick_fn) = self.tick_fn {
+            orchestrator.set_tick_fn(tick_fn);
+        }
+        if let Some(overrun_fn) = self.overrun_fn {
+            orchestrator.set_overrun_handler(overrun_fn);
+        }
+        if let Some(lifecycle_fn) = self.lifecycle_fn {
+            orchestrator.set_lifecycle_observer(lifecycle_fn);
+        }
+        let (commands, command_receiver) = unbounded();
+        orchestrator.set_command_receiver(command_receiver);
+        orchestrator.set_precise_timing(self.precise_timing);
+        let watchdog = self.watchdog_threshold.map(|threshold| {
+            let heartbeat = Heartbeat::new();
+            orchestrator.set_heartbeat(heartbeat.clone());
+            (heartbeat, threshold)
+        });
+        let seed = self.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+        });
+        orchestrator.set_seed(seed);
+        if let Some(observer) = self.input_observer {
+            orchestrator.set_input_observer(observer);
+        }
+        orchestrator.init_systems(|systems| {
+            for (key, scene) in self.initial_scenes {
+                systems.scene_manager.register_default_boxed(key, scene);
+            }
+        });
+
+        Ok(Engine {
+            orchestrator,
+            commands,
             tps: self.tps,
             channel_capacity: self.channel_capacity,
-        }
+            tap_coalescing: self.tap_coalescing,
+            mouse_clamping: self.mouse_clamping,
+            unmapped_key_warnings: self.unmapped_key_warnings,
+            max_discrete_events: self.max_discrete_events,
+            early_input_flush: self.early_input_flush,
+            watchdog,
+        })
     }
 }
 
@@ -169,7 +496,8 @@ impl<S: SceneKey, A: Action> Default for EngineBuilder<S, A> {
 /// Aetheric Engine runtime.
 ///
 /// The engine coordinates all subsystems and manages the main execution loop.
-/// Create via [`EngineBuilder`] with `EngineBuilder::new().build()`.
+/// Create via [`EngineBuilder`] with `Engine::builder().build()` (same as
+/// `EngineBuilder::new().build()`).
 ///
 /// # Architecture
 ///
@@ -218,11 +546,41 @@ impl<S: SceneKey, A: Action> Default for EngineBuilder<S, A> {
 /// ```
 pub struct Engine<S: SceneKey, A: Action> {
     orchestrator: CoreSystemsOrchestrator<S, A>,
+    commands: Sender<EngineCommand>,
     tps: f64,
     channel_capacity: usize,
+    tap_coalescing: bool,
+    mouse_clamping: bool,
+    unmapped_key_warnings: bool,
+    max_discrete_events: usize,
+    early_input_flush: Option<usize>,
+    watchdog: Option<(Heartbeat, Duration)>,
 }
 
 impl<S: SceneKey, A: Action> Engine<S, A> {
+    //--- Construction -----------------------------------------------------
+
+    /// Returns a builder with default settings.
+    ///
+    /// Shorthand for [`EngineBuilder::new`]:
+    ///
+    /// ```no_run
+    /// # use aetheric_engine::prelude::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameScene { Main }
+    /// # impl SceneKey for GameScene {}
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameAction { Jump }
+    /// # impl Action for GameAction {}
+    /// Engine::<GameScene, GameAction>::builder()
+    ///     .with_tps(120.0)
+    ///     .build()
+    ///     .run();
+    /// ```
+    pub fn builder() -> EngineBuilder<S, A> {
+        EngineBuilder::new()
+    }
+
     //--- Initialization ---------------------------------------------------
 
     /// Initializes engine systems before execution.
@@ -267,61 +625,280 @@ impl<S: SceneKey, A: Action> Engine<S, A> {
         self
     }
 
+    //--- Runtime Control --------------------------------------------------
+
+    /// Returns a sender for controlling the logic thread while it runs.
+    ///
+    /// Call before [`run`](Self::run) and move the sender to another thread
+    /// (tools, a debugger, a network handler). Sending never blocks; each
+    /// tick applies the commands received since the last one. Sends fail
+    /// once the engine has shut down.
+    ///
+    /// ```no_run
+    /// # use aetheric_engine::prelude::*;
+    /// # use aetheric_engine::EngineCommand;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameScene { Main }
+    /// # impl SceneKey for GameScene {}
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameAction { Jump }
+    /// # impl Action for GameAction {}
+    /// let engine = EngineBuilder::<GameScene, GameAction>::new().build();
+    /// let commands = engine.command_sender();
+    ///
+    /// std::thread::spawn(move || {
+    ///     let _ = commands.send(EngineCommand::Pause);
+    /// });
+    /// engine.run();
+    /// ```
+    pub fn command_sender(&self) -> Sender<EngineCommand> {
+        self.commands.clone()
+    }
+
     //--- Execution --------------------------------------------------------
 
     /// Starts the engine runtime and blocks until the application exits.
     ///
     /// # Lifecycle
     ///
-    /// 1. Creates MPSC channel for platform → core communication
+    /// 1. Creates MPSC channels for platform → core input and core → platform commands
     /// 2. Spawns logic thread running at configured TPS
     /// 3. Runs platform event loop (blocks here)
     /// 4. On window close: platform exits → channel disconnects → logic thread terminates
+    /// 5. On [`GlobalContext::request_exit`]: logic thread terminates → platform exits
     ///
-    /// # Panics
-    ///
-    /// Panics if platform initialization fails (e.g., no graphics context).
+    /// The core thread's exit reason is logged on shutdown. Errors are
+    /// logged; use [`try_run`](Self::try_run) to handle them instead.
     ///
     /// # Thread Panic Handling
     ///
-    /// If the logic thread panics, the error is logged and the engine attempts
-    /// graceful shutdown. The platform continues running to allow the user to
-    /// close the window normally.
+    /// If the logic thread panics, the error is logged and the platform is
+    /// told to shut down, closing the window instead of leaving it running
+    /// without a simulation.
     pub fn run(self) {
+        if let Err(e) = self.try_run() {
+            error!("Engine error: {}", e);
+        }
+    }
+
+    /// Like [`run`](Self::run), but returns startup and runtime failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError`] if the event loop cannot be created or fails,
+    /// or if the logic thread panicked. A platform error takes precedence.
+    pub fn try_run(self) -> Result<(), EngineError> {
         info!("Starting engine runtime (TPS: {})", self.tps);
 
-        //--- 1. Create communication channel -----------------------------
+        self.run_platform(Platform::run)
+    }
+
+    /// Like [`run`](Self::run), but on an event loop created by the caller.
+    ///
+    /// Useful when the application needs to configure the `EventLoop`
+    /// (platform extensions, user events) before handing it to the engine.
+    pub fn run_on(self, event_loop: EventLoop<()>) {
+        if let Err(e) = self.try_run_on(event_loop) {
+            error!("Engine error: {}", e);
+        }
+    }
+
+    /// Like [`try_run`](Self::try_run), but on an event loop created by the caller.
+    ///
+    /// # Errors
+    ///
+    /// See [`try_run`](Self::try_run).
+    pub fn try_run_on(self, event_loop: EventLoop<()>) -> Result<(), EngineError> {
+        info!("Starting engine runtime on external event loop (TPS: {})", self.tps);
+
+        self.run_platform(|platform| platform.run_on(event_loop))
+    }
+
+    /// Starts the core thread and returns a handler for a host-owned event loop.
+    ///
+    /// The host forwards its winit callbacks to the returned
+    /// [`EmbeddedEngine`] (or passes it to `EventLoop::run_app` directly).
+    /// Closing the engine's primary window stops the core thread but never
+    /// exits the host's event loop.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use aetheric_engine::prelude::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameScene { Main }
+    /// # impl SceneKey for GameScene {}
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// # enum GameAction { Jump }
+    /// # impl Action for GameAction {}
+    /// let mut engine = EngineBuilder::<GameScene, GameAction>::new().build().embed();
+    ///
+    /// // Inside the host's ApplicationHandler:
+    /// //   fn window_event(&mut self, event_loop, id, event) {
+    /// //       self.engine.window_event(event_loop, id, event);
+    /// //   }
+    ///
+    /// engine.shutdown();
+    /// ```
+    pub fn embed(self) -> EmbeddedEngine {
+        info!("Starting embedded engine runtime (TPS: {})", self.tps);
+
+        let (platform, core_handle) = self.start();
+
+        EmbeddedEngine {
+            platform: Some(platform.hosted()),
+            core_handle: Some(core_handle),
+        }
+    }
+
+    //--- Internal ---------------------------------------------------------
+
+    /// Starts the core thread, runs the platform with `run`, then joins.
+    ///
+    /// The platform is dropped before joining: that disconnects the input
+    /// channel, so the core thread exits even when the event loop failed to
+    /// start (e.g., no display on headless CI) instead of idling forever.
+    fn run_platform<F>(self, run: F) -> Result<(), EngineError>
+    where
+        F: FnOnce(&mut Platform) -> Result<(), PlatformError>,
+    {
+        let (mut platform, core_handle) = self.start();
+        info!("Platform initialized, entering event loop");
+
+        let platform_result = run(&mut platform).map_err(EngineError::from);
+        match &platform_result {
+            Ok(()) => info!("Platform event loop exited"),
+            Err(e) => error!("Platform failed: {}; stopping core thread", e),
+        }
+        drop(platform);
+
+        let core_result = join_core_thread(core_handle);
+        platform_result.and(core_result)
+    }
+
+    /// Creates the channels, spawns the core thread and builds the platform.
+    fn start(self) -> (Platform, JoinHandle<CoreExitReason>) {
+        //--- 1. Create communication channels ----------------------------
         let (tx, rx): (Sender<PlatformEvent>, Receiver<PlatformEvent>) =
             bounded(self.channel_capacity);
+        let (cmd_tx, cmd_rx): (Sender<PlatformCommand>, Receiver<PlatformCommand>) =
+            unbounded();
 
         info!("MPSC channel created (capacity: {})", self.channel_capacity);
 
         //--- 2. Spawn the core logic thread -------------------------------
-        let core_handle = self.orchestrator.spawn_core_thread(rx, self.tps);
+        let core_handle = self.orchestrator.spawn_core_thread(rx, cmd_tx, self.tps);
         info!("Core logic thread spawned");
 
-        //--- 3. Launch the platform subsystem -----------------------------
-        let platform = Platform::new(tx);
-        info!("Platform initialized, entering event loop");
+        //--- 3. Build the platform subsystem ------------------------------
+        let watchdog = self
+            .watchdog
+            .map(|(heartbeat, threshold)| Watchdog::new(heartbeat, threshold));
+        let platform = Platform::new(tx, cmd_rx)
+            .with_tap_coalescing(self.tap_coalescing)
+            .with_mouse_clamping(self.mouse_clamping)
+            .with_unmapped_key_warnings(self.unmapped_key_warnings)
+            .with_max_discrete_events(self.max_discrete_events)
+            .with_early_flush(self.early_input_flush)
+            .with_watchdog(watchdog);
+        (platform, core_handle)
+    }
+}
 
-        if let Err(e) = platform.run() {
-            error!("Platform error: {:?}", e);
-        }
+//=== EmbeddedEngine ======================================================
+
+/// Engine runtime driven by a host application's winit event loop.
+///
+/// Created by [`Engine::embed`]. Implements [`ApplicationHandler`] so the
+/// host can either run it directly or forward callbacks from its own
+/// handler. The core logic thread keeps running at fixed TPS.
+///
+/// Dropping the handle shuts the engine down; call
+/// [`shutdown`](Self::shutdown) to do so explicitly.
+pub struct EmbeddedEngine {
+    platform: Option<Platform>,
+    core_handle: Option<JoinHandle<CoreExitReason>>,
+}
 
-        info!("Platform event loop exited");
+impl EmbeddedEngine {
+    /// Returns `true` while the core logic thread is running.
+    pub fn is_running(&self) -> bool {
+        self.core_handle.as_ref().is_some_and(|handle| !handle.is_finished())
+    }
 
-        //--- 4. Cleanup: Wait for logic thread to terminate --------------
-        match core_handle.join() {
-            Ok(()) => {
-                info!("Core thread terminated cleanly");
-            }
-            Err(e) => {
-                error!("Core thread panicked: {:?}", e);
+    /// Disconnects the platform and waits for the core thread to exit.
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        // Dropping the platform disconnects the channel, ending the core loop
+        self.platform.take();
+
+        if let Some(handle) = self.core_handle.take() {
+            if let Err(e) = join_core_thread(handle) {
+                error!("Engine error: {}", e);
             }
         }
+    }
+
+    fn platform(&mut self) -> &mut Platform {
+        self.platform.as_mut().expect("platform is only taken on shutdown")
+    }
+}
+
+impl Drop for EmbeddedEngine {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+impl ApplicationHandler for EmbeddedEngine {
+    fn new_events(&mut self, event_loop: &ActiveEventLoop, cause: StartCause) {
+        self.platform().new_events(event_loop, cause);
+    }
 
-        info!("Engine shutdown complete");
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        self.platform().resumed(event_loop);
     }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window_id: WinitWindowId,
+        event: WindowEvent,
+    ) {
+        self.platform().window_event(event_loop, window_id, event);
+    }
+
+    fn device_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        device_id: DeviceId,
+        event: DeviceEvent,
+    ) {
+        self.platform().device_event(event_loop, device_id, event);
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        self.platform().about_to_wait(event_loop);
+    }
+}
+
+//=== Helpers =============================================================
+
+/// Waits for the logic thread to terminate and logs its exit reason.
+fn join_core_thread(core_handle: JoinHandle<CoreExitReason>) -> Result<(), EngineError> {
+    let result = match core_handle.join() {
+        Ok(reason) => {
+            info!("Core thread terminated cleanly: {:?}", reason);
+            Ok(())
+        }
+        Err(payload) => Err(EngineError::CorePanicked(panic_message(&*payload).to_string())),
+    };
+
+    info!("Engine shutdown complete");
+    result
 }
 
 //=========================================================================
@@ -331,8 +908,10 @@ impl<S: SceneKey, A: Action> Engine<S, A> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::input::KeyCode;
+    use std::sync::{Arc, Mutex};
+    use crate::core::platform_bridge::EventCollector;
 
+    #[allow(dead_code)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     enum TestScene {
         Main,
@@ -340,6 +919,7 @@ mod tests {
 
     impl SceneKey for TestScene {}
 
+    #[allow(dead_code)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     enum TestAction {
         Jump,
@@ -364,6 +944,13 @@ mod tests {
         assert_eq!(builder.channel_capacity, 128);
     }
 
+    #[test]
+    fn engine_builder_shorthand_uses_defaults() {
+        let engine = Engine::<TestScene, TestAction>::builder().build();
+        assert_eq!(engine.tps, 60.0);
+        assert_eq!(engine.channel_capacity, 128);
+    }
+
     #[test]
     fn builder_with_tps() {
         let builder = EngineBuilder::<TestScene, TestAction>::new().with_tps(120.0);
@@ -373,13 +960,13 @@ mod tests {
     #[test]
     #[should_panic(expected = "TPS must be positive")]
     fn builder_with_tps_panics_on_zero() {
-        EngineBuilder::<TestScene, TestAction>::new().with_tps(0.0);
+        EngineBuilder::<TestScene, TestAction>::new().with_tps(0.0).build();
     }
 
     #[test]
     #[should_panic(expected = "TPS must be positive")]
     fn builder_with_tps_panics_on_negative() {
-        EngineBuilder::<TestScene, TestAction>::new().with_tps(-60.0);
+        EngineBuilder::<TestScene, TestAction>::new().with_tps(-60.0).build();
     }
 
     #[test]
@@ -391,7 +978,205 @@ mod tests {
     #[test]
     #[should_panic(expected = "Channel capacity must be positive")]
     fn builder_with_channel_capacity_panics_on_zero() {
-        EngineBuilder::<TestScene, TestAction>::new().with_channel_capacity(0);
+        EngineBuilder::<TestScene, TestAction>::new().with_channel_capacity(0).build();
+    }
+
+    #[test]
+    fn try_build_rejects_zero_tps() {
+        let result = EngineBuilder::<TestScene, TestAction>::new().with_tps(0.0).try_build();
+        assert_eq!(result.err(), Some(BuilderError::InvalidTps(0.0)));
+    }
+
+    #[test]
+    fn try_build_rejects_infinite_tps() {
+        let result = EngineBuilder::<TestScene, TestAction>::new()
+            .with_tps(f64::INFINITY)
+            .try_build();
+        assert_eq!(result.err(), Some(BuilderError::InvalidTps(f64::INFINITY)));
+    }
+
+    #[test]
+    fn try_build_rejects_zero_channel_capacity() {
+        let result = EngineBuilder::<TestScene, TestAction>::new()
+            .with_channel_capacity(0)
+            .try_build();
+        assert_eq!(result.err(), Some(BuilderError::InvalidChannelCapacity));
+    }
+
+    #[test]
+    fn try_build_accepts_valid_configuration() {
+        let engine = EngineBuilder::<TestScene, TestAction>::new()
+            .with_tps(30.0)
+            .try_build()
+            .unwrap();
+        assert_eq!(engine.tps, 30.0);
+    }
+
+    #[test]
+    fn builder_with_tap_coalescing() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new();
+        assert!(!builder.tap_coalescing);
+
+        let engine = builder.with_tap_coalescing(true).build();
+        assert!(engine.tap_coalescing);
+    }
+
+    #[test]
+    fn builder_with_mouse_clamping() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new();
+        assert!(!builder.mouse_clamping);
+
+        let engine = builder.with_mouse_clamping(true).build();
+        assert!(engine.mouse_clamping);
+    }
+
+    #[test]
+    fn builder_with_unmapped_key_warnings() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new();
+        assert_eq!(builder.unmapped_key_warnings, cfg!(debug_assertions));
+
+        let engine = builder.with_unmapped_key_warnings(false).build();
+        assert!(!engine.unmapped_key_warnings);
+    }
+
+    #[test]
+    fn builder_with_max_discrete_events() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new();
+        assert_eq!(builder.max_discrete_events, DEFAULT_MAX_DISCRETE);
+
+        let engine = builder.with_max_discrete_events(64).build();
+        assert_eq!(engine.max_discrete_events, 64);
+    }
+
+    #[test]
+    fn builder_with_early_input_flush() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new();
+        assert_eq!(builder.early_input_flush, None);
+
+        let engine = builder.with_early_input_flush(1).build();
+        assert_eq!(engine.early_input_flush, Some(1));
+    }
+
+    /// Ticks a seeded engine `ticks` times, drawing once per tick.
+    fn drawn_sequence(seed: u64, ticks: usize) -> Vec<u64> {
+        let drawn = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&drawn);
+        let mut engine = EngineBuilder::<TestScene, TestAction>::new()
+            .with_seed(seed)
+            .with_tick_fn(move |context| sink.lock().unwrap().push(context.rng.next_u64()))
+            .build();
+
+        let (_tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        for _ in 0..ticks {
+            engine.orchestrator.tick(&mut collector, &cmd_tx);
+        }
+
+        let sequence = drawn.lock().unwrap().clone();
+        sequence
+    }
+
+    #[test]
+    fn builder_with_seed_reproduces_sequence() {
+        let first = drawn_sequence(1234, 32);
+
+        assert_eq!(first.len(), 32);
+        assert_eq!(first, drawn_sequence(1234, 32));
+        assert_ne!(first, drawn_sequence(4321, 32));
+    }
+
+    #[test]
+    fn input_observer_sees_each_event_once_in_order() {
+        use crate::core::input::{KeyCode, Modifiers};
+        use crate::core::platform_bridge::WindowId;
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let mut engine = EngineBuilder::<TestScene, TestAction>::new()
+            .with_input_observer(move |event| sink.lock().unwrap().push(event.clone()))
+            .build();
+
+        let down = InputEvent::KeyDown { key: KeyCode::KeyA, modifiers: Modifiers::NONE };
+        let up = InputEvent::KeyUp { key: KeyCode::KeyA, modifiers: Modifiers::NONE };
+        let moved = InputEvent::MouseMoved { x: 3.0, y: 4.0 };
+
+        let (tx, rx) = unbounded();
+        let (cmd_tx, _cmd_rx) = unbounded();
+        let mut collector = EventCollector::new(rx);
+        tx.send(PlatformEvent::Inputs {
+            window: WindowId::from_raw(1),
+            discrete: vec![down.clone(), up.clone()],
+            continuous: vec![moved.clone()],
+        }).unwrap();
+        engine.orchestrator.tick(&mut collector, &cmd_tx);
+        engine.orchestrator.tick(&mut collector, &cmd_tx);
+
+        assert_eq!(*seen.lock().unwrap(), vec![down, up, moved]);
+    }
+
+    #[test]
+    fn platform_failure_stops_core_thread_and_is_reported() {
+        let engine = EngineBuilder::<TestScene, TestAction>::new().build();
+
+        let result = engine.run_platform(|_platform| {
+            Err(PlatformError::EventLoopCreation("no display".to_string()))
+        });
+
+        // Returning at all means the core thread was joined
+        assert_eq!(result, Err(EngineError::EventLoopCreation("no display".to_string())));
+    }
+
+    #[test]
+    fn builder_with_watchdog() {
+        let engine = EngineBuilder::<TestScene, TestAction>::new().build();
+        assert!(engine.watchdog.is_none());
+
+        let engine = EngineBuilder::<TestScene, TestAction>::new()
+            .with_watchdog(Duration::from_secs(2))
+            .build();
+        assert_eq!(engine.watchdog.map(|(_, threshold)| threshold), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn builder_with_precise_timing() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new();
+        assert!(!builder.precise_timing);
+
+        let builder = builder.with_precise_timing(true);
+        assert!(builder.precise_timing);
+    }
+
+    #[test]
+    fn builder_with_initial_scene_stacks_scene_before_run() {
+        struct MainScene;
+
+        impl Scene<TestScene> for MainScene {
+            fn update(&mut self, _context: &GlobalContext) {}
+        }
+
+        let engine = EngineBuilder::<TestScene, TestAction>::new()
+            .with_initial_scene(TestScene::Main, MainScene)
+            .build();
+
+        engine.init(|systems| {
+            assert_eq!(systems.scene_manager.visible_scenes(), vec![TestScene::Main]);
+        });
+    }
+
+    #[test]
+    fn builder_with_tick_fn() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new().with_tick_fn(|_| {});
+        assert!(builder.tick_fn.is_some());
+    }
+
+    #[test]
+    fn builder_with_overrun_handler() {
+        let builder = EngineBuilder::<TestScene, TestAction>::new();
+        assert!(builder.overrun_fn.is_none());
+
+        let builder = builder.with_overrun_handler(|_, _| {});
+        assert!(builder.overrun_fn.is_some());
     }
 
     #[test]
@@ -409,4 +1194,22 @@ mod tests {
         assert_eq!(engine.tps, 120.0);
         assert_eq!(engine.channel_capacity, 256);
     }
-}
\ No newline at end of file
+
+    //=====================================================================
+    // Embedding Tests
+    //=====================================================================
+
+    #[test]
+    fn embedded_engine_runs_until_shutdown() {
+        let engine = EngineBuilder::<TestScene, TestAction>::new().build().embed();
+        assert!(engine.is_running());
+
+        engine.shutdown();
+    }
+
+    #[test]
+    fn dropping_embedded_engine_stops_core_thread() {
+        let engine = EngineBuilder::<TestScene, TestAction>::new().build().embed();
+        drop(engine);
+    }
+}
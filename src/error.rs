@@ -0,0 +1,130 @@
+//=========================================================================
+// Engine Errors
+//=========================================================================
+//
+// Public error types returned by the fallible engine entry points
+// (`EngineBuilder::try_build`, `Engine::try_run`, `Engine::try_run_on`).
+//
+// Internal errors (e.g., `PlatformError`) are mapped into `EngineError`
+// so host programs can handle startup failures without reaching into
+// crate-private types.
+//
+//=========================================================================
+
+//=== External Dependencies ===============================================
+
+use std::fmt;
+
+//=== Internal Dependencies ===============================================
+
+use crate::core::platform_bridge::PlatformError;
+
+//=== EngineError =========================================================
+
+/// Errors that stop the engine from starting or running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineError {
+    /// The platform event loop could not be created (e.g., no display).
+    EventLoopCreation(String),
+
+    /// The platform event loop failed while running.
+    EventLoopExecution(String),
+
+    /// The core logic thread panicked; holds the panic message.
+    CorePanicked(String),
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EventLoopCreation(e) => write!(f, "Event loop creation failed: {}", e),
+            Self::EventLoopExecution(e) => write!(f, "Event loop error: {}", e),
+            Self::CorePanicked(msg) => write!(f, "Core thread panicked: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for EngineError {}
+
+//=== BuilderError ========================================================
+
+/// Invalid [`EngineBuilder`](crate::EngineBuilder) configuration, reported
+/// by [`try_build`](crate::EngineBuilder::try_build).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuilderError {
+    /// `with_tps` was given a value that is not positive and finite; holds the value.
+    InvalidTps(f64),
+
+    /// `with_channel_capacity` was given zero.
+    InvalidChannelCapacity,
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTps(tps) => write!(f, "TPS must be positive and finite, got {}", tps),
+            Self::InvalidChannelCapacity => write!(f, "Channel capacity must be positive"),
+        }
+    }
+}
+
+impl std::error::Error for BuilderError {}
+
+//--- Conversions ---------------------------------------------------------
+
+impl From<PlatformError> for EngineError {
+    fn from(error: PlatformError) -> Self {
+        match error {
+            PlatformError::EventLoopCreation(e) => Self::EventLoopCreation(e),
+            PlatformError::EventLoopExecution(e) => Self::EventLoopExecution(e),
+            other @ PlatformError::NoWindow => Self::EventLoopExecution(other.to_string()),
+        }
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platform_creation_error_maps_and_displays() {
+        let error = EngineError::from(PlatformError::EventLoopCreation("no display".to_string()));
+
+        assert_eq!(error, EngineError::EventLoopCreation("no display".to_string()));
+        assert_eq!(error.to_string(), "Event loop creation failed: no display");
+    }
+
+    #[test]
+    fn platform_execution_error_maps_and_displays() {
+        let error = EngineError::from(PlatformError::EventLoopExecution("lost".to_string()));
+
+        assert_eq!(error.to_string(), "Event loop error: lost");
+    }
+
+    #[test]
+    fn builder_errors_name_the_invalid_field() {
+        assert_eq!(
+            BuilderError::InvalidTps(0.0).to_string(),
+            "TPS must be positive and finite, got 0"
+        );
+        assert_eq!(
+            BuilderError::InvalidTps(f64::INFINITY).to_string(),
+            "TPS must be positive and finite, got inf"
+        );
+        assert_eq!(
+            BuilderError::InvalidChannelCapacity.to_string(),
+            "Channel capacity must be positive"
+        );
+    }
+
+    #[test]
+    fn core_panic_displays_message() {
+        let error = EngineError::CorePanicked("scene exploded".to_string());
+
+        assert_eq!(error.to_string(), "Core thread panicked: scene exploded");
+    }
+}
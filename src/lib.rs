@@ -13,10 +13,17 @@
 pub mod core;
 pub mod prelude;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 mod platform;
 mod engine;
+mod error;
 
 //=== Public API ==========================================================
 
-pub use core::{GlobalContext, GlobalSystems, InputSystem};
-pub use engine::{Engine, EngineBuilder};
+pub use core::{
+    CoreExitReason, EngineCommand, EngineLifecycleEvent, GlobalContext, GlobalSystems, InputSystem,
+};
+pub use engine::{EmbeddedEngine, Engine, EngineBuilder};
+pub use error::{BuilderError, EngineError};
@@ -5,50 +5,154 @@
 // Per-frame input buffer with discrete and continuous event storage.
 //
 // Architecture:
-//   Discrete: Vec (order-preserved, consecutive dedup)
-//   Continuous: HashSet (coalesced, latest-wins)
+//   Discrete: Vec (order-preserved, consecutive dedup, optional tap coalescing)
+//   Continuous: one slot per event kind, merged by that kind's combine fn
 //
-// Discrete handles keys/buttons, continuous handles mouse movement.
+// Discrete handles keys/buttons, continuous handles mouse movement, scroll
+// and raw motion. Positions are absolute, so the latest wins; scroll and
+// raw motion are deltas, so they accumulate within a frame. The position
+// slot always drains first, ahead of the accumulated deltas.
+//
+// Discrete storage has a soft cap (DEFAULT_MAX_DISCRETE); past it the
+// oldest events are dropped so a flood cannot grow the buffer unbounded.
+// The drop warning is logged once per frame, with the total on drain.
+//
+// Early flush: with a threshold set, should_flush_early() reports once that
+// many discrete events are buffered so the platform can send them before
+// the next redraw. Continuous events never trigger it.
 //
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
-use std::collections::HashSet;
 use std::mem;
 
+use log::warn;
+
 //=== Internal Dependencies ===============================================
 
 use crate::core::input::event::InputEvent;
 
+//=== Constants ===========================================================
+
+/// Default soft cap on buffered discrete events per frame.
+///
+/// Far above what a human can produce in one frame; only reached by floods
+/// or a stalled core thread.
+pub(crate) const DEFAULT_MAX_DISCRETE: usize = 4096;
+
+//=== Continuous Coalescing ===============================================
+
+/// Merges a newer continuous event into the buffered one of the same kind.
+type Combine = fn(&mut InputEvent, InputEvent);
+
+/// Returns how continuous events of `event`'s kind coalesce.
+fn combiner(event: &InputEvent) -> Combine {
+    match event {
+        InputEvent::MouseScrolled { .. } | InputEvent::MouseMotion { .. } => accumulate,
+        _ => latest_wins,
+    }
+}
+
+/// Replaces the buffered event (absolute values such as cursor position).
+fn latest_wins(slot: &mut InputEvent, event: InputEvent) {
+    *slot = event;
+}
+
+/// Adds the newer delta to the buffered one (scroll, raw motion).
+fn accumulate(slot: &mut InputEvent, event: InputEvent) {
+    match (slot, event) {
+        (InputEvent::MouseScrolled { dx, dy }, InputEvent::MouseScrolled { dx: ndx, dy: ndy })
+        | (InputEvent::MouseMotion { dx, dy }, InputEvent::MouseMotion { dx: ndx, dy: ndy }) => {
+            *dx += ndx;
+            *dy += ndy;
+        }
+        (slot, event) => *slot = event,
+    }
+}
+
 //=== InputBuffer =========================================================
 
 /// Per-frame input buffer with order-preserving discrete storage and coalescing continuous storage.
-/// Discrete: Vec with consecutive deduplication. Continuous: HashSet with latest-wins replacement.
+/// Discrete: Vec with consecutive deduplication. Continuous: one slot per kind, merged per kind.
 pub(super) struct InputBuffer {
     discrete: Vec<InputEvent>,
-    continuous: HashSet<InputEvent>,
+    continuous: Vec<InputEvent>,
+    coalesce_taps: bool,
+    max_discrete: usize,
+    dropped: usize,
+    early_flush: Option<usize>,
 }
 
 impl InputBuffer {
-    /// Creates buffer with initial capacity (128 discrete, 1 continuous).
+    /// Creates buffer with initial capacity (128 discrete, 3 continuous).
     pub(super) fn new() -> Self {
         Self {
             discrete: Vec::with_capacity(128),
-            // Continuous buffer only holds MouseMoved (max size = 1)
-            continuous: HashSet::with_capacity(1),
+            // One slot per continuous kind (move, scroll, raw motion)
+            continuous: Vec::with_capacity(3),
+            coalesce_taps: false,
+            max_discrete: DEFAULT_MAX_DISCRETE,
+            dropped: 0,
+            early_flush: None,
         }
     }
 
-    /// Adds a continuous event (replaces previous via hash-by-discriminant).
+    /// Enables collapsing `KeyDown` + `KeyUp` of the same key into `KeyTapped`.
+    pub(super) fn with_tap_coalescing(mut self, enabled: bool) -> Self {
+        self.coalesce_taps = enabled;
+        self
+    }
+
+    /// Sets the soft cap on buffered discrete events (at least 1).
+    pub(super) fn with_max_discrete(mut self, max: usize) -> Self {
+        self.max_discrete = max.max(1);
+        self
+    }
+
+    /// Requests an early flush once `threshold` discrete events are buffered
+    /// (at least 1). `None` waits for the frame boundary.
+    pub(super) fn with_early_flush(mut self, threshold: Option<usize>) -> Self {
+        self.early_flush = threshold.map(|threshold| threshold.max(1));
+        self
+    }
+
+    /// Adds a continuous event, merging it with a buffered one of the same
+    /// kind (latest position wins, scroll and raw motion accumulate).
+    ///
+    /// The cursor position is kept ahead of the deltas, whatever order they
+    /// arrived in.
     pub(super) fn push_continuous(&mut self, event: InputEvent) {
-        self.continuous.replace(event);
+        debug_assert!(event.is_continuous(), "discrete event in continuous buffer: {:?}", event);
+        let kind = mem::discriminant(&event);
+        match self.continuous.iter_mut().find(|slot| mem::discriminant(*slot) == kind) {
+            Some(slot) => combiner(&event)(slot, event),
+            None if matches!(event, InputEvent::MouseMoved { .. }) => {
+                self.continuous.insert(0, event)
+            }
+            None => self.continuous.push(event),
+        }
     }
 
     /// Adds a discrete event (ignores consecutive duplicates only).
+    ///
+    /// With tap coalescing enabled, a `KeyUp` directly following a `KeyDown`
+    /// of the same key replaces it with a single `KeyTapped`.
     pub(super) fn push_discrete(&mut self, event: InputEvent) {
+        if self.coalesce_taps {
+            if let (InputEvent::KeyUp { key, modifiers }, Some(last)) =
+                (&event, self.discrete.last_mut())
+            {
+                if matches!(last, InputEvent::KeyDown { key: down, .. } if down == key) {
+                    *last = InputEvent::KeyTapped { key: *key, modifiers: *modifiers };
+                    return;
+                }
+            }
+        }
+
         if self.discrete.last() != Some(&event) {
             self.discrete.push(event);
+            self.enforce_cap();
         }
     }
 
@@ -58,6 +162,16 @@ impl InputBuffer {
             return None;
         }
 
+        if self.dropped > 0 {
+            warn!(
+                target: "platform::input",
+                "Dropped {} discrete events this frame (cap {})",
+                self.dropped,
+                self.max_discrete
+            );
+            self.dropped = 0;
+        }
+
         // Capture capacities before draining
         let discrete_cap = self.discrete.capacity();
         let continuous_cap = self.continuous.capacity();
@@ -65,20 +179,74 @@ impl InputBuffer {
         // Move discrete vec (O(1) - just pointer swap)
         let discrete = mem::take(&mut self.discrete);
 
-        // Drain continuous into vec (O(n) but n is typically 1)
-        let continuous: Vec<_> = self.continuous.drain().collect();
+        // Move continuous vec (at most one event per kind)
+        let continuous = mem::take(&mut self.continuous);
 
         // Restore with original capacities (avoids realloc next frame)
         self.discrete = Vec::with_capacity(discrete_cap);
-        self.continuous = HashSet::with_capacity(continuous_cap);
+        self.continuous = Vec::with_capacity(continuous_cap);
 
         Some((discrete, continuous))
     }
 
+    /// Puts previously drained events back in front of any buffered ones.
+    ///
+    /// Used when a flush could not be delivered. Buffered continuous events
+    /// are newer and are merged on top of restored ones: positions win,
+    /// deltas add up.
+    pub(super) fn restore(&mut self, mut discrete: Vec<InputEvent>, continuous: Vec<InputEvent>) {
+        discrete.append(&mut self.discrete);
+        self.discrete = discrete;
+        self.enforce_cap();
+
+        let newer = mem::replace(&mut self.continuous, continuous);
+        for event in newer {
+            self.push_continuous(event);
+        }
+    }
+
     /// Returns true if both buffers are empty.
     pub(super) fn is_empty(&self) -> bool {
         self.discrete.is_empty() && self.continuous.is_empty()
     }
+
+    /// Returns the number of buffered discrete events.
+    pub(super) fn discrete_len(&self) -> usize {
+        self.discrete.len()
+    }
+
+    /// Returns the number of buffered continuous events.
+    pub(super) fn continuous_len(&self) -> usize {
+        self.continuous.len()
+    }
+
+    /// Returns true if enough discrete events are buffered to flush early.
+    pub(super) fn should_flush_early(&self) -> bool {
+        self.early_flush
+            .is_some_and(|threshold| self.discrete.len() >= threshold)
+    }
+
+    //--- Internal ---------------------------------------------------------
+
+    /// Drops the oldest discrete events beyond the cap, keeping the newest.
+    ///
+    /// Warns on the first drop of a frame; the total is reported on drain.
+    fn enforce_cap(&mut self) {
+        let excess = self.discrete.len().saturating_sub(self.max_discrete);
+        if excess == 0 {
+            return;
+        }
+
+        if self.dropped == 0 {
+            warn!(
+                target: "platform::input",
+                "Discrete input buffer exceeded {} events; dropping oldest",
+                self.max_discrete
+            );
+        }
+        self.discrete.drain(..excess);
+        self.dropped += excess;
+    }
 }
 
 //=========================================================================
@@ -195,7 +363,7 @@ mod tests {
 
         assert_eq!(buffer.continuous.len(), 1);
 
-        let event = buffer.continuous.iter().next().unwrap();
+        let event = buffer.continuous.first().unwrap();
         match event {
             InputEvent::MouseMoved { x, y } => {
                 assert_eq!((*x, *y), (20.0, 30.0));
@@ -215,6 +383,58 @@ mod tests {
         assert_eq!(buffer.continuous.len(), 1, "Size should always be 1");
     }
 
+    fn scroll(dx: f32, dy: f32) -> InputEvent {
+        InputEvent::MouseScrolled { dx, dy }
+    }
+
+    fn motion(dx: f32, dy: f32) -> InputEvent {
+        InputEvent::MouseMotion { dx, dy }
+    }
+
+    #[test]
+    fn position_keeps_latest_while_scroll_and_motion_accumulate() {
+        let mut buffer = InputBuffer::new();
+        buffer.push_continuous(mouse_move(1.0, 1.0));
+        buffer.push_continuous(scroll(0.0, 1.0));
+        buffer.push_continuous(motion(2.0, 0.0));
+        buffer.push_continuous(mouse_move(5.0, 6.0));
+        buffer.push_continuous(scroll(0.5, 2.0));
+        buffer.push_continuous(motion(3.0, -1.0));
+
+        let (_, continuous) = buffer.drain().unwrap();
+
+        assert_eq!(continuous.len(), 3);
+        assert!(matches!(continuous[0], InputEvent::MouseMoved { x, y } if (x, y) == (5.0, 6.0)));
+        assert_eq!(continuous[1], scroll(0.5, 3.0));
+        assert_eq!(continuous[2], motion(5.0, -1.0));
+    }
+
+    #[test]
+    fn position_drains_ahead_of_earlier_deltas() {
+        let mut buffer = InputBuffer::new();
+        buffer.push_continuous(scroll(0.0, 1.0));
+        buffer.push_continuous(mouse_move(3.0, 4.0));
+        buffer.push_continuous(scroll(0.0, 2.0));
+        buffer.push_continuous(mouse_move(7.0, 8.0));
+
+        let (_, continuous) = buffer.drain().unwrap();
+
+        assert_eq!(continuous, vec![mouse_move(7.0, 8.0), scroll(0.0, 3.0)]);
+    }
+
+    #[test]
+    fn restore_adds_newer_deltas_to_restored_ones() {
+        let mut buffer = InputBuffer::new();
+        buffer.push_continuous(scroll(0.0, 1.0));
+        let (discrete, continuous) = buffer.drain().unwrap();
+
+        buffer.push_continuous(scroll(0.0, -3.0));
+        buffer.restore(discrete, continuous);
+
+        let (_, continuous) = buffer.drain().unwrap();
+        assert_eq!(continuous, vec![scroll(0.0, -2.0)]);
+    }
+
     //=====================================================================
     // Mixed Event Tests
     //=====================================================================
@@ -367,4 +587,226 @@ mod tests {
         buffer.drain();
         assert!(buffer.is_empty());
     }
-}
\ No newline at end of file
+
+    //=====================================================================
+    // Length Tests
+    //=====================================================================
+
+    #[test]
+    fn lengths_on_new_buffer_are_zero() {
+        let buffer = InputBuffer::new();
+        assert_eq!(buffer.discrete_len(), 0);
+        assert_eq!(buffer.continuous_len(), 0);
+    }
+
+    #[test]
+    fn lengths_track_pushes() {
+        let mut buffer = InputBuffer::new();
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        buffer.push_discrete(mouse_down(MouseButton::Left));
+        buffer.push_continuous(mouse_move(1.0, 1.0));
+        buffer.push_continuous(mouse_move(2.0, 2.0));
+
+        // Consecutive duplicate dropped, mouse moves coalesced
+        assert_eq!(buffer.discrete_len(), 2);
+        assert_eq!(buffer.continuous_len(), 1);
+    }
+
+    #[test]
+    fn lengths_reset_after_drain() {
+        let mut buffer = InputBuffer::new();
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        buffer.push_continuous(mouse_move(1.0, 1.0));
+        buffer.drain();
+
+        assert_eq!(buffer.discrete_len(), 0);
+        assert_eq!(buffer.continuous_len(), 0);
+    }
+
+    //=====================================================================
+    // Tap Coalescing Tests
+    //=====================================================================
+
+    fn key_up(key: KeyCode) -> InputEvent {
+        InputEvent::KeyUp {
+            key,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    fn key_tapped(key: KeyCode) -> InputEvent {
+        InputEvent::KeyTapped {
+            key,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    fn push_down_up_down(buffer: &mut InputBuffer) {
+        buffer.push_discrete(key_down(KeyCode::Space));
+        buffer.push_discrete(key_up(KeyCode::Space));
+        buffer.push_discrete(key_down(KeyCode::Space));
+    }
+
+    #[test]
+    fn taps_not_coalesced_by_default() {
+        let mut buffer = InputBuffer::new();
+        push_down_up_down(&mut buffer);
+
+        let (discrete, _) = buffer.drain().unwrap();
+        assert_eq!(
+            discrete,
+            vec![key_down(KeyCode::Space), key_up(KeyCode::Space), key_down(KeyCode::Space)]
+        );
+    }
+
+    #[test]
+    fn taps_coalesced_when_enabled() {
+        let mut buffer = InputBuffer::new().with_tap_coalescing(true);
+        push_down_up_down(&mut buffer);
+
+        let (discrete, _) = buffer.drain().unwrap();
+        assert_eq!(discrete, vec![key_tapped(KeyCode::Space), key_down(KeyCode::Space)]);
+    }
+
+    #[test]
+    fn coalescing_requires_same_key() {
+        let mut buffer = InputBuffer::new().with_tap_coalescing(true);
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        buffer.push_discrete(key_up(KeyCode::KeyB));
+
+        let (discrete, _) = buffer.drain().unwrap();
+        assert_eq!(discrete, vec![key_down(KeyCode::KeyA), key_up(KeyCode::KeyB)]);
+    }
+
+    #[test]
+    fn coalescing_requires_adjacent_events() {
+        let mut buffer = InputBuffer::new().with_tap_coalescing(true);
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        buffer.push_discrete(mouse_down(MouseButton::Left));
+        buffer.push_discrete(key_up(KeyCode::KeyA));
+
+        assert_eq!(buffer.discrete_len(), 3);
+    }
+
+    //--- Restore ------------------------------------------------------------
+
+    #[test]
+    fn restore_prepends_discrete_and_keeps_newer_continuous() {
+        let mut buffer = InputBuffer::new();
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        buffer.push_continuous(mouse_move(1.0, 1.0));
+        let (discrete, continuous) = buffer.drain().unwrap();
+
+        buffer.push_discrete(key_down(KeyCode::KeyB));
+        buffer.push_continuous(mouse_move(2.0, 2.0));
+        buffer.restore(discrete, continuous);
+
+        let (discrete, continuous) = buffer.drain().unwrap();
+        assert_eq!(discrete, vec![key_down(KeyCode::KeyA), key_down(KeyCode::KeyB)]);
+        assert!(matches!(continuous[..], [InputEvent::MouseMoved { x, .. }] if x == 2.0));
+    }
+
+    //--- Overflow -----------------------------------------------------------
+
+    fn push_keys(buffer: &mut InputBuffer, keys: &[KeyCode]) {
+        for &key in keys {
+            buffer.push_discrete(key_down(key));
+        }
+    }
+
+    #[test]
+    fn default_cap_is_not_reached_by_normal_input() {
+        let mut buffer = InputBuffer::new();
+        for i in 0..1024 {
+            let key = if i % 2 == 0 { KeyCode::KeyA } else { KeyCode::KeyB };
+            buffer.push_discrete(key_down(key));
+        }
+
+        assert_eq!(buffer.discrete_len(), 1024);
+        assert_eq!(buffer.dropped, 0);
+    }
+
+    #[test]
+    fn overflow_drops_oldest_and_keeps_newest() {
+        let mut buffer = InputBuffer::new().with_max_discrete(3);
+        let keys = [KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE];
+        push_keys(&mut buffer, &keys);
+
+        assert_eq!(buffer.discrete_len(), 3);
+        assert_eq!(buffer.dropped, 2);
+
+        let (discrete, _) = buffer.drain().unwrap();
+        assert_eq!(
+            discrete,
+            vec![key_down(KeyCode::KeyC), key_down(KeyCode::KeyD), key_down(KeyCode::KeyE)]
+        );
+    }
+
+    #[test]
+    fn drop_count_resets_on_drain() {
+        let mut buffer = InputBuffer::new().with_max_discrete(1);
+        push_keys(&mut buffer, &[KeyCode::KeyA, KeyCode::KeyB]);
+        assert_eq!(buffer.dropped, 1);
+
+        buffer.drain();
+        assert_eq!(buffer.dropped, 0);
+
+        buffer.push_discrete(key_down(KeyCode::KeyC));
+        assert_eq!(buffer.dropped, 0);
+    }
+
+    #[test]
+    fn restore_respects_cap() {
+        let mut buffer = InputBuffer::new().with_max_discrete(2);
+        push_keys(&mut buffer, &[KeyCode::KeyA, KeyCode::KeyB]);
+        let (discrete, continuous) = buffer.drain().unwrap();
+
+        buffer.push_discrete(key_down(KeyCode::KeyC));
+        buffer.restore(discrete, continuous);
+
+        let (discrete, _) = buffer.drain().unwrap();
+        assert_eq!(discrete, vec![key_down(KeyCode::KeyB), key_down(KeyCode::KeyC)]);
+    }
+
+    #[test]
+    fn zero_cap_keeps_one_event() {
+        let mut buffer = InputBuffer::new().with_max_discrete(0);
+        push_keys(&mut buffer, &[KeyCode::KeyA, KeyCode::KeyB]);
+        assert_eq!(buffer.discrete_len(), 1);
+    }
+
+    //--- Early Flush ------------------------------------------------------
+
+    #[test]
+    fn early_flush_is_off_by_default() {
+        let mut buffer = InputBuffer::new();
+        push_keys(&mut buffer, &[KeyCode::KeyA, KeyCode::KeyB]);
+        assert!(!buffer.should_flush_early());
+    }
+
+    #[test]
+    fn early_flush_triggers_at_threshold() {
+        let mut buffer = InputBuffer::new().with_early_flush(Some(2));
+
+        buffer.push_continuous(mouse_move(1.0, 1.0));
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        assert!(!buffer.should_flush_early());
+
+        buffer.push_discrete(key_down(KeyCode::KeyB));
+        assert!(buffer.should_flush_early());
+
+        buffer.drain();
+        assert!(!buffer.should_flush_early());
+    }
+
+    #[test]
+    fn continuous_events_never_trigger_early_flush() {
+        let mut buffer = InputBuffer::new().with_early_flush(Some(0));
+        buffer.push_continuous(mouse_move(1.0, 1.0));
+        assert!(!buffer.should_flush_early());
+
+        buffer.push_discrete(key_down(KeyCode::KeyA));
+        assert!(buffer.should_flush_early());
+    }
+}
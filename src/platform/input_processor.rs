@@ -9,22 +9,37 @@
 //
 // Stateful modifier tracking: Caches modifier state from ModifiersChanged
 // events and applies to all subsequent key/mouse events. Unmapped keys
-// (F13-F24, exotic keyboards) are filtered (returns None).
+// (F13-F24, exotic keyboards) are filtered (returns None). With unmapped
+// key warnings on (debug builds by default), each distinct winit key is
+// logged once at `warn` the first time it is dropped. This is the only place
+// such keys are reported: they never become InputEvent::Unidentified, and
+// StateTracker has no winit code to log, so it keeps ignoring that variant.
+//
+// Logical keys: on press, the character the layout produced is reported
+// alongside the physical key (KeyChar) for prompt text.
 //
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
+use std::collections::HashSet;
+
+use log::warn;
 use winit::{
     event::ElementState,
-    event::{KeyEvent, MouseButton as WinitMouseButton},
-    keyboard::{KeyCode as WinitKeyCode, ModifiersState, PhysicalKey},
+    event::{KeyEvent, MouseButton as WinitMouseButton, MouseScrollDelta},
+    keyboard::{Key, KeyCode as WinitKeyCode, ModifiersState, PhysicalKey},
 };
 
 //=== Internal Dependencies ===============================================
 
 use crate::core::input::event::{InputEvent, KeyCode, Modifiers, MouseButton};
 
+//=== Constants ===========================================================
+
+/// Pixels per scroll line, for converting touchpad (pixel) scrolling.
+const PIXELS_PER_SCROLL_LINE: f32 = 20.0;
+
 //=== InputProcessor ======================================================
 
 /// Converts Winit events to engine InputEvents with stateful modifier tracking.
@@ -32,6 +47,8 @@ use crate::core::input::event::{InputEvent, KeyCode, Modifiers, MouseButton};
 /// Filters unmapped keys and applies cached modifier state to all events.
 pub(crate) struct InputProcessor {
     current_modifiers: Modifiers,
+    warn_unmapped: bool,
+    reported_unmapped: HashSet<PhysicalKey>,
 }
 
 impl InputProcessor {
@@ -40,9 +57,16 @@ impl InputProcessor {
     pub(crate) fn new() -> Self {
         Self {
             current_modifiers: Modifiers::NONE,
+            warn_unmapped: cfg!(debug_assertions),
+            reported_unmapped: HashSet::new(),
         }
     }
 
+    /// Enables logging keys that have no engine [`KeyCode`].
+    pub(crate) fn set_unmapped_key_warnings(&mut self, enabled: bool) {
+        self.warn_unmapped = enabled;
+    }
+
     //--- Modifier State Management ----------------------------------------
 
     /// Updates cached modifier state (applied to subsequent events).
@@ -50,6 +74,7 @@ impl InputProcessor {
         self.current_modifiers = Modifiers::from(modifiers_state);
     }
 
+    #[cfg(test)]
     pub(crate) fn current_modifiers(&self) -> Modifiers {
         self.current_modifiers
     }
@@ -57,19 +82,30 @@ impl InputProcessor {
     //--- Event Processing -------------------------------------------------
 
     /// Converts Winit KeyEvent to InputEvent (filters unmapped keys).
-    pub(crate) fn process_key_event(&self, key_event: &KeyEvent) -> Option<InputEvent> {
+    pub(crate) fn process_key_event(&mut self, key_event: &KeyEvent) -> Option<InputEvent> {
         let key_code = match key_event.physical_key {
             PhysicalKey::Code(code) => KeyCode::from(code),
-            _ => return None,
+            PhysicalKey::Unidentified(_) => KeyCode::Unidentified,
         };
 
         if matches!(key_code, KeyCode::Unidentified) {
+            self.report_unmapped(key_event.physical_key);
             return None;
         }
 
         Some(self.create_key_input_event(key_code, key_event.state))
     }
 
+    /// Reports the character a key press produced (`None` for releases,
+    /// named keys, and multi-character input).
+    pub(crate) fn process_logical_key(&self, key_event: &KeyEvent) -> Option<InputEvent> {
+        if key_event.state != ElementState::Pressed {
+            return None;
+        }
+
+        self.create_key_char_event(key_event.physical_key, &key_event.logical_key)
+    }
+
     /// Converts Winit mouse button event to InputEvent (with modifiers).
     pub(crate) fn process_mouse_button(
         &self,
@@ -95,8 +131,59 @@ impl InputProcessor {
         InputEvent::MouseMoved { x, y }
     }
 
+    /// Converts a wheel delta to a scroll event in lines.
+    ///
+    /// Pixel deltas (touchpads) are scaled by [`PIXELS_PER_SCROLL_LINE`].
+    pub(crate) fn process_mouse_wheel(&self, delta: MouseScrollDelta) -> InputEvent {
+        let (dx, dy) = match delta {
+            MouseScrollDelta::LineDelta(dx, dy) => (dx, dy),
+            MouseScrollDelta::PixelDelta(position) => (
+                position.x as f32 / PIXELS_PER_SCROLL_LINE,
+                position.y as f32 / PIXELS_PER_SCROLL_LINE,
+            ),
+        };
+        InputEvent::MouseScrolled { dx, dy }
+    }
+
+    /// Creates a raw motion event from a device delta.
+    pub(crate) fn process_mouse_motion(&self, dx: f64, dy: f64) -> InputEvent {
+        InputEvent::MouseMotion { dx: dx as f32, dy: dy as f32 }
+    }
+
     //--- Internal Helpers -------------------------------------------------
 
+    /// Logs the first drop of each unmapped key. Returns `true` if it logged.
+    fn report_unmapped(&mut self, physical: PhysicalKey) -> bool {
+        if !self.warn_unmapped || !self.reported_unmapped.insert(physical) {
+            return false;
+        }
+
+        warn!(
+            target: "platform::input",
+            "Unmapped key {:?} ignored; it has no KeyCode and cannot be bound",
+            physical
+        );
+        true
+    }
+
+    fn create_key_char_event(&self, physical: PhysicalKey, logical: &Key) -> Option<InputEvent> {
+        let key = match physical {
+            PhysicalKey::Code(code) => KeyCode::from(code),
+            _ => return None,
+        };
+
+        let Key::Character(text) = logical else {
+            return None;
+        };
+
+        let mut chars = text.chars();
+        match (key, chars.next(), chars.next()) {
+            (KeyCode::Unidentified, _, _) => None,
+            (key, Some(ch), None) => Some(InputEvent::KeyChar { key, ch }),
+            _ => None,
+        }
+    }
+
     fn create_key_input_event(&self, key: KeyCode, state: ElementState) -> InputEvent {
         match state {
             ElementState::Pressed => InputEvent::KeyDown {
@@ -222,6 +309,9 @@ impl From<WinitMouseButton> for MouseButton {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::RefCell;
+    use std::sync::Once;
+    use winit::dpi::PhysicalPosition;
     use winit::keyboard::KeyCode as WinitKeyCode;
 
     fn make_modifiers(shift: bool, ctrl: bool, alt: bool) -> ModifiersState {
@@ -288,6 +378,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn key_char_pairs_physical_and_logical_key() {
+        let processor = InputProcessor::new();
+
+        // AZERTY: the physical Q position types 'a'
+        let event = processor.create_key_char_event(
+            PhysicalKey::Code(WinitKeyCode::KeyQ),
+            &Key::Character("a".into()),
+        );
+
+        assert_eq!(event, Some(InputEvent::KeyChar { key: KeyCode::KeyQ, ch: 'a' }));
+    }
+
+    #[test]
+    fn key_char_ignores_named_and_multi_char_keys() {
+        let processor = InputProcessor::new();
+        let space = PhysicalKey::Code(WinitKeyCode::Space);
+
+        let named = Key::Named(winit::keyboard::NamedKey::Space);
+        assert_eq!(processor.create_key_char_event(space, &named), None);
+        assert_eq!(processor.create_key_char_event(space, &Key::Character("ab".into())), None);
+        assert_eq!(
+            processor.create_key_char_event(
+                PhysicalKey::Code(WinitKeyCode::F13),
+                &Key::Character("x".into()),
+            ),
+            None
+        );
+    }
+
     #[test]
     fn keycode_conversion_filters_unidentified() {
         // Test conversion directly
@@ -295,6 +415,72 @@ mod tests {
         assert!(matches!(unidentified, KeyCode::Unidentified));
     }
 
+    //--- Unmapped Key Warnings --------------------------------------------
+
+    thread_local! {
+        static CAPTURED: RefCell<Vec<(log::Level, String)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Records log output per thread, so parallel tests don't see each other's.
+    struct CaptureLogger;
+
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            CAPTURED.with(|records| {
+                records.borrow_mut().push((record.level(), record.args().to_string()))
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Runs `f` and returns the log records it emitted on this thread.
+    fn capture_logs(f: impl FnOnce()) -> Vec<(log::Level, String)> {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            log::set_logger(&CaptureLogger).expect("no other logger installed in tests");
+            log::set_max_level(log::LevelFilter::Trace);
+        });
+
+        CAPTURED.with(|records| records.borrow_mut().clear());
+        f();
+        CAPTURED.with(|records| records.take())
+    }
+
+    #[test]
+    fn unmapped_keys_are_reported_once_when_enabled() {
+        let mut processor = InputProcessor::new();
+        processor.set_unmapped_key_warnings(true);
+
+        let records = capture_logs(|| {
+            processor.report_unmapped(PhysicalKey::Code(WinitKeyCode::F13));
+            processor.report_unmapped(PhysicalKey::Code(WinitKeyCode::F13));
+            processor.report_unmapped(PhysicalKey::Code(WinitKeyCode::F14));
+        });
+
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|(level, _)| *level == log::Level::Warn));
+        assert!(records[0].1.contains("F13"), "{}", records[0].1);
+        assert!(records[1].1.contains("F14"), "{}", records[1].1);
+    }
+
+    #[test]
+    fn unmapped_keys_are_quiet_when_disabled() {
+        let mut processor = InputProcessor::new();
+        processor.set_unmapped_key_warnings(false);
+
+        let records = capture_logs(|| {
+            processor.report_unmapped(PhysicalKey::Code(WinitKeyCode::F13));
+        });
+
+        assert!(records.is_empty());
+        assert!(processor.reported_unmapped.is_empty());
+    }
+
     #[test]
     fn mouse_button_has_modifiers() {
         let mut processor = InputProcessor::new();
@@ -328,6 +514,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mouse_wheel_is_reported_in_lines() {
+        let processor = InputProcessor::new();
+
+        let lines = processor.process_mouse_wheel(MouseScrollDelta::LineDelta(0.0, -2.0));
+        let pixels = processor.process_mouse_wheel(MouseScrollDelta::PixelDelta(
+            PhysicalPosition::new(10.0, 40.0),
+        ));
+
+        assert_eq!(lines, InputEvent::MouseScrolled { dx: 0.0, dy: -2.0 });
+        assert_eq!(pixels, InputEvent::MouseScrolled { dx: 0.5, dy: 2.0 });
+    }
+
     #[test]
     fn modifiers_persist_across_events() {
         let mut processor = InputProcessor::new();
@@ -5,61 +5,163 @@
 // Winit adapter: translates OS events to engine InputEvents via MPSC.
 //
 // Architecture:
-//   Winit Events → InputProcessor → InputBuffer → PlatformEvent (MPSC) → Core
+//   Winit Events → InputProcessor → InputBuffer (per window) → PlatformEvent (MPSC) → Core
+//   Core → PlatformCommand (MPSC) → window management (open/close, cursor, title/state)
 //
-// Frame Boundary: RedrawRequested triggers flush of all buffered input.
+// Commands that need the primary window are deferred until `resumed`
+// creates it.
+//
+// Frame Boundary: RedrawRequested triggers flush of the window's buffered input.
+// While redraws are paused (no scene needs one), input is flushed from
+// about_to_wait instead. With early flush enabled, discrete events are sent
+// as soon as the threshold is reached; mouse movement still waits.
+//
+// Backpressure: flushes never block. If the bounded channel to the core is
+// full, input stays buffered and is merged into the next flush.
 //
 // Thread Model: Must run on main thread (macOS/iOS requirement).
 //
+// Hosting: `run` owns the event loop; `run_on` accepts one created by the
+// caller, and `hosted()` lets an embedding app drive the handler itself.
+//
 //=========================================================================
 
 //=== External Dependencies ===============================================
 
-use crossbeam_channel::Sender;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crossbeam_channel::{Receiver, Sender, TryRecvError, TrySendError};
 use log::*;
 use winit::{
     application::ApplicationHandler,
-    dpi::LogicalSize,
-    event::WindowEvent,
+    dpi::{LogicalSize, PhysicalPosition},
+    event::{DeviceEvent, DeviceId, WindowEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
-    window::{Window, WindowAttributes},
+    window::{CursorGrabMode, Window, WindowAttributes, WindowId, WindowLevel},
 };
 
 //=== Internal Dependencies ===============================================
 
 use input_buffer::InputBuffer;
+pub(crate) use input_buffer::DEFAULT_MAX_DISCRETE;
 use input_processor::InputProcessor;
 
-use crate::core::platform_bridge::{PlatformError, PlatformEvent};
+use crate::core::input::event::InputEvent;
+use crate::core::platform_bridge::{
+    self, CoreExitReason, PlatformCommand, PlatformError, PlatformEvent, TickControl, Watchdog,
+};
 
 //=== Module Declarations =================================================
 
 mod input_buffer;
 mod input_processor;
 
+//=== Constants ===========================================================
+
+/// Discrete events per window per frame above which an `InputBurst` is reported.
+const INPUT_BURST_THRESHOLD: usize = 32;
+
 //=== Platform ============================================================
 
-/// Winit wrapper: manages window and sends input to core thread.
+/// Winit wrapper: manages windows and sends per-window input to core thread.
+///
+/// The primary window is created on `resumed`; closing it shuts the engine
+/// down. Secondary windows are opened/closed via [`PlatformCommand`]s.
 pub(crate) struct Platform {
-    window: Option<Window>,
-    buffer: InputBuffer,
+    windows: HashMap<WindowId, Window>,
+    primary_window: Option<WindowId>,
+    buffers: HashMap<WindowId, InputBuffer>,
+    window_sizes: HashMap<WindowId, (f32, f32)>,
+    deferred_commands: Vec<PlatformCommand>,
     event_sender: Sender<PlatformEvent>,
+    command_receiver: Receiver<PlatformCommand>,
     input_processor: InputProcessor,
+    owns_event_loop: bool,
+    coalesce_taps: bool,
+    max_discrete: usize,
+    early_flush: Option<usize>,
+    clamp_mouse: bool,
+    redraw_enabled: bool,
+    relative_mouse: bool,
+    backpressured: bool,
+    watchdog: Option<Watchdog>,
 }
 
 impl Platform {
     //--- Construction -----------------------------------------------------
 
-    pub fn new(event_sender: Sender<PlatformEvent>) -> Self {
+    pub fn new(
+        event_sender: Sender<PlatformEvent>,
+        command_receiver: Receiver<PlatformCommand>,
+    ) -> Self {
         info!(target: "platform", "Platform subsystem initialized");
         Self {
-            window: None,
-            buffer: InputBuffer::new(),
+            windows: HashMap::new(),
+            primary_window: None,
+            buffers: HashMap::new(),
+            window_sizes: HashMap::new(),
+            deferred_commands: Vec::new(),
             event_sender,
+            command_receiver,
             input_processor: InputProcessor::new(),
+            owns_event_loop: true,
+            coalesce_taps: false,
+            max_discrete: DEFAULT_MAX_DISCRETE,
+            early_flush: None,
+            clamp_mouse: false,
+            redraw_enabled: true,
+            relative_mouse: false,
+            backpressured: false,
+            watchdog: None,
         }
     }
 
+    /// Enables collapsing same-frame key press/release pairs into taps.
+    pub(crate) fn with_tap_coalescing(mut self, enabled: bool) -> Self {
+        self.coalesce_taps = enabled;
+        self
+    }
+
+    /// Sets the soft cap on discrete events buffered per window and frame.
+    pub(crate) fn with_max_discrete_events(mut self, max: usize) -> Self {
+        self.max_discrete = max;
+        self
+    }
+
+    /// Flushes a window's input once `threshold` discrete events are buffered.
+    pub(crate) fn with_early_flush(mut self, threshold: Option<usize>) -> Self {
+        self.early_flush = threshold;
+        self
+    }
+
+    /// Checks the core thread's heartbeat on every event loop iteration.
+    pub(crate) fn with_watchdog(mut self, watchdog: Option<Watchdog>) -> Self {
+        self.watchdog = watchdog;
+        self
+    }
+
+    /// Enables clamping cursor positions to the bounds of their window.
+    pub(crate) fn with_mouse_clamping(mut self, enabled: bool) -> Self {
+        self.clamp_mouse = enabled;
+        self
+    }
+
+    /// Logs a warning the first time each unmapped key is pressed.
+    pub(crate) fn with_unmapped_key_warnings(mut self, enabled: bool) -> Self {
+        self.input_processor.set_unmapped_key_warnings(enabled);
+        self
+    }
+
+    /// Marks the platform as hosted by an external application.
+    ///
+    /// A hosted platform never calls `ActiveEventLoop::exit`; closing the
+    /// primary window only notifies the core thread and drops the window.
+    pub(crate) fn hosted(mut self) -> Self {
+        self.owns_event_loop = false;
+        self
+    }
+
     //--- Execution --------------------------------------------------------
 
     /// Starts Winit event loop (never returns normally).
@@ -69,54 +171,116 @@ impl Platform {
     ///
     /// # Panics
     /// Panics if called off main thread (macOS/iOS).
-    pub fn run(mut self) -> Result<(), PlatformError> {
-        debug!(target: "platform", "Starting Winit event loop");
-
+    pub fn run(&mut self) -> Result<(), PlatformError> {
         let event_loop = EventLoop::new()
             .map_err(|e| PlatformError::EventLoopCreation(e.to_string()))?;
 
+        self.run_on(event_loop)
+    }
+
+    /// Runs on an event loop created by the caller (never returns normally).
+    ///
+    /// # Errors
+    /// Returns `PlatformError` if the event loop fails while running.
+    pub fn run_on(&mut self, event_loop: EventLoop<()>) -> Result<(), PlatformError> {
+        debug!(target: "platform", "Starting Winit event loop");
+
         event_loop.set_control_flow(ControlFlow::Poll);
 
-        event_loop.run_app(&mut self)
+        event_loop.run_app(self)
             .map_err(|e| PlatformError::EventLoopExecution(e.to_string()))
     }
 
     //--- Internal ---------------------------------------------------------
 
-    fn flush_input_buffer(&mut self) {
-        if let Some((discrete, continuous)) = self.buffer.drain() {
-            trace!(
-                target: "platform::input",
-                "Flushing {} discrete + {} continuous events",
-                discrete.len(),
-                continuous.len()
-            );
+    fn buffer_for(&mut self, window_id: WindowId) -> &mut InputBuffer {
+        let (coalesce_taps, max_discrete, early_flush) =
+            (self.coalesce_taps, self.max_discrete, self.early_flush);
+        self.buffers.entry(window_id).or_insert_with(|| {
+            InputBuffer::new()
+                .with_tap_coalescing(coalesce_taps)
+                .with_max_discrete(max_discrete)
+                .with_early_flush(early_flush)
+        })
+    }
 
-            if self.event_sender.send(PlatformEvent::Inputs { discrete, continuous }).is_err() {
-                warn!(target: "platform::input", "Channel disconnected, dropping events");
-            }
+    /// Buffers a discrete event, flushing right away if the buffer asks to.
+    fn push_discrete(&mut self, window_id: WindowId, event: InputEvent) {
+        let buffer = self.buffer_for(window_id);
+        buffer.push_discrete(event);
+
+        if buffer.should_flush_early() {
+            self.flush_input_buffer(window_id);
         }
     }
 
-    #[cfg(test)]
-    pub(crate) fn window(&self) -> Option<&Window> {
-        self.window.as_ref()
-    }
-}
+    fn flush_input_buffer(&mut self, window_id: WindowId) {
+        let Some(buffer) = self.buffers.get_mut(&window_id) else {
+            return;
+        };
 
-//=== Winit Integration ===================================================
+        let discrete_len = buffer.discrete_len();
+        let continuous_len = buffer.continuous_len();
 
-impl ApplicationHandler for Platform {
-    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        if self.window.is_some() {
-            debug!(target: "platform", "Window already exists (mobile resume?)");
-            return;
+        if let Some((discrete, continuous)) = buffer.drain() {
+            trace!(
+                target: "platform::input",
+                "Flushing {} discrete + {} continuous events for {:?}",
+                discrete_len,
+                continuous_len,
+                window_id
+            );
+
+            let event = PlatformEvent::Inputs {
+                window: window_id.into(),
+                discrete,
+                continuous,
+            };
+
+            // Never block the UI thread: a full channel keeps the input
+            // buffered for the next flush, where it merges with newer input
+            match self.event_sender.try_send(event) {
+                Ok(()) => {
+                    if self.backpressured {
+                        debug!(target: "platform::input", "Core caught up, input channel drained");
+                        self.backpressured = false;
+                    }
+                    // Only after delivery, so a held batch is reported once
+                    // and the diagnostic never takes the batch's slot
+                    if discrete_len > INPUT_BURST_THRESHOLD {
+                        let _ = self.event_sender.try_send(PlatformEvent::InputBurst {
+                            window: window_id.into(),
+                            discrete: discrete_len,
+                        });
+                    }
+                }
+                Err(TrySendError::Full(event)) => {
+                    if !self.backpressured {
+                        warn!(
+                            target: "platform::input",
+                            "Input channel full (capacity {:?}), core is falling behind; holding input",
+                            self.event_sender.capacity()
+                        );
+                        self.backpressured = true;
+                    }
+                    if let PlatformEvent::Inputs { discrete, continuous, .. } = event {
+                        buffer.restore(discrete, continuous);
+                    }
+                }
+                Err(TrySendError::Disconnected(_)) => {
+                    warn!(target: "platform::input", "Channel disconnected, dropping events");
+                }
+            }
         }
+    }
 
-        let attrs = WindowAttributes::default()
-            .with_title("Aetheric Engine")
-            .with_inner_size(LogicalSize::new(800, 600));
+    //--- Window Management ------------------------------------------------
 
+    fn create_window(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        attrs: WindowAttributes,
+    ) -> Option<WindowId> {
         match event_loop.create_window(attrs) {
             Ok(window) => {
                 info!(
@@ -126,29 +290,211 @@ impl ApplicationHandler for Platform {
                     window.inner_size().height,
                     window.scale_factor()
                 );
+                let id = window.id();
+                let size = window.inner_size();
+                self.window_sizes.insert(id, (size.width as f32, size.height as f32));
                 window.request_redraw();
-                self.window = Some(window);
+                self.windows.insert(id, window);
+                let _ = self.event_sender.send(PlatformEvent::WindowCreated(id.into()));
+                Some(id)
             }
             Err(e) => {
                 error!(target: "platform", "Window creation failed: {}", e);
+                None
+            }
+        }
+    }
 
-                let _ = self.event_sender.send(PlatformEvent::WindowClosed);
-                event_loop.exit();
+    /// Destroys a secondary window and notifies the core thread.
+    fn close_secondary_window(&mut self, window_id: WindowId) {
+        if self.primary_window == Some(window_id) {
+            warn!(target: "platform", "Refusing to close primary window via command");
+            return;
+        }
+
+        self.buffers.remove(&window_id);
+        self.window_sizes.remove(&window_id);
+        if self.windows.remove(&window_id).is_some() {
+            debug!(target: "platform", "Secondary window {:?} closed", window_id);
+            let _ = self.event_sender.send(PlatformEvent::WindowDestroyed(window_id.into()));
+        }
+    }
+
+    fn process_commands(&mut self, event_loop: &ActiveEventLoop) {
+        loop {
+            let command = match self.command_receiver.try_recv() {
+                Ok(command) => command,
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    // Core thread has stopped; nothing left to drive
+                    if self.owns_event_loop {
+                        info!(target: "platform", "Core thread stopped, exiting event loop");
+                        event_loop.exit();
+                    }
+                    return;
+                }
+            };
+
+            match command {
+                PlatformCommand::OpenWindow { title, width, height } => {
+                    let attrs = WindowAttributes::default()
+                        .with_title(title)
+                        .with_inner_size(LogicalSize::new(width, height));
+                    self.create_window(event_loop, attrs);
+                }
+                PlatformCommand::CloseWindow(window) => {
+                    self.close_secondary_window(window.into());
+                }
+                PlatformCommand::SetRedraw(enabled) => self.set_redraw(enabled),
+                PlatformCommand::SetRelativeMouse(enabled) => self.relative_mouse = enabled,
+                command @ (PlatformCommand::SetCursorVisible(_)
+                | PlatformCommand::SetCursorGrab(_)
+                | PlatformCommand::SetAlwaysOnTop(_)
+                | PlatformCommand::Minimize
+                | PlatformCommand::Maximize
+                | PlatformCommand::SetTitle(_)) => {
+                    if let Err(e) = self.apply_window_command(command) {
+                        debug!(target: "platform", "{}; deferring window command", e);
+                    }
+                }
+                PlatformCommand::Shutdown => {
+                    error!(target: "platform", "Core thread failed, shutting down");
+                    self.windows.clear();
+                    self.buffers.clear();
+                    if self.owns_event_loop {
+                        event_loop.exit();
+                    }
+                    return;
+                }
             }
         }
     }
 
-    fn window_event(
+    /// Applies a command that targets the primary window.
+    ///
+    /// Returns `PlatformError::NoWindow` and defers the command if the
+    /// primary window does not exist yet; see `apply_deferred_commands`.
+    fn apply_window_command(&mut self, command: PlatformCommand) -> Result<(), PlatformError> {
+        let Some(window) = self.primary_window.and_then(|id| self.windows.get(&id)) else {
+            self.deferred_commands.push(command);
+            return Err(PlatformError::NoWindow);
+        };
+
+        match command {
+            PlatformCommand::SetCursorVisible(visible) => window.set_cursor_visible(visible),
+            PlatformCommand::SetCursorGrab(grab) => {
+                let result = if grab {
+                    // Not every platform supports both modes
+                    window.set_cursor_grab(CursorGrabMode::Confined)
+                        .or_else(|_| window.set_cursor_grab(CursorGrabMode::Locked))
+                } else {
+                    window.set_cursor_grab(CursorGrabMode::None)
+                };
+                if let Err(e) = result {
+                    warn!(target: "platform", "Cursor grab failed: {}", e);
+                }
+            }
+            PlatformCommand::SetAlwaysOnTop(enabled) => window.set_window_level(if enabled {
+                WindowLevel::AlwaysOnTop
+            } else {
+                WindowLevel::Normal
+            }),
+            PlatformCommand::Minimize => window.set_minimized(true),
+            PlatformCommand::Maximize => window.set_maximized(true),
+            PlatformCommand::SetTitle(title) => window.set_title(&title),
+            other => warn!(target: "platform", "Not a window command: {:?}", other),
+        }
+
+        Ok(())
+    }
+
+    /// Pauses or resumes the continuous redraw loop.
+    fn set_redraw(&mut self, enabled: bool) {
+        debug!(target: "platform", "Continuous redraw {}", if enabled { "resumed" } else { "paused" });
+        self.redraw_enabled = enabled;
+
+        // Restart the RedrawRequested chain that stopped while paused
+        if enabled {
+            for window in self.windows.values() {
+                window.request_redraw();
+            }
+        }
+    }
+
+    /// Flushes all window buffers while redraws are paused.
+    ///
+    /// Without redraws there is no `RedrawRequested` frame boundary, so
+    /// input would otherwise never reach the core thread.
+    fn flush_idle_input(&mut self) {
+        if self.redraw_enabled {
+            return;
+        }
+
+        let window_ids: Vec<WindowId> = self.buffers.keys().copied().collect();
+        for window_id in window_ids {
+            self.flush_input_buffer(window_id);
+            self.recenter_cursor(window_id);
+        }
+    }
+
+    /// Warps the cursor to the primary window's center in relative mode.
+    ///
+    /// Queues a `CursorWarped` event for the next flush so the core moves
+    /// its baseline instead of reporting the jump as motion.
+    fn recenter_cursor(&mut self, window_id: WindowId) {
+        if !self.relative_mouse || self.primary_window != Some(window_id) {
+            return;
+        }
+        let Some(window) = self.windows.get(&window_id) else {
+            return;
+        };
+
+        let (width, height) = self.window_sizes.get(&window_id).copied().unwrap_or_else(|| {
+            let size = window.inner_size();
+            (size.width as f32, size.height as f32)
+        });
+        let (x, y) = (width / 2.0, height / 2.0);
+
+        match window.set_cursor_position(PhysicalPosition::new(x as f64, y as f64)) {
+            Ok(()) => self.buffer_for(window_id).push_discrete(InputEvent::CursorWarped { x, y }),
+            Err(e) => {
+                warn!(target: "platform", "Cursor warp unsupported ({}); disabling relative mouse", e);
+                self.relative_mouse = false;
+            }
+        }
+    }
+
+    /// Replays window commands received before the primary window existed.
+    ///
+    /// Commands that still cannot be applied are deferred again.
+    fn apply_deferred_commands(&mut self) {
+        for command in std::mem::take(&mut self.deferred_commands) {
+            let _ = self.apply_window_command(command);
+        }
+    }
+
+    //--- Window Events ----------------------------------------------------
+
+    /// Routes a window event into the per-window input buffers.
+    ///
+    /// Independent of `ActiveEventLoop` so hosts and tests can feed events
+    /// directly. Returns `TickControl::Exit` when the primary window closes.
+    pub(crate) fn handle_window_event(
         &mut self,
-        event_loop: &ActiveEventLoop,
-        _window_id: winit::window::WindowId,
+        window_id: WindowId,
         event: WindowEvent,
-    ) {
+    ) -> TickControl {
         match &event {
+            WindowEvent::CloseRequested if self.primary_window != Some(window_id) => {
+                info!(target: "platform", "Secondary window close requested");
+                self.close_secondary_window(window_id);
+            }
+
             WindowEvent::CloseRequested => {
                 info!(target: "platform", "Window close requested");
                 let _ = self.event_sender.send(PlatformEvent::WindowClosed);
-                event_loop.exit();
+                self.windows.remove(&window_id);
+                return TickControl::Exit(CoreExitReason::WindowClosed);
             }
 
             WindowEvent::ModifiersChanged(state) => {
@@ -156,17 +502,37 @@ impl ApplicationHandler for Platform {
                 self.input_processor.update_modifiers(state.state());
             }
 
+            WindowEvent::Resized(size) => {
+                let (width, height) = (size.width as f32, size.height as f32);
+                self.window_sizes.insert(window_id, (width, height));
+                self.push_discrete(window_id, InputEvent::WindowResized { width, height });
+            }
+
             WindowEvent::CursorMoved { position, .. } => {
-                let event = self.input_processor.process_mouse_move(
-                    position.x as f32,
-                    position.y as f32
-                );
-                self.buffer.push_continuous(event);
+                let (x, y) = self.cursor_position(window_id, position.x as f32, position.y as f32);
+                let event = self.input_processor.process_mouse_move(x, y);
+                self.buffer_for(window_id).push_continuous(event);
+            }
+
+            WindowEvent::MouseWheel { delta, .. } => {
+                let event = self.input_processor.process_mouse_wheel(*delta);
+                self.buffer_for(window_id).push_continuous(event);
+            }
+
+            WindowEvent::CursorEntered { .. } => {
+                self.push_discrete(window_id, InputEvent::CursorEntered);
+            }
+
+            WindowEvent::CursorLeft { .. } => {
+                self.push_discrete(window_id, InputEvent::CursorLeft);
             }
 
             WindowEvent::KeyboardInput { event: key_event, .. } => {
+                if let Some(event) = self.input_processor.process_logical_key(key_event) {
+                    self.push_discrete(window_id, event);
+                }
                 if let Some(event) = self.input_processor.process_key_event(key_event) {
-                    self.buffer.push_discrete(event);
+                    self.push_discrete(window_id, event);
                 } else {
                     trace!(target: "platform::input", "Unmapped key ignored");
                 }
@@ -174,19 +540,126 @@ impl ApplicationHandler for Platform {
 
             WindowEvent::MouseInput { state, button, .. } => {
                 let event = self.input_processor.process_mouse_button(*button, *state);
-                self.buffer.push_discrete(event);
+                self.push_discrete(window_id, event);
             }
 
             WindowEvent::RedrawRequested => {
-                self.flush_input_buffer();
+                self.flush_input_buffer(window_id);
+                self.recenter_cursor(window_id);
 
-                if let Some(window) = &self.window {
-                    window.request_redraw();
+                if self.redraw_enabled {
+                    if let Some(window) = self.windows.get(&window_id) {
+                        window.request_redraw();
+                    }
                 }
             }
 
             _ => {}
         }
+
+        TickControl::Continue
+    }
+
+    /// Routes raw device input into the primary window's buffer.
+    ///
+    /// Device events are not tied to a window; raw motion is attributed to
+    /// the primary window and dropped before it exists.
+    pub(crate) fn handle_device_event(&mut self, event: DeviceEvent) {
+        let (DeviceEvent::MouseMotion { delta: (dx, dy) }, Some(window_id)) =
+            (event, self.primary_window)
+        else {
+            return;
+        };
+
+        let event = self.input_processor.process_mouse_motion(dx, dy);
+        self.buffer_for(window_id).push_continuous(event);
+    }
+
+    /// Applies mouse clamping to a raw cursor position, if enabled.
+    ///
+    /// Positions for windows with no known size are passed through.
+    fn cursor_position(&self, window_id: WindowId, x: f32, y: f32) -> (f32, f32) {
+        match self.window_sizes.get(&window_id) {
+            Some(&(width, height)) if self.clamp_mouse => {
+                (x.clamp(0.0, width), y.clamp(0.0, height))
+            }
+            _ => (x, y),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn window(&self) -> Option<&Window> {
+        self.primary_window.and_then(|id| self.windows.get(&id))
+    }
+}
+
+//=== Winit Conversions ===================================================
+
+impl From<WindowId> for platform_bridge::WindowId {
+    fn from(id: WindowId) -> Self {
+        Self::from_raw(u64::from(id))
+    }
+}
+
+impl From<platform_bridge::WindowId> for WindowId {
+    fn from(id: platform_bridge::WindowId) -> Self {
+        Self::from(id.raw())
+    }
+}
+
+//=== Winit Integration ===================================================
+
+impl ApplicationHandler for Platform {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.primary_window.is_some() {
+            debug!(target: "platform", "Window already exists (mobile resume?)");
+            return;
+        }
+
+        let attrs = WindowAttributes::default()
+            .with_title("Aetheric Engine")
+            .with_inner_size(LogicalSize::new(800, 600));
+
+        match self.create_window(event_loop, attrs) {
+            Some(id) => {
+                self.primary_window = Some(id);
+                self.apply_deferred_commands();
+            }
+            None => {
+                let _ = self.event_sender.send(PlatformEvent::WindowClosed);
+                event_loop.exit();
+            }
+        }
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        self.process_commands(event_loop);
+        self.flush_idle_input();
+
+        if let Some(watchdog) = &mut self.watchdog {
+            watchdog.check(Instant::now());
+        }
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        let control = self.handle_window_event(window_id, event);
+        if matches!(control, TickControl::Exit(_)) && self.owns_event_loop {
+            event_loop.exit();
+        }
+    }
+
+    fn device_event(
+        &mut self,
+        _event_loop: &ActiveEventLoop,
+        _device_id: DeviceId,
+        event: DeviceEvent,
+    ) {
+        self.handle_device_event(event);
     }
 }
 
@@ -199,39 +672,48 @@ mod tests {
     use super::*;
     use crossbeam_channel::unbounded;
     use crate::core::input::{KeyCode, Modifiers};
-    use crate::core::input::event::InputEvent;
+    use winit::{
+        dpi::{PhysicalPosition, PhysicalSize},
+        event::{DeviceId, ElementState, MouseButton as WinitMouseButton, MouseScrollDelta, TouchPhase},
+        keyboard::ModifiersState,
+    };
+
+    const MAIN: WindowId = WindowId::dummy();
+
+    fn test_platform() -> (Platform, Receiver<PlatformEvent>) {
+        let (tx, rx) = unbounded();
+        let (_cmd_tx, cmd_rx) = unbounded();
+        (Platform::new(tx, cmd_rx), rx)
+    }
 
     #[test]
     fn platform_creation() {
-        let (tx, _rx) = unbounded();
-        let platform = Platform::new(tx);
+        let (platform, _rx) = test_platform();
         assert!(platform.window().is_none());
     }
 
     #[test]
     fn flush_empty_buffer_is_noop() {
-        let (tx, rx) = unbounded();
-        let mut platform = Platform::new(tx);
+        let (mut platform, rx) = test_platform();
 
-        platform.flush_input_buffer();
+        platform.flush_input_buffer(MAIN);
 
         assert!(rx.try_recv().is_err());
     }
 
     #[test]
     fn flush_sends_buffered_events() {
-        let (tx, rx) = unbounded();
-        let mut platform = Platform::new(tx);
+        let (mut platform, rx) = test_platform();
 
-        platform.buffer.push_discrete(InputEvent::KeyDown {
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
             key: KeyCode::Space,
             modifiers: Modifiers::NONE,
         });
 
-        platform.flush_input_buffer();
+        platform.flush_input_buffer(MAIN);
 
         match rx.try_recv() {
-            Ok(PlatformEvent::Inputs { discrete, continuous }) => {
+            Ok(PlatformEvent::Inputs { discrete, continuous, .. }) => {
                 assert_eq!(discrete.len(), 1);
                 assert!(continuous.is_empty());
             }
@@ -241,33 +723,516 @@ mod tests {
 
     #[test]
     fn flush_handles_disconnected_channel() {
-        let (tx, rx) = unbounded();
-        let mut platform = Platform::new(tx);
+        let (mut platform, rx) = test_platform();
 
-        platform.buffer.push_discrete(InputEvent::KeyDown {
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
             key: KeyCode::Space,
             modifiers: Modifiers::NONE,
         });
 
         drop(rx);
 
-        platform.flush_input_buffer();
+        platform.flush_input_buffer(MAIN);
+    }
+
+    #[test]
+    fn flush_on_full_channel_keeps_input_without_blocking() {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let (_cmd_tx, cmd_rx) = unbounded();
+        let mut platform = Platform::new(tx, cmd_rx);
+        platform.event_sender.try_send(PlatformEvent::WindowClosed).unwrap();
+
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+            key: KeyCode::KeyA,
+            modifiers: Modifiers::NONE,
+        });
+        platform.flush_input_buffer(MAIN);
+
+        assert!(platform.backpressured);
+        assert_eq!(platform.buffer_for(MAIN).discrete_len(), 1);
+
+        // Once the core drains the channel, held input goes out with newer input
+        assert!(matches!(rx.try_recv(), Ok(PlatformEvent::WindowClosed)));
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyUp {
+            key: KeyCode::KeyA,
+            modifiers: Modifiers::NONE,
+        });
+        platform.flush_input_buffer(MAIN);
+
+        assert!(!platform.backpressured);
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { discrete, .. }) => assert_eq!(discrete.len(), 2),
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
     }
 
     #[test]
     fn multiple_flushes_clear_buffer() {
-        let (tx, rx) = unbounded();
-        let mut platform = Platform::new(tx);
+        let (mut platform, rx) = test_platform();
 
-        platform.buffer.push_discrete(InputEvent::KeyDown {
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
             key: KeyCode::KeyA,
             modifiers: Modifiers::NONE,
         });
 
-        platform.flush_input_buffer();
-        platform.flush_input_buffer();
+        platform.flush_input_buffer(MAIN);
+        platform.flush_input_buffer(MAIN);
 
         assert!(rx.try_recv().is_ok());
         assert!(rx.try_recv().is_err());
     }
-}
\ No newline at end of file
+
+    //--- Window Routing ---------------------------------------------------
+
+    #[test]
+    fn flushed_events_carry_window_id() {
+        let (mut platform, rx) = test_platform();
+        let inspector = WindowId::from(42);
+
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+            key: KeyCode::KeyA,
+            modifiers: Modifiers::NONE,
+        });
+        platform.buffer_for(inspector).push_continuous(InputEvent::MouseMoved { x: 5.0, y: 6.0 });
+
+        platform.flush_input_buffer(inspector);
+        platform.flush_input_buffer(MAIN);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { window, discrete, continuous }) => {
+                assert_eq!(window, inspector.into());
+                assert!(discrete.is_empty());
+                assert_eq!(continuous.len(), 1);
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { window, discrete, .. }) => {
+                assert_eq!(window, MAIN.into());
+                assert_eq!(discrete.len(), 1);
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn flush_only_drains_requested_window() {
+        let (mut platform, rx) = test_platform();
+        let inspector = WindowId::from(42);
+
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+            key: KeyCode::KeyA,
+            modifiers: Modifiers::NONE,
+        });
+        platform.buffer_for(inspector).push_discrete(InputEvent::KeyDown {
+            key: KeyCode::KeyB,
+            modifiers: Modifiers::NONE,
+        });
+
+        platform.flush_input_buffer(MAIN);
+
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_err());
+        assert!(!platform.buffer_for(inspector).is_empty());
+    }
+
+    #[test]
+    fn window_id_round_trips_through_bridge() {
+        let id = WindowId::from(1234);
+        let bridged: platform_bridge::WindowId = id.into();
+        assert_eq!(WindowId::from(bridged), id);
+    }
+
+    #[test]
+    fn closing_primary_window_via_command_is_refused() {
+        let (mut platform, rx) = test_platform();
+        platform.primary_window = Some(MAIN);
+        platform.buffer_for(MAIN);
+
+        platform.close_secondary_window(MAIN);
+
+        assert!(platform.buffers.contains_key(&MAIN));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn cursor_command_before_window_is_deferred() {
+        let (mut platform, _rx) = test_platform();
+
+        let result = platform.apply_window_command(PlatformCommand::SetCursorGrab(true));
+
+        assert!(matches!(result, Err(PlatformError::NoWindow)));
+        assert_eq!(platform.deferred_commands, vec![PlatformCommand::SetCursorGrab(true)]);
+    }
+
+    #[test]
+    fn window_control_commands_before_window_are_deferred_in_order() {
+        let (mut platform, _rx) = test_platform();
+        let commands = vec![
+            PlatformCommand::SetAlwaysOnTop(true),
+            PlatformCommand::Minimize,
+            PlatformCommand::Maximize,
+            PlatformCommand::SetTitle("Editor".to_string()),
+        ];
+
+        for command in commands.clone() {
+            let result = platform.apply_window_command(command);
+            assert!(matches!(result, Err(PlatformError::NoWindow)));
+        }
+
+        assert_eq!(platform.deferred_commands, commands);
+    }
+
+    #[test]
+    fn deferred_commands_survive_replay_without_window() {
+        let (mut platform, _rx) = test_platform();
+        let _ = platform.apply_window_command(PlatformCommand::SetCursorVisible(false));
+        let _ = platform.apply_window_command(PlatformCommand::SetCursorGrab(true));
+
+        // Primary id known but window not yet in the map: still no window
+        platform.primary_window = Some(MAIN);
+        platform.apply_deferred_commands();
+
+        assert_eq!(
+            platform.deferred_commands,
+            vec![PlatformCommand::SetCursorVisible(false), PlatformCommand::SetCursorGrab(true)]
+        );
+    }
+
+    #[test]
+    fn idle_input_is_flushed_only_while_redraw_paused() {
+        let (mut platform, rx) = test_platform();
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+            key: KeyCode::KeyA,
+            modifiers: Modifiers::NONE,
+        });
+
+        platform.flush_idle_input();
+        assert!(rx.try_recv().is_err());
+
+        platform.set_redraw(false);
+        platform.flush_idle_input();
+        assert!(matches!(rx.try_recv(), Ok(PlatformEvent::Inputs { window, .. }) if window == MAIN.into()));
+    }
+
+    //--- Synthetic Window Events ------------------------------------------
+
+    #[test]
+    fn synthetic_events_are_flushed_on_redraw() {
+        let (mut platform, rx) = test_platform();
+
+        platform.handle_window_event(MAIN, WindowEvent::CursorMoved {
+            device_id: DeviceId::dummy(),
+            position: PhysicalPosition::new(10.0, 20.0),
+        });
+        platform.handle_window_event(MAIN, WindowEvent::MouseInput {
+            device_id: DeviceId::dummy(),
+            state: ElementState::Pressed,
+            button: WinitMouseButton::Left,
+        });
+        assert!(rx.try_recv().is_err());
+
+        platform.handle_window_event(MAIN, WindowEvent::RedrawRequested);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { window, discrete, continuous }) => {
+                assert_eq!(window, MAIN.into());
+                assert_eq!(discrete.len(), 1);
+                assert_eq!(continuous, vec![InputEvent::MouseMoved { x: 10.0, y: 20.0 }]);
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn scroll_and_raw_motion_are_buffered_as_continuous_input() {
+        let (mut platform, rx) = test_platform();
+        platform.handle_device_event(DeviceEvent::MouseMotion { delta: (1.0, 1.0) });
+        platform.primary_window = Some(MAIN);
+
+        for _ in 0..2 {
+            platform.handle_window_event(MAIN, WindowEvent::MouseWheel {
+                device_id: DeviceId::dummy(),
+                delta: MouseScrollDelta::LineDelta(0.0, 1.0),
+                phase: TouchPhase::Moved,
+            });
+            platform.handle_device_event(DeviceEvent::MouseMotion { delta: (3.0, -2.0) });
+        }
+        platform.flush_input_buffer(MAIN);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { discrete, continuous, .. }) => {
+                assert!(discrete.is_empty());
+                assert_eq!(continuous, vec![
+                    InputEvent::MouseScrolled { dx: 0.0, dy: 2.0 },
+                    InputEvent::MouseMotion { dx: 6.0, dy: -4.0 },
+                ]);
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn early_flush_sends_discrete_input_immediately() {
+        let (tx, rx) = unbounded();
+        let (_cmd_tx, cmd_rx) = unbounded();
+        let mut platform = Platform::new(tx, cmd_rx).with_early_flush(Some(1));
+
+        platform.handle_window_event(MAIN, WindowEvent::CursorMoved {
+            device_id: DeviceId::dummy(),
+            position: PhysicalPosition::new(10.0, 20.0),
+        });
+        assert!(rx.try_recv().is_err());
+
+        platform.handle_window_event(MAIN, WindowEvent::MouseInput {
+            device_id: DeviceId::dummy(),
+            state: ElementState::Pressed,
+            button: WinitMouseButton::Left,
+        });
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { window, discrete, .. }) => {
+                assert_eq!(window, MAIN.into());
+                assert_eq!(discrete.len(), 1);
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    /// Sends a synthetic cursor move and returns the flushed position.
+    fn flushed_cursor(
+        platform: &mut Platform,
+        rx: &Receiver<PlatformEvent>,
+        x: f64,
+        y: f64,
+    ) -> (f32, f32) {
+        platform.handle_window_event(MAIN, WindowEvent::CursorMoved {
+            device_id: DeviceId::dummy(),
+            position: PhysicalPosition::new(x, y),
+        });
+        platform.flush_input_buffer(MAIN);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { continuous, .. }) => match continuous[..] {
+                [InputEvent::MouseMoved { x, y }] => (x, y),
+                _ => panic!("Expected a single MouseMoved, got {:?}", continuous),
+            },
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn out_of_bounds_cursor_is_raw_by_default() {
+        let (mut platform, rx) = test_platform();
+        platform.handle_window_event(MAIN, WindowEvent::Resized(PhysicalSize::new(100, 50)));
+
+        assert_eq!(flushed_cursor(&mut platform, &rx, -5.0, 80.0), (-5.0, 80.0));
+    }
+
+    #[test]
+    fn out_of_bounds_cursor_is_clamped_when_enabled() {
+        let (mut platform, rx) = test_platform();
+        platform.clamp_mouse = true;
+        platform.handle_window_event(MAIN, WindowEvent::Resized(PhysicalSize::new(100, 50)));
+
+        assert_eq!(flushed_cursor(&mut platform, &rx, -5.0, 80.0), (0.0, 50.0));
+        assert_eq!(flushed_cursor(&mut platform, &rx, 140.0, -3.0), (100.0, 0.0));
+        assert_eq!(flushed_cursor(&mut platform, &rx, 40.0, 25.0), (40.0, 25.0));
+
+        // Bounds follow resizes
+        platform.handle_window_event(MAIN, WindowEvent::Resized(PhysicalSize::new(200, 100)));
+        assert_eq!(flushed_cursor(&mut platform, &rx, 140.0, 80.0), (140.0, 80.0));
+    }
+
+    #[test]
+    fn resize_is_forwarded_as_discrete_input() {
+        let (mut platform, rx) = test_platform();
+        platform.handle_window_event(MAIN, WindowEvent::Resized(PhysicalSize::new(640, 480)));
+        platform.handle_window_event(MAIN, WindowEvent::RedrawRequested);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { discrete, .. }) => assert_eq!(
+                discrete,
+                vec![InputEvent::WindowResized { width: 640.0, height: 480.0 }]
+            ),
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn clamping_passes_through_unknown_window_size() {
+        let (mut platform, rx) = test_platform();
+        platform.clamp_mouse = true;
+
+        assert_eq!(flushed_cursor(&mut platform, &rx, -5.0, 80.0), (-5.0, 80.0));
+    }
+
+    #[test]
+    fn cursor_enter_and_leave_are_forwarded() {
+        let (mut platform, rx) = test_platform();
+
+        platform.handle_window_event(MAIN, WindowEvent::CursorEntered { device_id: DeviceId::dummy() });
+        platform.handle_window_event(MAIN, WindowEvent::CursorLeft { device_id: DeviceId::dummy() });
+        platform.flush_input_buffer(MAIN);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { discrete, .. }) => {
+                assert_eq!(discrete, vec![InputEvent::CursorEntered, InputEvent::CursorLeft]);
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn synthetic_modifiers_apply_to_mouse_input() {
+        let (mut platform, rx) = test_platform();
+
+        platform.handle_window_event(
+            MAIN,
+            WindowEvent::ModifiersChanged(ModifiersState::SHIFT.into()),
+        );
+        platform.handle_window_event(MAIN, WindowEvent::MouseInput {
+            device_id: DeviceId::dummy(),
+            state: ElementState::Pressed,
+            button: WinitMouseButton::Right,
+        });
+        platform.handle_window_event(MAIN, WindowEvent::RedrawRequested);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { discrete, .. }) => {
+                assert!(matches!(
+                    discrete[0],
+                    InputEvent::MouseButtonDown { modifiers: Modifiers { shift: true, .. }, .. }
+                ));
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn primary_close_requests_exit() {
+        let (mut platform, rx) = test_platform();
+        platform.primary_window = Some(MAIN);
+
+        let control = platform.handle_window_event(MAIN, WindowEvent::CloseRequested);
+
+        assert_eq!(control, TickControl::Exit(CoreExitReason::WindowClosed));
+        assert!(matches!(rx.try_recv(), Ok(PlatformEvent::WindowClosed)));
+    }
+
+    #[test]
+    fn secondary_close_keeps_running() {
+        let (mut platform, rx) = test_platform();
+        platform.primary_window = Some(MAIN);
+
+        let control = platform.handle_window_event(
+            WindowId::from(42),
+            WindowEvent::CloseRequested,
+        );
+
+        assert_eq!(control, TickControl::Continue);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn hosted_platform_does_not_own_event_loop() {
+        let (platform, _rx) = test_platform();
+        assert!(platform.owns_event_loop);
+        assert!(!platform.hosted().owns_event_loop);
+    }
+
+    #[test]
+    fn flush_reports_input_burst_above_threshold() {
+        let (mut platform, rx) = test_platform();
+
+        for i in 0..=INPUT_BURST_THRESHOLD {
+            let key = if i % 2 == 0 { KeyCode::KeyA } else { KeyCode::KeyB };
+            platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+                key,
+                modifiers: Modifiers::NONE,
+            });
+        }
+
+        platform.flush_input_buffer(MAIN);
+
+        assert!(matches!(rx.try_recv(), Ok(PlatformEvent::Inputs { .. })));
+        match rx.try_recv() {
+            Ok(PlatformEvent::InputBurst { window, discrete }) => {
+                assert_eq!(window, MAIN.into());
+                assert_eq!(discrete, INPUT_BURST_THRESHOLD + 1);
+            }
+            other => panic!("Expected InputBurst event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn input_burst_never_takes_the_slot_of_held_input() {
+        let (tx, rx) = crossbeam_channel::bounded(2);
+        let (_cmd_tx, cmd_rx) = unbounded();
+        let mut platform = Platform::new(tx, cmd_rx);
+        platform.event_sender.try_send(PlatformEvent::WindowClosed).unwrap();
+        platform.event_sender.try_send(PlatformEvent::WindowClosed).unwrap();
+
+        for i in 0..=INPUT_BURST_THRESHOLD {
+            let key = if i % 2 == 0 { KeyCode::KeyA } else { KeyCode::KeyB };
+            platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+                key,
+                modifiers: Modifiers::NONE,
+            });
+        }
+        platform.flush_input_buffer(MAIN);
+        assert!(platform.backpressured);
+
+        // One slot frees up: the held batch gets it, the burst report doesn't
+        assert!(matches!(rx.try_recv(), Ok(PlatformEvent::WindowClosed)));
+        platform.flush_input_buffer(MAIN);
+
+        assert!(!platform.backpressured);
+        assert!(matches!(rx.try_recv(), Ok(PlatformEvent::WindowClosed)));
+        assert!(matches!(rx.try_recv(), Ok(PlatformEvent::Inputs { .. })));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn tap_coalescing_applies_to_window_buffers() {
+        let (tx, rx) = unbounded();
+        let (_cmd_tx, cmd_rx) = unbounded();
+        let mut platform = Platform::new(tx, cmd_rx).with_tap_coalescing(true);
+
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+            key: KeyCode::Space,
+            modifiers: Modifiers::NONE,
+        });
+        platform.buffer_for(MAIN).push_discrete(InputEvent::KeyUp {
+            key: KeyCode::Space,
+            modifiers: Modifiers::NONE,
+        });
+        platform.flush_input_buffer(MAIN);
+
+        match rx.try_recv() {
+            Ok(PlatformEvent::Inputs { discrete, .. }) => {
+                assert_eq!(discrete, vec![InputEvent::KeyTapped {
+                    key: KeyCode::Space,
+                    modifiers: Modifiers::NONE,
+                }]);
+            }
+            other => panic!("Expected Inputs event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn discrete_cap_applies_to_window_buffers() {
+        let (tx, _rx) = unbounded();
+        let (_cmd_tx, cmd_rx) = unbounded();
+        let mut platform = Platform::new(tx, cmd_rx).with_max_discrete_events(2);
+
+        for key in [KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC] {
+            platform.buffer_for(MAIN).push_discrete(InputEvent::KeyDown {
+                key,
+                modifiers: Modifiers::NONE,
+            });
+        }
+
+        assert_eq!(platform.buffer_for(MAIN).discrete_len(), 2);
+    }
+}
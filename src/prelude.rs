@@ -6,19 +6,24 @@
 //
 // Usage:
 //   use aetheric_engine::prelude::*;
+//   Engine::<MyScene, MyAction>::builder().build().run();
 //
 //=========================================================================
 
 //=== Public API ==========================================================
 
 // Engine core
-pub use crate::engine::{Engine, EngineBuilder};
+pub use crate::engine::{EmbeddedEngine, Engine, EngineBuilder};
+pub use crate::error::{BuilderError, EngineError};
 
 // Global systems and context
 pub use crate::core::globals::{GlobalContext, GlobalSystems};
 
 // Input system
-pub use crate::core::input::{Action, InputContext, InputSystem, KeyCode, Modifiers, MouseButton};
+pub use crate::core::input::{
+    Action, ActionEvent, ActionPhase, ActionPublishPolicy, InputContext, InputSystem, KeyCode,
+    Modifiers, MouseButton,
+};
 
 // Scene system
 pub use crate::core::scene::{Scene, SceneKey, SceneTransition};
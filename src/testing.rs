@@ -0,0 +1,264 @@
+//=========================================================================
+// Testing Utilities
+//=========================================================================
+//
+// Headless driver for the core loop (enabled by the `testing` feature).
+//
+// Architecture:
+//   TestHarness ──inject()──> PlatformEvent (MPSC) ──tick()──> Orchestrator
+//
+// No window or event loop is created; synthetic input stands in for the
+// platform thread and each tick runs without frame pacing.
+//
+//=========================================================================
+
+//=== External Dependencies ===============================================
+
+use crossbeam_channel::{unbounded, Receiver, Sender};
+
+//=== Internal Dependencies ===============================================
+
+use crate::core::input::event::InputEvent;
+use crate::core::input::{KeyCode, Modifiers, MouseButton};
+use crate::core::platform_bridge::{
+    EventCollector, PlatformCommand, PlatformEvent, TickControl, WindowId,
+};
+use crate::core::{Action, CoreSystemsOrchestrator, GlobalContext, GlobalSystems, SceneKey};
+
+//=== Synthetic Input =====================================================
+
+/// Key pressed with no modifiers.
+pub fn key_down(key: KeyCode) -> InputEvent {
+    InputEvent::KeyDown { key, modifiers: Modifiers::NONE }
+}
+
+/// Key released with no modifiers.
+pub fn key_up(key: KeyCode) -> InputEvent {
+    InputEvent::KeyUp { key, modifiers: Modifiers::NONE }
+}
+
+/// Mouse button pressed with no modifiers.
+pub fn mouse_down(button: MouseButton) -> InputEvent {
+    InputEvent::MouseButtonDown { button, modifiers: Modifiers::NONE }
+}
+
+/// Mouse button released with no modifiers.
+pub fn mouse_up(button: MouseButton) -> InputEvent {
+    InputEvent::MouseButtonUp { button, modifiers: Modifiers::NONE }
+}
+
+/// Cursor moved to `(x, y)` in window pixels.
+pub fn mouse_move(x: f32, y: f32) -> InputEvent {
+    InputEvent::MouseMoved { x, y }
+}
+
+//=== TestHarness =========================================================
+
+/// Drives the core systems headlessly, one tick at a time.
+///
+/// Input injected between ticks is delivered as a single platform batch
+/// from the primary window, exactly as the platform thread would send it.
+///
+/// ```
+/// use aetheric_engine::prelude::*;
+/// use aetheric_engine::testing::{key_down, TestHarness};
+///
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// # enum GameScene { Main }
+/// # impl SceneKey for GameScene {}
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// # enum GameAction { Jump }
+/// # impl Action for GameAction {}
+/// let mut harness = TestHarness::<GameScene, GameAction>::new();
+///
+/// harness.inject(vec![key_down(KeyCode::Space)]);
+/// harness.tick();
+///
+/// assert!(harness.context().input_state.is_key_pressed(KeyCode::Space));
+/// ```
+pub struct TestHarness<S: SceneKey, A: Action> {
+    orchestrator: CoreSystemsOrchestrator<S, A>,
+    collector: EventCollector,
+    events: Sender<PlatformEvent>,
+    commands: Sender<PlatformCommand>,
+    command_receiver: Receiver<PlatformCommand>,
+    window: WindowId,
+    started: bool,
+}
+
+impl<S: SceneKey, A: Action> TestHarness<S, A> {
+    //--- Construction -----------------------------------------------------
+
+    /// Creates a harness with a single (virtual) primary window.
+    pub fn new() -> Self {
+        let (events, receiver) = unbounded();
+        let (commands, command_receiver) = unbounded();
+        let window = WindowId::from_raw(1);
+        let _ = events.send(PlatformEvent::WindowCreated(window));
+
+        Self {
+            orchestrator: CoreSystemsOrchestrator::new(),
+            collector: EventCollector::new(receiver),
+            events,
+            commands,
+            command_receiver,
+            window,
+            started: false,
+        }
+    }
+
+    /// Configures systems (bindings, scenes), like [`Engine::init`](crate::Engine::init).
+    pub fn init<F>(&mut self, init_fn: F) -> &mut Self
+    where
+        F: FnOnce(&mut GlobalSystems<S, A>),
+    {
+        self.orchestrator.init_systems(init_fn);
+        self
+    }
+
+    //--- Input ------------------------------------------------------------
+
+    /// Queues events for the next tick as one batch from the primary window.
+    ///
+    /// Mouse moves, scroll and raw motion are sent as continuous input,
+    /// everything else as discrete.
+    pub fn inject(&self, events: Vec<InputEvent>) {
+        let (continuous, discrete) = events
+            .into_iter()
+            .partition(InputEvent::is_continuous);
+
+        let _ = self.events.send(PlatformEvent::Inputs {
+            window: self.window,
+            discrete,
+            continuous,
+        });
+    }
+
+    //--- Ticking ----------------------------------------------------------
+
+    /// Runs one tick. Returns `false` once game code has requested exit.
+    ///
+    /// The first tick calls `on_enter` for the initial scenes beforehand.
+    pub fn tick(&mut self) -> bool {
+        if !self.started {
+            self.orchestrator.start();
+            self.started = true;
+        }
+
+        let control = self.orchestrator.tick(&mut self.collector, &self.commands);
+
+        // Nothing consumes platform commands here; keep the channel empty
+        while self.command_receiver.try_recv().is_ok() {}
+
+        control == TickControl::Continue
+    }
+
+    /// Runs up to `ticks` ticks, stopping early on an exit request.
+    ///
+    /// Returns the number of ticks that ran.
+    pub fn run_ticks(&mut self, ticks: usize) -> usize {
+        for ran in 1..=ticks {
+            if !self.tick() {
+                return ran;
+            }
+        }
+        ticks
+    }
+
+    //--- Inspection -------------------------------------------------------
+
+    /// Returns the shared context as scenes saw it during the last tick.
+    pub fn context(&self) -> &GlobalContext {
+        self.orchestrator.context()
+    }
+
+    /// Returns the shared context for setup between ticks.
+    pub fn context_mut(&mut self) -> &mut GlobalContext {
+        self.orchestrator.context_mut()
+    }
+}
+
+impl<S: SceneKey, A: Action> Default for TestHarness<S, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//=========================================================================
+// Unit Tests
+//=========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::input::InputContext;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestScene {
+        Main,
+    }
+
+    impl SceneKey for TestScene {}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestAction {
+        Jump,
+    }
+
+    impl Action for TestAction {}
+
+    #[test]
+    fn injected_key_down_is_pressed_for_one_tick() {
+        let mut harness = TestHarness::<TestScene, TestAction>::new();
+
+        harness.inject(vec![key_down(KeyCode::Space)]);
+        harness.tick();
+        assert!(harness.context().input_state.is_key_pressed(KeyCode::Space));
+
+        harness.tick();
+        assert!(!harness.context().input_state.is_key_pressed(KeyCode::Space));
+        assert!(harness.context().input_state.is_key_down(KeyCode::Space));
+    }
+
+    #[test]
+    fn injected_input_drives_actions() {
+        let mut harness = TestHarness::<TestScene, TestAction>::new();
+        harness.init(|systems| {
+            systems.input.bind_key(KeyCode::Space, TestAction::Jump, InputContext::Primary);
+        });
+
+        harness.inject(vec![key_down(KeyCode::Space)]);
+        harness.tick();
+
+        assert!(harness.context().action_pressed(TestAction::Jump));
+    }
+
+    #[test]
+    fn mouse_moves_are_sent_as_continuous_input() {
+        let mut harness = TestHarness::<TestScene, TestAction>::new();
+
+        harness.inject(vec![mouse_down(MouseButton::Left), mouse_move(4.0, 2.0)]);
+        harness.tick();
+
+        let state = &harness.context().input_state;
+        assert!(state.is_button_pressed(MouseButton::Left));
+        assert_eq!(state.mouse_position(), (4.0, 2.0));
+    }
+
+    #[test]
+    fn primary_window_is_reported() {
+        let mut harness = TestHarness::<TestScene, TestAction>::new();
+        harness.tick();
+
+        assert_eq!(harness.context().windows(), &[WindowId::from_raw(1)]);
+    }
+
+    #[test]
+    fn run_ticks_stops_on_exit_request() {
+        let mut harness = TestHarness::<TestScene, TestAction>::new();
+        harness.context_mut().request_exit();
+
+        assert_eq!(harness.run_ticks(5), 1);
+    }
+}